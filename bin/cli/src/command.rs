@@ -1,12 +1,16 @@
 use bounty_cli::{
+    apply,
     bank,
     bounty,
     donate,
     key,
     org,
     shares,
+    tx,
     vote,
     wallet,
+    watch,
+    OutputFormat,
 };
 use clap::Clap;
 use std::path::PathBuf;
@@ -17,6 +21,25 @@ pub struct Opts {
     pub cmd: SubCommand,
     #[clap(short = "p", long = "path")]
     pub path: Option<PathBuf>,
+    /// Prints results as `text` or `json`, errors in json carry a stable code.
+    #[clap(short = "o", long = "output", default_value = "text")]
+    pub output: OutputFormat,
+    /// The account whose keystore is used, each account has its own.
+    #[clap(short = "a", long = "account", default_value = "default")]
+    pub account: String,
+    /// Initializes an empty keystore with the //Alice development key and
+    /// the password `password`.
+    #[clap(long = "dev")]
+    pub dev: bool,
+    /// Websocket url of the node, defaults to the local node.
+    #[clap(long = "url", conflicts_with = "light")]
+    pub url: Option<String>,
+    /// Runs an embedded light client instead of connecting to a node.
+    #[clap(long = "light")]
+    pub light: bool,
+    /// Chain of the light client: `dev`, `local` or a chain spec file.
+    #[clap(long = "chain", default_value = "dev")]
+    pub chain: String,
 }
 
 #[derive(Clone, Debug, Clap)]
@@ -28,6 +51,9 @@ pub enum SubCommand {
     Donate(DonateCommand),
     Bank(BankCommand),
     Bounty(BountyCommand),
+    Watch(watch::WatchCommand),
+    Tx(TxCommand),
+    Apply(apply::ApplyCommand),
     Run,
 }
 
@@ -40,8 +66,13 @@ pub struct KeyCommand {
 #[derive(Clone, Debug, Clap)]
 pub enum KeySubCommand {
     Set(key::KeySetCommand),
+    Generate(key::KeyGenerateCommand),
+    Import(key::KeyImportCommand),
+    ExportPaperkey(key::KeyExportPaperkeyCommand),
     Unlock(key::KeyUnlockCommand),
     Lock(key::KeyLockCommand),
+    /// Lists the accounts with a keystore.
+    List,
 }
 
 #[derive(Clone, Debug, Clap)]
@@ -76,6 +107,10 @@ pub enum OrgSubCommand {
     // full org stuff
     RegisterFlatOrg(org::OrgRegisterFlatCommand),
     RegisterWeightedOrg(org::OrgRegisterWeightedCommand),
+    // queries
+    Show(org::OrgShowCommand),
+    List(org::OrgListCommand),
+    Members(org::OrgMembersCommand),
 }
 
 #[derive(Clone, Debug, Clap)]
@@ -113,6 +148,14 @@ pub struct BankCommand {
 pub enum BankSubCommand {
     OpenAccount(bank::BankOpenOrgAccountCommand),
     OpenAccount2(bank::BankOpenOrgAccountCommand),
+    Show(bank::BankShowCommand),
+    List(bank::BankListCommand),
+    Spends(bank::BankSpendsCommand),
+    ProposeSpend(bank::BankProposeSpendCommand),
+    TriggerSpendVote(bank::BankTriggerSpendVoteCommand),
+    SudoApproveSpend(bank::BankSudoApproveSpendCommand),
+    PollSpend(bank::BankPollSpendCommand),
+    Close(bank::BankCloseCommand),
 }
 
 #[derive(Clone, Debug, Clap)]
@@ -132,4 +175,24 @@ pub enum BountySubCommand {
     TriggerMilestoneReview(bounty::BountyTriggerMilestoneReviewCommand),
     SudoApproveMilestone(bounty::BountySudoApproveMilestoneCommand),
     PollMilestone(bounty::BountyPollMilestoneCommand),
+    // queries
+    Show(bounty::BountyShowCommand),
+    List(bounty::BountyListCommand),
+    ShowApplication(bounty::BountyShowApplicationCommand),
+    ListApplications(bounty::BountyListApplicationsCommand),
+    ShowMilestone(bounty::BountyShowMilestoneCommand),
+    ListMilestones(bounty::BountyListMilestonesCommand),
+}
+
+#[derive(Clone, Debug, Clap)]
+pub struct TxCommand {
+    #[clap(subcommand)]
+    pub cmd: TxSubCommand,
+}
+
+#[derive(Clone, Debug, Clap)]
+pub enum TxSubCommand {
+    Prepare(tx::TxPrepareCommand),
+    Sign(tx::TxSignCommand),
+    Submit(tx::TxSubmitCommand),
 }
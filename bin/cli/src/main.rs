@@ -4,6 +4,8 @@ use bounty_cli::{
     set_device_key,
     Command,
     Error,
+    Output,
+    OutputFormat,
 };
 use clap::Clap;
 use exitfailure::ExitDisplay;
@@ -23,25 +25,43 @@ use substrate_subxt::{
     sp_core::sr25519,
     ClientBuilder,
 };
-use test_client::Runtime;
+use test_client::{
+    light::open_light_client,
+    Runtime,
+};
 
 mod command;
 
 #[async_std::main]
 async fn main() -> Result<(), ExitDisplay<Error>> {
-    Ok(run().await?)
+    env_logger::init();
+    let opts: Opts = Opts::parse();
+    let format = opts.output;
+    match run(opts).await {
+        Ok(output) => {
+            output.print(format);
+            Ok(())
+        }
+        Err(err) if format == OutputFormat::Json => {
+            println!("{}", err.to_json());
+            std::process::exit(1)
+        }
+        Err(err) => Err(err.into()),
+    }
 }
 
 type Client = test_client::bounty::Client<Runtime, sr25519::Pair, Store>;
 
 struct Paths {
     _root: PathBuf,
+    keystores: PathBuf,
     keystore: PathBuf,
     db: PathBuf,
+    light: PathBuf,
 }
 
 impl Paths {
-    fn new(root: Option<PathBuf>) -> Result<Self, Error> {
+    fn new(root: Option<PathBuf>, account: &str) -> Result<Self, Error> {
         let root = if let Some(root) = root {
             root
         } else {
@@ -49,35 +69,104 @@ impl Paths {
                 .ok_or(Error::ConfigDirNotFound)?
                 .join("sunshine-cli")
         };
-        let keystore = root.join("keystore");
+        let keystores = root.join("keystore");
+        let keystore = keystores.join(account);
         let db = root.join("db");
+        let light = root.join("light");
         Ok(Paths {
             _root: root,
+            keystores,
             keystore,
             db,
+            light,
         })
     }
 }
 
-async fn run() -> Result<(), Error> {
-    env_logger::init();
-    let opts: Opts = Opts::parse();
+fn list_accounts(paths: &Paths) -> Result<Output, Error> {
+    let mut accounts = Vec::new();
+    if paths.keystores.exists() {
+        for entry in std::fs::read_dir(&paths.keystores)? {
+            let entry = entry?;
+            if entry.file_type()?.is_dir() {
+                let name = entry.file_name().to_string_lossy().to_string();
+                let selected = entry.path() == paths.keystore;
+                let marker = if selected { "*" } else { " " };
+                accounts.push(Output::new(
+                    format!("{} {}", marker, name),
+                    serde_json::json!({
+                        "account": name,
+                        "selected": selected,
+                    }),
+                ));
+            }
+        }
+    }
+    Ok(Output::list(accounts))
+}
+
+async fn run(opts: Opts) -> Result<Output, Error> {
     // initialize requisite storage utilities
-    let paths = Paths::new(opts.path)?;
+    let paths = Paths::new(opts.path, &opts.account)?;
+    if let SubCommand::Key(KeyCommand {
+        cmd: KeySubCommand::List,
+    }) = opts.cmd
+    {
+        return list_accounts(&paths)
+    }
     let keystore = KeyStore::open(&paths.keystore).await?;
-    // initialize keystore with alice's keys
-    let alice_seed: [u8; 32] =
-        sr25519::Pair::from_string_with_seed("//Alice", None)
-            .unwrap()
-            .1
-            .unwrap();
-    keystore
-        .initialize(
-            &DeviceKey::from_seed(alice_seed),
-            &Password::from("password".to_string()),
-        )
-        .await?;
-    let subxt = ClientBuilder::<Runtime>::new().build().await?;
+    // keys are managed without a connection to a node
+    if let SubCommand::Key(KeyCommand { cmd }) = &opts.cmd {
+        match cmd {
+            KeySubCommand::Generate(cmd) => {
+                return cmd
+                    .exec_offline::<Runtime, sr25519::Pair>(&keystore)
+                    .await
+            }
+            KeySubCommand::Import(cmd) => {
+                return cmd
+                    .exec_offline::<Runtime, sr25519::Pair>(&keystore)
+                    .await
+            }
+            KeySubCommand::ExportPaperkey(cmd) => {
+                return cmd.exec_offline(&keystore).await
+            }
+            _ => (),
+        }
+    }
+    // initialize an empty keystore with alice's keys if asked to
+    if opts.dev && !keystore.is_initialized().await {
+        let alice_seed: [u8; 32] =
+            sr25519::Pair::from_string_with_seed("//Alice", None)
+                .unwrap()
+                .1
+                .unwrap();
+        keystore
+            .initialize(
+                &DeviceKey::from_seed(alice_seed),
+                &Password::from("password".to_string()),
+            )
+            .await?;
+    }
+    // signing a prepared transaction needs no connection to a node
+    if let SubCommand::Tx(TxCommand {
+        cmd: TxSubCommand::Sign(cmd),
+    }) = &opts.cmd
+    {
+        return cmd.exec_offline::<Runtime, sr25519::Pair>(&keystore).await
+    }
+    let subxt = if opts.light {
+        open_light_client(&paths.light, &opts.chain)
+            .await
+            .map_err(|err| Error::LightClient(err.to_string()))?
+    } else if let Some(url) = &opts.url {
+        ClientBuilder::<Runtime>::new()
+            .set_url(url.as_str())
+            .build()
+            .await?
+    } else {
+        ClientBuilder::<Runtime>::new().build().await?
+    };
     let config =
         Config::from_path(&paths.db).map_err(ipfs_embed::Error::Sled)?;
     let store = Store::new(config)?;
@@ -99,11 +188,24 @@ async fn run() -> Result<(), Error> {
                         force,
                     )
                     .await?;
-                    println!("your device key is {}", account_id.to_string());
-                    Ok(())
+                    Ok(Output::new(
+                        format!(
+                            "your device key is {}",
+                            account_id.to_string()
+                        ),
+                        serde_json::json!({ "account": account_id.to_string() }),
+                    ))
                 }
                 KeySubCommand::Unlock(cmd) => cmd.exec(&client).await,
                 KeySubCommand::Lock(cmd) => cmd.exec(&client).await,
+                KeySubCommand::Generate(_)
+                | KeySubCommand::Import(_)
+                | KeySubCommand::ExportPaperkey(_) => {
+                    unreachable!("managed before connecting to a node")
+                }
+                KeySubCommand::List => {
+                    unreachable!("listed before opening the keystore")
+                }
             }
         }
         SubCommand::Wallet(WalletCommand { cmd }) => {
@@ -130,6 +232,9 @@ async fn run() -> Result<(), Error> {
                 OrgSubCommand::RegisterWeightedOrg(cmd) => {
                     cmd.exec(&client).await
                 }
+                OrgSubCommand::Show(cmd) => cmd.exec(&client).await,
+                OrgSubCommand::List(cmd) => cmd.exec(&client).await,
+                OrgSubCommand::Members(cmd) => cmd.exec(&client).await,
             }
         }
         SubCommand::Vote(VoteCommand { cmd }) => {
@@ -155,6 +260,18 @@ async fn run() -> Result<(), Error> {
             match cmd {
                 BankSubCommand::OpenAccount(cmd) => cmd.exec(&client).await,
                 BankSubCommand::OpenAccount2(cmd) => cmd.exec(&client).await,
+                BankSubCommand::Show(cmd) => cmd.exec(&client).await,
+                BankSubCommand::List(cmd) => cmd.exec(&client).await,
+                BankSubCommand::Spends(cmd) => cmd.exec(&client).await,
+                BankSubCommand::ProposeSpend(cmd) => cmd.exec(&client).await,
+                BankSubCommand::TriggerSpendVote(cmd) => {
+                    cmd.exec(&client).await
+                }
+                BankSubCommand::SudoApproveSpend(cmd) => {
+                    cmd.exec(&client).await
+                }
+                BankSubCommand::PollSpend(cmd) => cmd.exec(&client).await,
+                BankSubCommand::Close(cmd) => cmd.exec(&client).await,
             }
         }
         SubCommand::Bounty(BountyCommand { cmd }) => {
@@ -182,8 +299,31 @@ async fn run() -> Result<(), Error> {
                     cmd.exec(&client).await
                 }
                 BountySubCommand::PollMilestone(cmd) => cmd.exec(&client).await,
+                BountySubCommand::Show(cmd) => cmd.exec(&client).await,
+                BountySubCommand::List(cmd) => cmd.exec(&client).await,
+                BountySubCommand::ShowApplication(cmd) => {
+                    cmd.exec(&client).await
+                }
+                BountySubCommand::ListApplications(cmd) => {
+                    cmd.exec(&client).await
+                }
+                BountySubCommand::ShowMilestone(cmd) => cmd.exec(&client).await,
+                BountySubCommand::ListMilestones(cmd) => {
+                    cmd.exec(&client).await
+                }
+            }
+        }
+        SubCommand::Watch(cmd) => cmd.exec(&client).await,
+        SubCommand::Tx(TxCommand { cmd }) => {
+            match cmd {
+                TxSubCommand::Prepare(cmd) => cmd.exec(&client).await,
+                TxSubCommand::Sign(_) => {
+                    unreachable!("signed before connecting to a node")
+                }
+                TxSubCommand::Submit(cmd) => cmd.exec(&client).await,
             }
         }
+        SubCommand::Apply(cmd) => cmd.exec(&client).await,
         SubCommand::Run => {
             loop {
                 async_std::task::sleep(std::time::Duration::from_millis(100))
@@ -102,7 +102,7 @@ pub mod mock {
                 cache_size: 128,
             },
             builder: test_node::service::new_full,
-            chain_spec: test_node::chain_spec::development_config(),
+            chain_spec: test_node::chain_spec::development_config(None),
             role: Role::Authority(AccountKeyring::Alice),
         };
         let client = SubxtClient::new(config).unwrap().into();
@@ -1,4 +1,5 @@
 use crate::Runtime;
+use sc_service::ChainSpec as _;
 use sled::{
     transaction::TransactionError,
     Tree,
@@ -8,7 +9,13 @@ use sp_database::{
     Database,
     Transaction,
 };
-use std::sync::Arc;
+use std::{
+    path::{
+        Path,
+        PathBuf,
+    },
+    sync::Arc,
+};
 use substrate_subxt::{
     client::{
         DatabaseConfig,
@@ -19,6 +26,10 @@ use substrate_subxt::{
     Client,
     ClientBuilder,
 };
+use test_node::{
+    chain_spec::ChainSpec,
+    ChainType,
+};
 use thiserror::Error;
 
 #[derive(Debug, Error)]
@@ -27,6 +38,8 @@ pub enum Error {
     Subxt(#[from] substrate_subxt::Error),
     #[error(transparent)]
     ScService(#[from] sc_service::Error),
+    #[error(transparent)]
+    Sled(#[from] sled::Error),
     #[error("Invalid chain spec: {0}")]
     ChainSpec(#[from] ChainSpecError),
 }
@@ -35,13 +48,22 @@ pub enum Error {
 #[error("Invalid chain spec: {0}")]
 pub struct ChainSpecError(String);
 
+/// Loads the `dev` or `local` chain spec or a chain spec json file
+pub fn load_chain_spec(chain: &str) -> Result<ChainSpec, Error> {
+    Ok(match chain {
+        "dev" => ChainType::Development.chain_spec(),
+        "local" => ChainType::Local.chain_spec(),
+        path => {
+            ChainSpec::from_json_file(PathBuf::from(path))
+                .map_err(ChainSpecError)?
+        }
+    })
+}
+
 pub async fn build_light_client(
     tree: Tree,
-    chain_spec: &'static [u8],
+    chain_spec: ChainSpec,
 ) -> Result<Client<Runtime>, Error> {
-    let chain_spec =
-        test_node::chain_spec::ChainSpec::from_json_bytes(chain_spec)
-            .map_err(ChainSpecError)?;
     let config = SubxtClientConfig {
         impl_name: test_node::IMPL_NAME,
         impl_version: test_node::IMPL_VERSION,
@@ -59,6 +81,17 @@ pub async fn build_light_client(
     Ok(client)
 }
 
+/// Starts a light client for `chain` which keeps the chain data in a tree of
+/// the sled database in `path`, one tree per chain
+pub async fn open_light_client(
+    path: &Path,
+    chain: &str,
+) -> Result<Client<Runtime>, Error> {
+    let chain_spec = load_chain_spec(chain)?;
+    let tree = sled::open(path)?.open_tree(chain_spec.id())?;
+    build_light_client(tree, chain_spec).await
+}
+
 struct Key;
 
 impl Key {
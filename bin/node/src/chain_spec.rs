@@ -1,4 +1,8 @@
 use sc_service::ChainType;
+use serde::{
+    Deserialize,
+    Serialize,
+};
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 use sp_core::{
     sr25519,
@@ -10,10 +14,18 @@ use sp_runtime::traits::{
     IdentifyAccount,
     Verify,
 };
+use std::{
+    fs::File,
+    path::Path,
+};
 use suntime::{
     AccountId,
     AuraConfig,
+    Balance,
     BalancesConfig,
+    BankConfig,
+    BlockNumber,
+    BountyConfig,
     GenesisConfig,
     GrandpaConfig,
     IndicesConfig,
@@ -23,11 +35,71 @@ use suntime::{
     SystemConfig,
     WASM_BINARY,
 };
+use util::{
+    court::ResolutionMetadata,
+    organization::OrganizationGenesis,
+};
 use utils_identity::cid::CidBytes;
 
 /// Specialized `ChainSpec`.
 pub type ChainSpec = sc_service::GenericChainSpec<GenesisConfig>;
 
+/// Organizations, bank accounts and bounties loaded from the `--org-genesis` file
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct OrgGenesis {
+    /// Balances of accounts, in addition to the endowed development accounts
+    #[serde(default)]
+    pub balances: Vec<(AccountId, Balance)>,
+    /// Flat or weighted organizations, registered in order from id 1
+    #[serde(default)]
+    pub orgs: Vec<OrganizationGenesis<AccountId, u64, u64, CidBytes>>,
+    /// Bank accounts opened with a deposit from the opener
+    #[serde(default)]
+    pub banks: Vec<BankGenesis>,
+    /// Bounties posted with a reservation from the poster
+    #[serde(default)]
+    pub bounties: Vec<BountyGenesis>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct BankGenesis {
+    pub opener: AccountId,
+    pub org: u64,
+    pub deposit: Balance,
+    #[serde(default)]
+    pub controller: Option<AccountId>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct BountyGenesis {
+    pub poster: AccountId,
+    #[serde(default)]
+    pub description: CidBytes,
+    pub amount: Balance,
+    pub acceptance_committee: ResolutionMetadata<u64, u64, BlockNumber>,
+    #[serde(default)]
+    pub supervision_committee:
+        Option<ResolutionMetadata<u64, u64, BlockNumber>>,
+}
+
+impl OrgGenesis {
+    /// Parse the org genesis from a JSON file
+    pub fn from_json_file(path: &Path) -> Result<Self, String> {
+        let file = File::open(path).map_err(|e| {
+            format!(
+                "Error opening org genesis file `{}`: {}",
+                path.display(),
+                e
+            )
+        })?;
+        serde_json::from_reader(file)
+            .map_err(|e| format!("Error parsing org genesis file: {}", e))
+    }
+}
+
 /// Helper function to generate a crypto pair from seed
 pub fn get_from_seed<TPublic: Public>(
     seed: &str,
@@ -52,12 +124,12 @@ pub fn get_authority_keys_from_seed(s: &str) -> (AuraId, GrandpaId) {
     (get_from_seed::<AuraId>(s), get_from_seed::<GrandpaId>(s))
 }
 
-pub fn development_config() -> ChainSpec {
+pub fn development_config(org_genesis: Option<OrgGenesis>) -> ChainSpec {
     ChainSpec::from_genesis(
         "Development",
         "dev",
         ChainType::Development,
-        || {
+        move || {
             testnet_genesis(
                 // initial authorities
                 vec![get_authority_keys_from_seed("Alice")],
@@ -77,6 +149,7 @@ pub fn development_config() -> ChainSpec {
                     get_account_id_from_seed::<sr25519::Public>("Alice"),
                     get_account_id_from_seed::<sr25519::Public>("Bob"),
                 ],
+                org_genesis.clone(),
                 true,
             )
         },
@@ -88,12 +161,12 @@ pub fn development_config() -> ChainSpec {
     )
 }
 
-pub fn local_testnet_config() -> ChainSpec {
+pub fn local_testnet_config(org_genesis: Option<OrgGenesis>) -> ChainSpec {
     ChainSpec::from_genesis(
         "Local Testnet",
         "local_testnet",
         ChainType::Local,
-        || {
+        move || {
             testnet_genesis(
                 // initial authorities
                 vec![
@@ -132,6 +205,7 @@ pub fn local_testnet_config() -> ChainSpec {
                     get_account_id_from_seed::<sr25519::Public>("Eve"),
                     get_account_id_from_seed::<sr25519::Public>("Ferdie"),
                 ],
+                org_genesis.clone(),
                 true,
             )
         },
@@ -149,8 +223,14 @@ pub fn testnet_genesis(
     endowed_accounts: Vec<AccountId>,
     first_org_value_constitution: CidBytes,
     first_org_flat_membership: Vec<AccountId>,
+    org_genesis: Option<OrgGenesis>,
     _enable_println: bool,
 ) -> GenesisConfig {
+    // the organizations of an org genesis file replace the first organization
+    let (first_org_flat_membership, org_genesis) = match org_genesis {
+        Some(org_genesis) => (Vec::new(), org_genesis),
+        None => (first_org_flat_membership, OrgGenesis::default()),
+    };
     GenesisConfig {
         frame_system: Some(SystemConfig {
             code: WASM_BINARY.to_vec(),
@@ -159,8 +239,10 @@ pub fn testnet_genesis(
         pallet_balances: Some(BalancesConfig {
             balances: endowed_accounts
                 .iter()
+                .filter(|k| !org_genesis.balances.iter().any(|(a, _)| a == *k))
                 .cloned()
                 .map(|k| (k, 1 << 60))
+                .chain(org_genesis.balances.iter().cloned())
                 .collect(),
         }),
         pallet_indices: Some(IndicesConfig { indices: vec![] }),
@@ -168,6 +250,29 @@ pub fn testnet_genesis(
             first_organization_supervisor: root_key.clone(),
             first_organization_value_constitution: first_org_value_constitution,
             first_organization_flat_membership: first_org_flat_membership,
+            organizations: org_genesis.orgs,
+        }),
+        bank: Some(BankConfig {
+            banks: org_genesis
+                .banks
+                .into_iter()
+                .map(|b| (b.opener, b.org, b.deposit, b.controller))
+                .collect(),
+        }),
+        bounty: Some(BountyConfig {
+            bounties: org_genesis
+                .bounties
+                .into_iter()
+                .map(|b| {
+                    (
+                        b.poster,
+                        b.description,
+                        b.amount,
+                        b.acceptance_committee,
+                        b.supervision_committee,
+                    )
+                })
+                .collect(),
         }),
         pallet_aura: Some(AuraConfig {
             authorities: initial_authorities
@@ -2,6 +2,7 @@ use sc_cli::{
     RunCmd,
     Subcommand,
 };
+use std::path::PathBuf;
 use structopt::StructOpt;
 
 #[derive(Debug, StructOpt)]
@@ -11,4 +12,9 @@ pub struct Cli {
 
     #[structopt(flatten)]
     pub run: RunCmd,
+
+    /// JSON file with the organizations, bank accounts and bounties of the
+    /// `dev` or `local` genesis, replacing the default organization
+    #[structopt(long = "org-genesis", value_name = "PATH", parse(from_os_str))]
+    pub org_genesis: Option<PathBuf>,
 }
@@ -39,10 +39,20 @@ impl SubstrateCli for Cli {
         &self,
         id: &str,
     ) -> Result<Box<dyn sc_service::ChainSpec>, String> {
+        let org_genesis = self
+            .org_genesis
+            .as_ref()
+            .map(|path| chain_spec::OrgGenesis::from_json_file(path))
+            .transpose()?;
         Ok(match id {
-            "dev" => Box::new(chain_spec::development_config()),
-            "" | "local" => Box::new(chain_spec::local_testnet_config()),
+            "dev" => Box::new(chain_spec::development_config(org_genesis)),
+            "" | "local" => {
+                Box::new(chain_spec::local_testnet_config(org_genesis))
+            }
             path => {
+                if org_genesis.is_some() {
+                    return Err("--org-genesis is only supported for the dev and local chains".into())
+                }
                 Box::new(chain_spec::ChainSpec::from_json_file(
                     std::path::PathBuf::from(path),
                 )?)
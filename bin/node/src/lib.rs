@@ -33,8 +33,8 @@ pub enum ChainType {
 impl ChainType {
     pub fn chain_spec(&self) -> ChainSpec {
         match self {
-            Self::Development => chain_spec::development_config(),
-            Self::Local => chain_spec::local_testnet_config(),
+            Self::Development => chain_spec::development_config(None),
+            Self::Local => chain_spec::local_testnet_config(None),
         }
     }
 }
@@ -20,6 +20,9 @@ use suntime::{
     RuntimeApi,
 };
 
+/// The custom rpc methods served by the node
+pub type RpcExtension = jsonrpc_core::IoHandler<sc_rpc::Metadata>;
+
 // Our native executor instance.
 native_executor_instance!(
     pub Executor,
@@ -56,6 +59,17 @@ macro_rules! new_full_start {
                 builder.prometheus_registry(),
             ))
         })?
+        .with_rpc_extensions(
+            |builder| -> Result<crate::service::RpcExtension, _> {
+                let mut io = jsonrpc_core::IoHandler::default();
+                io.extend_with(vote_rpc::VoteApi::to_delegate(
+                    vote_rpc::Vote::<_, _, utils_identity::cid::CidBytes>::new(
+                        builder.client().clone(),
+                    ),
+                ));
+                Ok(io)
+            },
+        )?
         .with_import_queue(
             |_config,
              client,
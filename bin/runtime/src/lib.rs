@@ -42,6 +42,7 @@ use sp_std::prelude::*;
 #[cfg(feature = "std")]
 use sp_version::NativeVersion;
 use sp_version::RuntimeVersion;
+use util::share::ShareClass;
 use utils_identity::cid::CidBytes;
 
 // A few exports that help ease life for downstream crates.
@@ -198,9 +199,9 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
     // and set impl_version to 0. If only runtime
     // implementation changes and behavior does not, then leave spec_version as
     // is and increment impl_version.
-    spec_version: 1,
-    impl_version: 1,
-    transaction_version: 1,
+    spec_version: 2,
+    impl_version: 0,
+    transaction_version: 2,
     apis: RUNTIME_API_VERSIONS,
 };
 
@@ -365,6 +366,14 @@ impl pallet_sudo::Trait for Runtime {
 pub use org;
 parameter_types! {
     pub const ReservationLimit: u32 = 10000;
+    pub const MaxMembers: u32 = 10000;
+    pub const MaxOrgDepth: u32 = 8;
+    pub const OrgVoteDuration: BlockNumber = 10 * MINUTES;
+    pub const SupervisorTermLength: BlockNumber = 30 * DAYS;
+    pub const MaxConsecutiveSupervisorTerms: u32 = 2;
+    pub const SignalShareClass: ShareClass = ShareClass::Voting;
+    pub const MembershipDeposit: Balance = 1000;
+    pub const MaxMembershipApplications: u32 = 100;
 }
 impl org::Trait for Runtime {
     type Event = Event;
@@ -372,12 +381,31 @@ impl org::Trait for Runtime {
     type OrgId = u64;
     type Shares = u64;
     type ReservationLimit = ReservationLimit;
+    type MaxMembers = MaxMembers;
+    type MaxOrgDepth = MaxOrgDepth;
+    type RemovalGuard = (Vote, Bank, Bounty);
+    type OrgVote = Vote;
+    type OrgVoteId = u64;
+    type OrgVoteDuration = OrgVoteDuration;
+    type SignalShareClass = SignalShareClass;
+    type SupervisorTermLength = SupervisorTermLength;
+    type MaxConsecutiveSupervisorTerms = MaxConsecutiveSupervisorTerms;
+    type MembershipCurrency = Balances;
+    type MembershipDeposit = MembershipDeposit;
+    type MaxMembershipApplications = MaxMembershipApplications;
+    type ShareChangeHandler = Vote;
 }
 pub use vote;
+parameter_types! {
+    pub const MaxClosedVoteHistory: u32 = 100;
+    pub const MaxVoteControlDuration: BlockNumber = 7 * DAYS;
+}
 impl vote::Trait for Runtime {
     type Event = Event;
     type VoteId = u64;
     type Signal = u64;
+    type MaxClosedVoteHistory = MaxClosedVoteHistory;
+    type MaxVoteControlDuration = MaxVoteControlDuration;
 }
 parameter_types! {
     pub const MinimumDisputeAmount: u128 = 10;
@@ -393,12 +421,14 @@ pub use donate;
 parameter_types! {
     pub const TransactionFee: u128 = 3;
     pub const TreasuryModuleId: ModuleId = ModuleId(*b"py/trsry");
+    pub const PayoutShareClass: ShareClass = ShareClass::Revenue;
 }
 impl donate::Trait for Runtime {
     type Event = Event;
     type Currency = Balances;
     type TransactionFee = TransactionFee;
     type Treasury = TreasuryModuleId;
+    type PayoutShareClass = PayoutShareClass;
 }
 pub use bank;
 parameter_types! {
@@ -442,8 +472,8 @@ construct_runtime!(
         Vote: vote::{Module, Call, Storage, Event<T>},
         Court: court::{Module, Call, Storage, Event<T>},
         Donate: donate::{Module, Call, Event<T>},
-        Bank: bank::{Module, Call, Storage, Event<T>},
-        Bounty: bounty::{Module, Call, Storage, Event<T>},
+        Bank: bank::{Module, Call, Config<T>, Storage, Event<T>},
+        Bounty: bounty::{Module, Call, Config<T>, Storage, Event<T>},
     }
 );
 
@@ -587,4 +617,70 @@ impl_runtime_apis! {
             None
         }
     }
+
+    impl vote_rpc_runtime_api::VoteApi<Block, AccountId, u64, u64, u64, BlockNumber, CidBytes> for Runtime {
+        fn open_votes(org: u64) -> Vec<u64> {
+            Vote::live_votes(org)
+        }
+
+        fn closed_votes(org: u64) -> Vec<u64> {
+            Vote::closed_votes(org)
+        }
+
+        fn account_votes(who: AccountId) -> Vec<u64> {
+            Vote::votes_for_account(&who)
+        }
+
+        fn pending_votes(who: AccountId) -> Vec<u64> {
+            Vote::pending_votes_for_account(&who)
+        }
+
+        fn vote_summary(
+            vote_id: u64,
+            who: Option<AccountId>,
+        ) -> Option<util::vote::VoteSummary<u64, BlockNumber, CidBytes>> {
+            Vote::vote_summary(vote_id, who)
+        }
+
+        fn vote_justifications(
+            vote_id: u64,
+        ) -> Vec<(AccountId, util::vote::VoterView, CidBytes)> {
+            Vote::vote_justifications(vote_id)
+        }
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    impl frame_benchmarking::Benchmark<Block> for Runtime {
+        fn dispatch_benchmark(
+            pallet: Vec<u8>,
+            benchmark: Vec<u8>,
+            lowest_range_values: Vec<u32>,
+            highest_range_values: Vec<u32>,
+            steps: Vec<u32>,
+            repeat: u32,
+        ) -> Result<Vec<frame_benchmarking::BenchmarkBatch>, sp_runtime::RuntimeString> {
+            use frame_benchmarking::{
+                add_benchmark,
+                BenchmarkBatch,
+                Benchmarking,
+            };
+
+            let mut batches = Vec::<BenchmarkBatch>::new();
+            let params = (
+                &pallet,
+                &benchmark,
+                &lowest_range_values,
+                &highest_range_values,
+                &steps,
+                repeat,
+            );
+
+            add_benchmark!(params, batches, b"org", Org);
+
+            if batches.is_empty() {
+                return Err("Benchmark not found for this pallet.".into())
+            }
+            Ok(batches)
+        }
+    }
 }
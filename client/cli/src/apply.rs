@@ -0,0 +1,393 @@
+use crate::{
+    async_trait,
+    AbstractClient,
+    Bank,
+    Bounty,
+    Command,
+    Donate,
+    Error,
+    Org,
+    Output,
+    Pair,
+    Result,
+    Runtime,
+    Vote,
+};
+use bounty_client::{
+    Account,
+    BountyDocument,
+    ConstitutionDocument,
+    OfflineCall,
+    SunshineEvent,
+    DEFAULT_ERA_PERIOD,
+};
+use clap::Clap;
+use core::fmt::{
+    Debug,
+    Display,
+};
+use libipld::{
+    cid::{
+        Cid,
+        Codec,
+    },
+    multihash::Blake2b256,
+};
+use serde::Deserialize;
+use std::{
+    collections::HashMap,
+    path::PathBuf,
+};
+use substrate_subxt::{
+    sp_core::crypto::Ss58Codec,
+    system::System,
+};
+use util::court::ResolutionMetadata;
+use utils_identity::cid::CidBytes;
+
+/// A declarative plan applied by `apply`, every section is applied in order
+/// and later steps refer to the orgs, banks and bounties of earlier steps by
+/// their name or to existing ones by their id
+///
+/// ```toml
+/// [[orgs]]
+/// name = "core"
+/// constitution = "build the website"
+/// members = ["5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY"]
+///
+/// [[shares]]
+/// org = "core"
+/// who = "5FHneW46xGXgs5mUiveU4sbTyGBzmstUspZC92UhjJM694ty"
+/// shares = 10
+///
+/// [[banks]]
+/// name = "treasury"
+/// org = "core"
+/// seed = 1000
+///
+/// [[bounties]]
+/// name = "website"
+/// description = "a website for the org"
+/// amount = 100
+/// acceptance_org = "core"
+/// passage_threshold = 1
+/// ```
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct Plan {
+    #[serde(default)]
+    pub orgs: Vec<OrgStep>,
+    #[serde(default)]
+    pub shares: Vec<SharesStep>,
+    #[serde(default)]
+    pub banks: Vec<BankStep>,
+    #[serde(default)]
+    pub bounties: Vec<BountyStep>,
+    #[serde(default)]
+    pub votes: Vec<VoteStep>,
+}
+
+/// An id on chain or the name of a step of the plan
+#[derive(Clone, Debug, Deserialize)]
+#[serde(untagged)]
+pub enum Ref {
+    Id(u64),
+    Name(String),
+}
+
+/// Registers a weighted org if `weighted_members` is set, a flat org otherwise
+#[derive(Clone, Debug, Deserialize)]
+pub struct OrgStep {
+    pub name: String,
+    pub sudo: Option<String>,
+    pub parent: Option<Ref>,
+    pub constitution: String,
+    #[serde(default)]
+    pub members: Vec<String>,
+    #[serde(default)]
+    pub weighted_members: Vec<(String, u64)>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct SharesStep {
+    pub org: Ref,
+    pub who: String,
+    pub shares: u64,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct BankStep {
+    pub name: String,
+    pub org: Ref,
+    pub seed: u128,
+    pub operator: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct BountyStep {
+    pub name: String,
+    pub description: String,
+    pub amount: u128,
+    pub acceptance_org: Ref,
+    pub passage_threshold: u64,
+    pub rejection_threshold: Option<u64>,
+    pub duration: Option<u32>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct VoteStep {
+    pub org: Ref,
+    pub topic: Option<String>,
+    pub support_requirement: u64,
+    pub turnout_requirement: Option<u64>,
+    pub duration: Option<u32>,
+}
+
+/// The ids of the named steps applied so far
+#[derive(Default)]
+struct Ids {
+    orgs: HashMap<String, u64>,
+    banks: HashMap<String, u64>,
+    bounties: HashMap<String, u64>,
+}
+
+fn resolve(ids: &HashMap<String, u64>, reference: &Ref) -> Result<u64> {
+    match reference {
+        Ref::Id(id) => Ok(*id),
+        Ref::Name(name) => {
+            ids.get(name).copied().ok_or_else(|| {
+                Error::InvalidPlan(format!("unknown name {}", name))
+            })
+        }
+    }
+}
+
+fn insert(ids: &mut HashMap<String, u64>, name: &str, id: u64) -> Result<()> {
+    if ids.insert(name.to_string(), id).is_some() {
+        return Err(Error::InvalidPlan(format!("duplicate name {}", name)))
+    }
+    Ok(())
+}
+
+fn account<T: System>(account: &str) -> Result<<T as System>::AccountId>
+where
+    <T as System>::AccountId: Ss58Codec,
+{
+    let account: Account<T> = account.parse()?;
+    Ok(account.id)
+}
+
+/// Applies a plan of orgs, share issuance, banks, bounties and votes and
+/// prints a summary of the steps
+#[derive(Clone, Debug, Clap)]
+pub struct ApplyCommand {
+    /// The toml file of the plan.
+    pub plan: PathBuf,
+}
+
+#[async_trait]
+impl<T: Runtime + Org + Vote + Donate + Bank + Bounty, P: Pair> Command<T, P>
+    for ApplyCommand
+where
+    <T as System>::AccountId: Ss58Codec,
+    <T as System>::Index: From<u32>,
+    <T as System>::BlockNumber: From<u32>,
+    <T as Org>::OrgId: From<u64> + Into<u64> + Display,
+    <T as Org>::Shares: From<u64> + Display,
+    <T as Org>::IpfsReference: From<CidBytes> + Debug,
+    <T as Vote>::Signal: From<u64>,
+    <T as Vote>::VoteId: Display,
+    <T as Bank>::Currency: From<u128> + Display,
+    <T as Bounty>::BountyId: Into<u64> + Display,
+{
+    async fn exec(&self, client: &dyn AbstractClient<T, P>) -> Result<Output> {
+        let plan = async_std::fs::read_to_string(&self.plan).await?;
+        let plan: Plan = toml::from_str(&plan)
+            .map_err(|err| Error::InvalidPlan(err.to_string()))?;
+        let mut ids = Ids::default();
+        let mut steps = Vec::new();
+
+        for step in &plan.orgs {
+            let sudo = step.sudo.as_deref().map(account::<T>).transpose()?;
+            let parent = step
+                .parent
+                .as_ref()
+                .map(|parent| resolve(&ids.orgs, parent))
+                .transpose()?
+                .map(Into::into);
+            let constitution = client
+                .insert_constitution(&ConstitutionDocument {
+                    constitution: step.constitution.clone(),
+                })
+                .await?;
+            let (org, caller) = if step.weighted_members.is_empty() {
+                let members = step
+                    .members
+                    .iter()
+                    .map(|member| account::<T>(member))
+                    .collect::<Result<Vec<_>>>()?;
+                let event = client
+                    .register_flat_org(sudo, parent, constitution, &members)
+                    .await?;
+                (event.new_id, event.caller)
+            } else {
+                let members = step
+                    .weighted_members
+                    .iter()
+                    .map(|(member, shares)| {
+                        Ok((account::<T>(member)?, (*shares).into()))
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                let event = client
+                    .register_weighted_org(sudo, parent, constitution, &members)
+                    .await?;
+                (event.new_id, event.caller)
+            };
+            insert(&mut ids.orgs, &step.name, org.into())?;
+            steps.push(Output::new(
+                format!(
+                    "Account {} registered org {} with OrgId {}",
+                    caller, step.name, org
+                ),
+                serde_json::json!({
+                    "step": "org",
+                    "name": step.name,
+                    "org": org,
+                }),
+            ));
+        }
+
+        // share issuances do not depend on each other, they are signed with
+        // consecutive nonces and submitted together
+        if !plan.shares.is_empty() {
+            let signer = client.signer().await?.account_id().clone();
+            let mut txs = Vec::with_capacity(plan.shares.len());
+            let mut nonce = None;
+            for step in &plan.shares {
+                let call = OfflineCall::IssueShares {
+                    org: resolve(&ids.orgs, &step.org)?.into(),
+                    who: account::<T>(&step.who)?,
+                    shares: step.shares.into(),
+                };
+                let tx = client
+                    .prepare_transaction(
+                        &signer,
+                        nonce,
+                        DEFAULT_ERA_PERIOD,
+                        call,
+                    )
+                    .await?;
+                nonce = Some(tx.nonce + <T as System>::Index::from(1u32));
+                txs.push(client.sign_transaction(&tx).await?);
+            }
+            let results = futures::future::try_join_all(
+                txs.into_iter().map(|tx| client.submit_transaction(tx)),
+            )
+            .await?;
+            for event in results.into_iter().flatten() {
+                if let SunshineEvent::SharesIssued(event) = event {
+                    steps.push(Output::new(
+                        format!(
+                            "{} shares issued to {} in OrgId {}",
+                            event.shares, event.who, event.organization
+                        ),
+                        serde_json::json!({
+                            "step": "shares",
+                            "org": event.organization,
+                            "account": event.who.to_string(),
+                            "shares": event.shares.to_string(),
+                        }),
+                    ));
+                }
+            }
+        }
+
+        for step in &plan.banks {
+            let org = resolve(&ids.orgs, &step.org)?;
+            let operator =
+                step.operator.as_deref().map(account::<T>).transpose()?;
+            let event = client
+                .open_org_bank_account(step.seed.into(), org.into(), operator)
+                .await?;
+            let bank = u64::from_be_bytes(event.new_bank_id.0);
+            insert(&mut ids.banks, &step.name, bank)?;
+            steps.push(Output::new(
+                format!(
+                    "Opened bank {} with id {} and balance {} for OrgId {}",
+                    step.name, bank, event.seed, event.hosting_org
+                ),
+                serde_json::json!({
+                    "step": "bank",
+                    "name": step.name,
+                    "bank": bank,
+                    "org": event.hosting_org,
+                    "seed": event.seed.to_string(),
+                }),
+            ));
+        }
+
+        for step in &plan.bounties {
+            let org = resolve(&ids.orgs, &step.acceptance_org)?;
+            let acceptance_committee = ResolutionMetadata::new(
+                org.into(),
+                step.passage_threshold.into(),
+                step.rejection_threshold.map(Into::into),
+                step.duration.map(Into::into),
+            );
+            let (_, event) = client
+                .account_posts_bounty(
+                    &BountyDocument {
+                        description: step.description.clone(),
+                    },
+                    step.amount.into(),
+                    acceptance_committee,
+                    None,
+                )
+                .await?;
+            insert(&mut ids.bounties, &step.name, event.new_bounty_id.into())?;
+            steps.push(Output::new(
+                format!(
+                    "Posted bounty {} with id {} and amount {}",
+                    step.name,
+                    event.new_bounty_id,
+                    event.amount_reserved_for_bounty
+                ),
+                serde_json::json!({
+                    "step": "bounty",
+                    "name": step.name,
+                    "bounty": event.new_bounty_id,
+                    "amount": event.amount_reserved_for_bounty.to_string(),
+                }),
+            ));
+        }
+
+        for step in &plan.votes {
+            let org = resolve(&ids.orgs, &step.org)?;
+            let topic = step.topic.as_ref().map(|topic| {
+                let hash = Blake2b256::digest(topic.as_bytes());
+                CidBytes::from(&Cid::new_v1(Codec::Raw, hash)).into()
+            });
+            let event = client
+                .create_threshold_approval_vote(
+                    topic,
+                    org.into(),
+                    step.support_requirement.into(),
+                    step.turnout_requirement.map(Into::into),
+                    step.duration.map(Into::into),
+                )
+                .await?;
+            steps.push(Output::new(
+                format!(
+                    "Started vote {} in OrgId {}",
+                    event.new_vote_id, event.org
+                ),
+                serde_json::json!({
+                    "step": "vote",
+                    "vote": event.new_vote_id,
+                    "org": event.org,
+                }),
+            ));
+        }
+
+        Ok(Output::list(steps))
+    }
+}
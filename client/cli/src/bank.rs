@@ -5,13 +5,19 @@ use crate::{
     Bounty,
     Command,
     Donate,
+    Error,
     Org,
+    Output,
     Pair,
     Result,
     Runtime,
     Vote,
 };
-use bounty_client::Account;
+use bounty_client::{
+    Account,
+    Page,
+    SpendProposalOf,
+};
 use clap::Clap;
 use core::fmt::{
     Debug,
@@ -21,6 +27,34 @@ use substrate_subxt::{
     sp_core::crypto::Ss58Codec,
     system::System,
 };
+use util::bank::OnChainTreasuryID;
+
+/// A line and a json value describing a spend proposal
+fn spend_summary<T: Bank>(
+    spend_id: <T as Bank>::SpendId,
+    spend: SpendProposalOf<T>,
+) -> (String, serde_json::Value)
+where
+    <T as System>::AccountId: Ss58Codec,
+    <T as Bank>::Currency: Display,
+    <T as Bank>::SpendId: Display,
+{
+    (
+        format!(
+            "Spend {} of {} to {} in state {:?}",
+            spend_id,
+            spend.amount(),
+            spend.dest(),
+            spend.state()
+        ),
+        serde_json::json!({
+            "spend": spend_id,
+            "amount": spend.amount().to_string(),
+            "dest": spend.dest().to_string(),
+            "state": format!("{:?}", spend.state()),
+        }),
+    )
+}
 
 #[derive(Clone, Debug, Clap)]
 pub struct BankOpenOrgAccountCommand {
@@ -37,7 +71,7 @@ where
     <T as Org>::OrgId: From<u64> + Display,
     <T as Bank>::Currency: From<u128> + Display,
 {
-    async fn exec(&self, client: &dyn AbstractClient<T, P>) -> Result<()> {
+    async fn exec(&self, client: &dyn AbstractClient<T, P>) -> Result<Output> {
         let bank_operator: Option<T::AccountId> =
             if let Some(acc) = &self.bank_operator {
                 let new_acc: Account<T> = acc.parse()?;
@@ -52,10 +86,328 @@ where
                 bank_operator,
             )
             .await?;
-        println!(
-            "Account {} initialized new bank account {:?} with balance {} for Org {} with bank operator {:?}",
-            event.seeder, event.new_bank_id, event.seed, event.hosting_org, event.bank_operator
-        );
-        Ok(())
+        Ok(Output::new(
+            format!(
+                "Account {} initialized new bank account {:?} with balance {} for Org {} with bank operator {:?}",
+                event.seeder, event.new_bank_id, event.seed, event.hosting_org, event.bank_operator
+            ),
+            serde_json::json!({
+                "seeder": event.seeder.to_string(),
+                "bank": u64::from_be_bytes(event.new_bank_id.0),
+                "seed": event.seed.to_string(),
+                "org": event.hosting_org,
+                "operator": event.bank_operator.map(|operator| operator.to_string()),
+            }),
+        ))
+    }
+}
+
+#[derive(Clone, Debug, Clap)]
+pub struct BankShowCommand {
+    pub bank_id: u64,
+}
+
+#[async_trait]
+impl<T: Runtime + Org + Vote + Donate + Bank + Bounty, P: Pair> Command<T, P>
+    for BankShowCommand
+where
+    <T as System>::AccountId: Ss58Codec,
+    <T as Org>::OrgId: Display,
+    <T as Bank>::Currency: Display,
+    <T as Bank>::SpendId: Display,
+{
+    async fn exec(&self, client: &dyn AbstractClient<T, P>) -> Result<Output> {
+        let id = OnChainTreasuryID(self.bank_id.to_be_bytes());
+        let bank = client.bank(id).await?.ok_or(Error::BankNotFound)?;
+        let balance = client.bank_account_data(id).await?;
+        let (lines, proposals): (Vec<_>, Vec<_>) = client
+            .spend_proposals(id, Page::default())
+            .await?
+            .into_iter()
+            .map(|(spend_id, spend)| spend_summary::<T>(spend_id, spend))
+            .unzip();
+        let mut text = vec![format!(
+            "Bank {} of OrgId {} with controller {:?} and balance data {:?}",
+            self.bank_id,
+            bank.org(),
+            bank.controller(),
+            balance
+        )];
+        text.extend(lines);
+        Ok(Output::new(
+            text.join("\n"),
+            serde_json::json!({
+                "bank": self.bank_id,
+                "org": bank.org(),
+                "controller": bank.controller().map(|controller| controller.to_string()),
+                "balance": format!("{:?}", balance),
+                "proposals": proposals,
+            }),
+        ))
+    }
+}
+
+#[derive(Clone, Debug, Clap)]
+pub struct BankListCommand {
+    pub org: u64,
+    #[clap(long = "offset", default_value = "0")]
+    pub offset: u32,
+    #[clap(long = "limit", default_value = "20")]
+    pub limit: u32,
+}
+
+#[async_trait]
+impl<T: Runtime + Org + Vote + Donate + Bank + Bounty, P: Pair> Command<T, P>
+    for BankListCommand
+where
+    <T as System>::AccountId: Ss58Codec,
+    <T as Org>::OrgId: From<u64> + Display,
+{
+    async fn exec(&self, client: &dyn AbstractClient<T, P>) -> Result<Output> {
+        let page = Page {
+            offset: self.offset,
+            limit: self.limit,
+        };
+        let banks = client
+            .org_banks(self.org.into(), page)
+            .await?
+            .into_iter()
+            .map(|(id, bank)| {
+                let id = u64::from_be_bytes(id.0);
+                Output::new(
+                    format!(
+                        "Bank {} of OrgId {} with controller {:?}",
+                        id,
+                        bank.org(),
+                        bank.controller()
+                    ),
+                    serde_json::json!({
+                        "bank": id,
+                        "org": bank.org(),
+                        "controller": bank.controller().map(|controller| controller.to_string()),
+                    }),
+                )
+            })
+            .collect();
+        Ok(Output::list(banks))
+    }
+}
+
+#[derive(Clone, Debug, Clap)]
+pub struct BankSpendsCommand {
+    pub bank_id: u64,
+    #[clap(long = "offset", default_value = "0")]
+    pub offset: u32,
+    #[clap(long = "limit", default_value = "20")]
+    pub limit: u32,
+}
+
+#[async_trait]
+impl<T: Runtime + Org + Vote + Donate + Bank + Bounty, P: Pair> Command<T, P>
+    for BankSpendsCommand
+where
+    <T as System>::AccountId: Ss58Codec,
+    <T as Bank>::Currency: Display,
+    <T as Bank>::SpendId: Display,
+{
+    async fn exec(&self, client: &dyn AbstractClient<T, P>) -> Result<Output> {
+        let page = Page {
+            offset: self.offset,
+            limit: self.limit,
+        };
+        let spends = client
+            .spend_proposals(
+                OnChainTreasuryID(self.bank_id.to_be_bytes()),
+                page,
+            )
+            .await?
+            .into_iter()
+            .map(|(spend_id, spend)| {
+                let (text, json) = spend_summary::<T>(spend_id, spend);
+                Output::new(text, json)
+            })
+            .collect();
+        Ok(Output::list(spends))
+    }
+}
+
+#[derive(Clone, Debug, Clap)]
+pub struct BankProposeSpendCommand {
+    pub bank_id: u64,
+    pub amount: u128,
+    pub dest: String,
+}
+
+#[async_trait]
+impl<T: Runtime + Org + Vote + Donate + Bank + Bounty, P: Pair> Command<T, P>
+    for BankProposeSpendCommand
+where
+    <T as System>::AccountId: Ss58Codec,
+    <T as Bank>::Currency: From<u128> + Display,
+    <T as Bank>::SpendId: Display,
+{
+    async fn exec(&self, client: &dyn AbstractClient<T, P>) -> Result<Output> {
+        let dest: Account<T> = self.dest.parse()?;
+        let event = client
+            .member_proposes_spend(
+                OnChainTreasuryID(self.bank_id.to_be_bytes()),
+                self.amount.into(),
+                dest.id,
+            )
+            .await?;
+        Ok(Output::new(
+            format!(
+                "Account {} proposed spend {} of {} from bank {} to {}",
+                event.caller,
+                event.spend_id,
+                event.amount,
+                self.bank_id,
+                event.dest
+            ),
+            serde_json::json!({
+                "caller": event.caller.to_string(),
+                "bank": self.bank_id,
+                "spend": event.spend_id,
+                "amount": event.amount.to_string(),
+                "dest": event.dest.to_string(),
+            }),
+        ))
+    }
+}
+
+#[derive(Clone, Debug, Clap)]
+pub struct BankTriggerSpendVoteCommand {
+    pub bank_id: u64,
+    pub spend_id: u64,
+}
+
+#[async_trait]
+impl<T: Runtime + Org + Vote + Donate + Bank + Bounty, P: Pair> Command<T, P>
+    for BankTriggerSpendVoteCommand
+where
+    <T as System>::AccountId: Ss58Codec,
+    <T as Vote>::VoteId: Display,
+    <T as Bank>::SpendId: From<u64> + Display,
+{
+    async fn exec(&self, client: &dyn AbstractClient<T, P>) -> Result<Output> {
+        let event = client
+            .member_triggers_vote_on_spend_proposal(
+                OnChainTreasuryID(self.bank_id.to_be_bytes()),
+                self.spend_id.into(),
+            )
+            .await?;
+        Ok(Output::new(
+            format!(
+                "Account {} triggered vote {} on spend {} of bank {}",
+                event.caller, event.vote_id, event.spend_id, self.bank_id
+            ),
+            serde_json::json!({
+                "caller": event.caller.to_string(),
+                "bank": self.bank_id,
+                "spend": event.spend_id,
+                "vote": event.vote_id,
+            }),
+        ))
+    }
+}
+
+#[derive(Clone, Debug, Clap)]
+pub struct BankSudoApproveSpendCommand {
+    pub bank_id: u64,
+    pub spend_id: u64,
+}
+
+#[async_trait]
+impl<T: Runtime + Org + Vote + Donate + Bank + Bounty, P: Pair> Command<T, P>
+    for BankSudoApproveSpendCommand
+where
+    <T as System>::AccountId: Ss58Codec,
+    <T as Bank>::SpendId: From<u64> + Display,
+{
+    async fn exec(&self, client: &dyn AbstractClient<T, P>) -> Result<Output> {
+        let event = client
+            .member_sudo_approves_spend_proposal(
+                OnChainTreasuryID(self.bank_id.to_be_bytes()),
+                self.spend_id.into(),
+            )
+            .await?;
+        Ok(Output::new(
+            format!(
+                "Account {} approved spend {} of bank {}",
+                event.caller, event.spend_id, self.bank_id
+            ),
+            serde_json::json!({
+                "caller": event.caller.to_string(),
+                "bank": self.bank_id,
+                "spend": event.spend_id,
+            }),
+        ))
+    }
+}
+
+#[derive(Clone, Debug, Clap)]
+pub struct BankPollSpendCommand {
+    pub bank_id: u64,
+    pub spend_id: u64,
+}
+
+#[async_trait]
+impl<T: Runtime + Org + Vote + Donate + Bank + Bounty, P: Pair> Command<T, P>
+    for BankPollSpendCommand
+where
+    <T as System>::AccountId: Ss58Codec,
+    <T as Bank>::SpendId: From<u64> + Display,
+{
+    async fn exec(&self, client: &dyn AbstractClient<T, P>) -> Result<Output> {
+        let event = client
+            .member_polls_spend_proposal(
+                OnChainTreasuryID(self.bank_id.to_be_bytes()),
+                self.spend_id.into(),
+            )
+            .await?;
+        Ok(Output::new(
+            format!(
+                "Account {} polled spend {} of bank {} with state {:?}",
+                event.caller, event.spend_id, self.bank_id, event.state
+            ),
+            serde_json::json!({
+                "caller": event.caller.to_string(),
+                "bank": self.bank_id,
+                "spend": event.spend_id,
+                "state": format!("{:?}", event.state),
+            }),
+        ))
+    }
+}
+
+#[derive(Clone, Debug, Clap)]
+pub struct BankCloseCommand {
+    pub bank_id: u64,
+}
+
+#[async_trait]
+impl<T: Runtime + Org + Vote + Donate + Bank + Bounty, P: Pair> Command<T, P>
+    for BankCloseCommand
+where
+    <T as System>::AccountId: Ss58Codec,
+    <T as Org>::OrgId: Display,
+{
+    async fn exec(&self, client: &dyn AbstractClient<T, P>) -> Result<Output> {
+        let event = client
+            .close_org_bank_account(OnChainTreasuryID(
+                self.bank_id.to_be_bytes(),
+            ))
+            .await?;
+        Ok(Output::new(
+            format!(
+                "Account {} closed bank {} of OrgId {}",
+                event.closer, self.bank_id, event.org
+            ),
+            serde_json::json!({
+                "closer": event.closer.to_string(),
+                "bank": self.bank_id,
+                "org": event.org,
+            }),
+        ))
     }
 }
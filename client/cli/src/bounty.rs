@@ -7,23 +7,23 @@ use crate::{
     Donate,
     Error,
     Org,
+    Output,
     Pair,
     Result,
     Runtime,
     Vote,
 };
+use bounty_client::{
+    ApplicationDocument,
+    BountyDocument,
+    MilestoneDocument,
+    Page,
+};
 use clap::Clap;
 use core::fmt::{
     Debug,
     Display,
 };
-use libipld::{
-    cid::{
-        Cid,
-        Codec,
-    },
-    multihash::Blake2b256,
-};
 use substrate_subxt::{
     sp_core::crypto::Ss58Codec,
     system::System,
@@ -59,12 +59,9 @@ where
     <T as Bank>::Currency: From<u128> + Display,
     <T as Bounty>::BountyId: Display,
 {
-    async fn exec(&self, client: &dyn AbstractClient<T, P>) -> Result<()> {
-        let description: CidBytes = {
-            let content = self.description.as_bytes();
-            let hash = Blake2b256::digest(&content[..]);
-            let cid = Cid::new_v1(Codec::Raw, hash);
-            CidBytes::from(&cid)
+    async fn exec(&self, client: &dyn AbstractClient<T, P>) -> Result<Output> {
+        let description = BountyDocument {
+            description: self.description.clone(),
         };
         let ac_rejection_threshold: Option<T::Signal> =
             if let Some(ac_r_t) = self.ac_rejection_threshold {
@@ -119,19 +116,26 @@ where
         } else {
             None
         };
-        let event = client
+        let (description, event) = client
             .account_posts_bounty(
-                description.into(),
+                &description,
                 self.amount_reserved_for_bounty.into(),
                 acceptance_committee,
                 supervision_committee,
             )
             .await?;
-        println!(
-            "AccountId {} posted new bounty with identifier {} with amount reserved: {}",
-            event.poster, event.new_bounty_id, event.amount_reserved_for_bounty
-        );
-        Ok(())
+        Ok(Output::new(
+            format!(
+                "AccountId {} posted new bounty with identifier {} with amount reserved: {} and description {:?}",
+                event.poster, event.new_bounty_id, event.amount_reserved_for_bounty, description
+            ),
+            serde_json::json!({
+                "poster": event.poster.to_string(),
+                "bounty": event.new_bounty_id,
+                "amount": event.amount_reserved_for_bounty.to_string(),
+                "description": format!("{:?}", description),
+            }),
+        ))
     }
 }
 
@@ -151,25 +155,30 @@ where
     <T as Bank>::Currency: From<u128> + Display,
     <T as Bounty>::BountyId: From<u64> + Display,
 {
-    async fn exec(&self, client: &dyn AbstractClient<T, P>) -> Result<()> {
-        let description: CidBytes = {
-            let content = self.description.as_bytes();
-            let hash = Blake2b256::digest(&content[..]);
-            let cid = Cid::new_v1(Codec::Raw, hash);
-            CidBytes::from(&cid)
+    async fn exec(&self, client: &dyn AbstractClient<T, P>) -> Result<Output> {
+        let description = ApplicationDocument {
+            description: self.description.clone(),
         };
-        let event = client
+        let (description, event) = client
             .account_applies_for_bounty(
                 self.bounty_id.into(),
-                description.into(),
+                &description,
                 self.total_amount.into(),
             )
             .await?;
-        println!(
-            "AccountId {:?} applied for bounty with identifier {} with application identifier {} for total amount {}",
-            event.submitter, event.bounty_id, event.new_grant_app_id, event.total_amount,
-        );
-        Ok(())
+        Ok(Output::new(
+            format!(
+                "AccountId {:?} applied for bounty with identifier {} with application identifier {} for total amount {} and description {:?}",
+                event.submitter, event.bounty_id, event.new_grant_app_id, event.total_amount, description,
+            ),
+            serde_json::json!({
+                "submitter": event.submitter.to_string(),
+                "bounty": event.bounty_id,
+                "application": event.new_grant_app_id,
+                "amount": event.total_amount.to_string(),
+                "description": format!("{:?}", description),
+            }),
+        ))
     }
 }
 
@@ -187,18 +196,25 @@ where
     <T as Vote>::VoteId: Display,
     <T as Bounty>::BountyId: From<u64> + Display,
 {
-    async fn exec(&self, client: &dyn AbstractClient<T, P>) -> Result<()> {
+    async fn exec(&self, client: &dyn AbstractClient<T, P>) -> Result<Output> {
         let event = client
             .account_triggers_application_review(
                 self.bounty_id.into(),
                 self.app_id.into(),
             )
             .await?;
-        println!(
-            "AccountId {:?} triggered review for bounty {} application {} with application state {:?}",
-            event.trigger, event.bounty_id, event.application_id, event.application_state
-        );
-        Ok(())
+        Ok(Output::new(
+            format!(
+                "AccountId {:?} triggered review for bounty {} application {} with application state {:?}",
+                event.trigger, event.bounty_id, event.application_id, event.application_state
+            ),
+            serde_json::json!({
+                "caller": event.trigger.to_string(),
+                "bounty": event.bounty_id,
+                "application": event.application_id,
+                "state": format!("{:?}", event.application_state),
+            }),
+        ))
     }
 }
 
@@ -216,18 +232,25 @@ where
     <T as Vote>::VoteId: Display,
     <T as Bounty>::BountyId: From<u64> + Display,
 {
-    async fn exec(&self, client: &dyn AbstractClient<T, P>) -> Result<()> {
+    async fn exec(&self, client: &dyn AbstractClient<T, P>) -> Result<Output> {
         let event = client
             .account_sudo_approves_application(
                 self.bounty_id.into(),
                 self.app_id.into(),
             )
             .await?;
-        println!(
-            "AccountId {:?} sudo approved bounty {} application {} with application state {:?}",
-            event.sudo, event.bounty_id, event.application_id, event.application_state
-        );
-        Ok(())
+        Ok(Output::new(
+            format!(
+                "AccountId {:?} sudo approved bounty {} application {} with application state {:?}",
+                event.sudo, event.bounty_id, event.application_id, event.application_state
+            ),
+            serde_json::json!({
+                "caller": event.sudo.to_string(),
+                "bounty": event.bounty_id,
+                "application": event.application_id,
+                "state": format!("{:?}", event.application_state),
+            }),
+        ))
     }
 }
 
@@ -245,15 +268,22 @@ where
     <T as Vote>::VoteId: Display,
     <T as Bounty>::BountyId: From<u64> + Display,
 {
-    async fn exec(&self, client: &dyn AbstractClient<T, P>) -> Result<()> {
+    async fn exec(&self, client: &dyn AbstractClient<T, P>) -> Result<Output> {
         let event = client
             .poll_application(self.bounty_id.into(), self.app_id.into())
             .await?;
-        println!(
-            "AccountId {:?} polled bounty {} application {} with application state {:?}",
-            event.poller, event.bounty_id, event.application_id, event.application_state
-        );
-        Ok(())
+        Ok(Output::new(
+            format!(
+                "AccountId {:?} polled bounty {} application {} with application state {:?}",
+                event.poller, event.bounty_id, event.application_id, event.application_state
+            ),
+            serde_json::json!({
+                "caller": event.poller.to_string(),
+                "bounty": event.bounty_id,
+                "application": event.application_id,
+                "state": format!("{:?}", event.application_state),
+            }),
+        ))
     }
 }
 
@@ -261,7 +291,7 @@ where
 pub struct BountySubmitMilestoneCommand {
     pub bounty_id: u64,
     pub application_id: u64,
-    pub submission_reference: String,
+    pub submission: String,
     pub amount_requested: u128,
 }
 
@@ -274,26 +304,32 @@ where
     <T as Bank>::Currency: From<u128> + Display,
     <T as Bounty>::BountyId: From<u64> + Display,
 {
-    async fn exec(&self, client: &dyn AbstractClient<T, P>) -> Result<()> {
-        let submission_reference: CidBytes = {
-            let content = self.submission_reference.as_bytes();
-            let hash = Blake2b256::digest(&content[..]);
-            let cid = Cid::new_v1(Codec::Raw, hash);
-            CidBytes::from(&cid)
+    async fn exec(&self, client: &dyn AbstractClient<T, P>) -> Result<Output> {
+        let submission = MilestoneDocument {
+            submission: self.submission.clone(),
         };
-        let event = client
+        let (submission_reference, event) = client
             .submit_milestone(
                 self.bounty_id.into(),
                 self.application_id.into(),
-                submission_reference.into(),
+                &submission,
                 self.amount_requested.into(),
             )
             .await?;
-        println!(
-            "AccountId {:?} submitted a milestone for bounty {} application {} milestone {} for amount {}",
-            event.submitter, event.bounty_id, event.application_id, event.new_milestone_id, event.amount_requested,
-        );
-        Ok(())
+        Ok(Output::new(
+            format!(
+                "AccountId {:?} submitted a milestone for bounty {} application {} milestone {} for amount {} with submission {:?}",
+                event.submitter, event.bounty_id, event.application_id, event.new_milestone_id, event.amount_requested, submission_reference,
+            ),
+            serde_json::json!({
+                "submitter": event.submitter.to_string(),
+                "bounty": event.bounty_id,
+                "application": event.application_id,
+                "milestone": event.new_milestone_id,
+                "amount": event.amount_requested.to_string(),
+                "submission": format!("{:?}", submission_reference),
+            }),
+        ))
     }
 }
 
@@ -309,18 +345,25 @@ impl<T: Runtime + Org + Vote + Donate + Bank + Bounty, P: Pair> Command<T, P>
 where
     <T as Bounty>::BountyId: From<u64> + Display,
 {
-    async fn exec(&self, client: &dyn AbstractClient<T, P>) -> Result<()> {
+    async fn exec(&self, client: &dyn AbstractClient<T, P>) -> Result<Output> {
         let event = client
             .trigger_milestone_review(
                 self.bounty_id.into(),
                 self.milestone_id.into(),
             )
             .await?;
-        println!(
-            "AccountId {:?} triggered a milestone review for bounty {} milestone {} with state {:?}",
-            event.trigger, event.bounty_id, event.milestone_id, event.milestone_state,
-        );
-        Ok(())
+        Ok(Output::new(
+            format!(
+                "AccountId {:?} triggered a milestone review for bounty {} milestone {} with state {:?}",
+                event.trigger, event.bounty_id, event.milestone_id, event.milestone_state,
+            ),
+            serde_json::json!({
+                "caller": event.trigger.to_string(),
+                "bounty": event.bounty_id,
+                "milestone": event.milestone_id,
+                "state": format!("{:?}", event.milestone_state),
+            }),
+        ))
     }
 }
 
@@ -336,18 +379,25 @@ impl<T: Runtime + Org + Vote + Donate + Bank + Bounty, P: Pair> Command<T, P>
 where
     <T as Bounty>::BountyId: From<u64> + Display,
 {
-    async fn exec(&self, client: &dyn AbstractClient<T, P>) -> Result<()> {
+    async fn exec(&self, client: &dyn AbstractClient<T, P>) -> Result<Output> {
         let event = client
             .sudo_approves_milestone(
                 self.bounty_id.into(),
                 self.milestone_id.into(),
             )
             .await?;
-        println!(
-            "AccountId {:?} sudo approved bounty {} milestone {} with state {:?}",
-            event.sudo, event.bounty_id, event.milestone_id, event.milestone_state,
-        );
-        Ok(())
+        Ok(Output::new(
+            format!(
+                "AccountId {:?} sudo approved bounty {} milestone {} with state {:?}",
+                event.sudo, event.bounty_id, event.milestone_id, event.milestone_state,
+            ),
+            serde_json::json!({
+                "caller": event.sudo.to_string(),
+                "bounty": event.bounty_id,
+                "milestone": event.milestone_id,
+                "state": format!("{:?}", event.milestone_state),
+            }),
+        ))
     }
 }
 
@@ -363,17 +413,300 @@ impl<T: Runtime + Org + Vote + Donate + Bank + Bounty, P: Pair> Command<T, P>
 where
     <T as Bounty>::BountyId: From<u64> + Display,
 {
-    async fn exec(&self, client: &dyn AbstractClient<T, P>) -> Result<()> {
+    async fn exec(&self, client: &dyn AbstractClient<T, P>) -> Result<Output> {
         let event = client
             .poll_milestone(self.bounty_id.into(), self.milestone_id.into())
             .await?;
-        println!(
-            "AccountId {:?} polled bounty {} milestone {} with state {:?}",
-            event.poller,
-            event.bounty_id,
-            event.milestone_id,
-            event.milestone_state,
-        );
-        Ok(())
+        Ok(Output::new(
+            format!(
+                "AccountId {:?} polled bounty {} milestone {} with state {:?}",
+                event.poller,
+                event.bounty_id,
+                event.milestone_id,
+                event.milestone_state,
+            ),
+            serde_json::json!({
+                "caller": event.poller.to_string(),
+                "bounty": event.bounty_id,
+                "milestone": event.milestone_id,
+                "state": format!("{:?}", event.milestone_state),
+            }),
+        ))
+    }
+}
+
+#[derive(Clone, Debug, Clap)]
+pub struct BountyShowCommand {
+    pub bounty_id: u64,
+}
+
+#[async_trait]
+impl<T: Runtime + Org + Vote + Donate + Bank + Bounty, P: Pair> Command<T, P>
+    for BountyShowCommand
+where
+    <T as Bank>::Currency: Display,
+    <T as Bounty>::BountyId: From<u64> + Display,
+{
+    async fn exec(&self, client: &dyn AbstractClient<T, P>) -> Result<Output> {
+        let bounty = client
+            .bounty(self.bounty_id.into())
+            .await?
+            .ok_or(Error::BountyNotFound)?;
+        let description = client
+            .get_bounty_description(&bounty.topic())
+            .await
+            .ok()
+            .map(|doc| doc.description);
+        Ok(Output::new(
+            format!(
+                "Bounty {} posted by {:?} with {} reserved, description {:?} and acceptance committee {:?}",
+                self.bounty_id,
+                bounty.poster(),
+                bounty.funding_reserved(),
+                description,
+                bounty.acceptance_committee()
+            ),
+            serde_json::json!({
+                "bounty": self.bounty_id,
+                "poster": format!("{:?}", bounty.poster()),
+                "amount": bounty.funding_reserved().to_string(),
+                "description": description,
+                "acceptance_committee": format!("{:?}", bounty.acceptance_committee()),
+            }),
+        ))
+    }
+}
+
+#[derive(Clone, Debug, Clap)]
+pub struct BountyListCommand {
+    #[clap(long = "offset", default_value = "0")]
+    pub offset: u32,
+    #[clap(long = "limit", default_value = "20")]
+    pub limit: u32,
+}
+
+#[async_trait]
+impl<T: Runtime + Org + Vote + Donate + Bank + Bounty, P: Pair> Command<T, P>
+    for BountyListCommand
+where
+    <T as Bank>::Currency: Display,
+    <T as Bounty>::BountyId: Display,
+{
+    async fn exec(&self, client: &dyn AbstractClient<T, P>) -> Result<Output> {
+        let page = Page {
+            offset: self.offset,
+            limit: self.limit,
+        };
+        let bounties = client
+            .bounties(page)
+            .await?
+            .into_iter()
+            .map(|(id, bounty)| {
+                Output::new(
+                    format!(
+                        "Bounty {} posted by {:?} with {} reserved",
+                        id,
+                        bounty.poster(),
+                        bounty.funding_reserved()
+                    ),
+                    serde_json::json!({
+                        "bounty": id,
+                        "poster": format!("{:?}", bounty.poster()),
+                        "amount": bounty.funding_reserved().to_string(),
+                    }),
+                )
+            })
+            .collect();
+        Ok(Output::list(bounties))
+    }
+}
+
+#[derive(Clone, Debug, Clap)]
+pub struct BountyShowApplicationCommand {
+    pub bounty_id: u64,
+    pub app_id: u64,
+}
+
+#[async_trait]
+impl<T: Runtime + Org + Vote + Donate + Bank + Bounty, P: Pair> Command<T, P>
+    for BountyShowApplicationCommand
+where
+    <T as Bank>::Currency: Display,
+    <T as Bounty>::BountyId: From<u64> + Display,
+{
+    async fn exec(&self, client: &dyn AbstractClient<T, P>) -> Result<Output> {
+        let application = client
+            .application(self.bounty_id.into(), self.app_id.into())
+            .await?
+            .ok_or(Error::ApplicationNotFound)?;
+        let description = client
+            .get_application(&application.submission())
+            .await
+            .ok()
+            .map(|doc| doc.description);
+        Ok(Output::new(
+            format!(
+                "Application {} for bounty {} by {:?} for total amount {} with state {:?} and description {:?}",
+                self.app_id,
+                self.bounty_id,
+                application.submitter(),
+                application.total_amount(),
+                application.state(),
+                description
+            ),
+            serde_json::json!({
+                "bounty": self.bounty_id,
+                "application": self.app_id,
+                "submitter": application.submitter().to_string(),
+                "amount": application.total_amount().to_string(),
+                "state": format!("{:?}", application.state()),
+                "description": description,
+            }),
+        ))
+    }
+}
+
+#[derive(Clone, Debug, Clap)]
+pub struct BountyListApplicationsCommand {
+    pub bounty_id: u64,
+    #[clap(long = "offset", default_value = "0")]
+    pub offset: u32,
+    #[clap(long = "limit", default_value = "20")]
+    pub limit: u32,
+}
+
+#[async_trait]
+impl<T: Runtime + Org + Vote + Donate + Bank + Bounty, P: Pair> Command<T, P>
+    for BountyListApplicationsCommand
+where
+    <T as Bank>::Currency: Display,
+    <T as Bounty>::BountyId: From<u64> + Display,
+{
+    async fn exec(&self, client: &dyn AbstractClient<T, P>) -> Result<Output> {
+        let page = Page {
+            offset: self.offset,
+            limit: self.limit,
+        };
+        let applications = client
+            .applications(self.bounty_id.into(), page)
+            .await?
+            .into_iter()
+            .map(|(id, application)| {
+                Output::new(
+                    format!(
+                        "Application {} by {:?} for total amount {} with state {:?}",
+                        id,
+                        application.submitter(),
+                        application.total_amount(),
+                        application.state()
+                    ),
+                    serde_json::json!({
+                        "bounty": self.bounty_id,
+                        "application": id,
+                        "submitter": application.submitter().to_string(),
+                        "amount": application.total_amount().to_string(),
+                        "state": format!("{:?}", application.state()),
+                    }),
+                )
+            })
+            .collect();
+        Ok(Output::list(applications))
+    }
+}
+
+#[derive(Clone, Debug, Clap)]
+pub struct BountyShowMilestoneCommand {
+    pub bounty_id: u64,
+    pub milestone_id: u64,
+}
+
+#[async_trait]
+impl<T: Runtime + Org + Vote + Donate + Bank + Bounty, P: Pair> Command<T, P>
+    for BountyShowMilestoneCommand
+where
+    <T as Bank>::Currency: Display,
+    <T as Bounty>::BountyId: From<u64> + Display,
+{
+    async fn exec(&self, client: &dyn AbstractClient<T, P>) -> Result<Output> {
+        let milestone = client
+            .milestone(self.bounty_id.into(), self.milestone_id.into())
+            .await?
+            .ok_or(Error::MilestoneNotFound)?;
+        let submission = client
+            .get_milestone(&milestone.submission())
+            .await
+            .ok()
+            .map(|doc| doc.submission);
+        Ok(Output::new(
+            format!(
+                "Milestone {} for bounty {} application {} by {:?} for amount {} with state {:?} and submission {:?}",
+                self.milestone_id,
+                self.bounty_id,
+                milestone.referenced_application(),
+                milestone.submitter(),
+                milestone.amount(),
+                milestone.state(),
+                submission
+            ),
+            serde_json::json!({
+                "bounty": self.bounty_id,
+                "application": milestone.referenced_application(),
+                "milestone": self.milestone_id,
+                "submitter": milestone.submitter().to_string(),
+                "amount": milestone.amount().to_string(),
+                "state": format!("{:?}", milestone.state()),
+                "submission": submission,
+            }),
+        ))
+    }
+}
+
+#[derive(Clone, Debug, Clap)]
+pub struct BountyListMilestonesCommand {
+    pub bounty_id: u64,
+    #[clap(long = "offset", default_value = "0")]
+    pub offset: u32,
+    #[clap(long = "limit", default_value = "20")]
+    pub limit: u32,
+}
+
+#[async_trait]
+impl<T: Runtime + Org + Vote + Donate + Bank + Bounty, P: Pair> Command<T, P>
+    for BountyListMilestonesCommand
+where
+    <T as Bank>::Currency: Display,
+    <T as Bounty>::BountyId: From<u64> + Display,
+{
+    async fn exec(&self, client: &dyn AbstractClient<T, P>) -> Result<Output> {
+        let page = Page {
+            offset: self.offset,
+            limit: self.limit,
+        };
+        let milestones = client
+            .milestones(self.bounty_id.into(), page)
+            .await?
+            .into_iter()
+            .map(|(id, milestone)| {
+                Output::new(
+                    format!(
+                        "Milestone {} for application {} by {:?} for amount {} with state {:?}",
+                        id,
+                        milestone.referenced_application(),
+                        milestone.submitter(),
+                        milestone.amount(),
+                        milestone.state()
+                    ),
+                    serde_json::json!({
+                        "bounty": self.bounty_id,
+                        "application": milestone.referenced_application(),
+                        "milestone": id,
+                        "submitter": milestone.submitter().to_string(),
+                        "amount": milestone.amount().to_string(),
+                        "state": format!("{:?}", milestone.state()),
+                    }),
+                )
+            })
+            .collect();
+        Ok(Output::list(milestones))
     }
 }
@@ -6,6 +6,7 @@ use crate::{
     Command,
     Donate,
     Org,
+    Output,
     Pair,
     Result,
     Runtime,
@@ -35,15 +36,22 @@ where
     <T as Org>::OrgId: From<u64> + Display,
     <T as Donate>::DCurrency: From<u128> + Display,
 {
-    async fn exec(&self, client: &dyn AbstractClient<T, P>) -> Result<()> {
+    async fn exec(&self, client: &dyn AbstractClient<T, P>) -> Result<Output> {
         let event = client
             .make_prop_donation_with_fee(self.org.into(), self.amt.into())
             .await?;
-        println!(
-            "AccountId {:?} donated {} to OrgId {} (with the module fee)",
-            event.sender, event.amt, event.org
-        );
-        Ok(())
+        Ok(Output::new(
+            format!(
+                "AccountId {:?} donated {} to OrgId {} (with the module fee)",
+                event.sender, event.amt, event.org
+            ),
+            serde_json::json!({
+                "sender": event.sender.to_string(),
+                "org": event.org,
+                "amount": event.amt.to_string(),
+                "fee": event.fee,
+            }),
+        ))
     }
 }
 
@@ -61,14 +69,21 @@ where
     <T as Org>::OrgId: From<u64> + Display,
     <T as Donate>::DCurrency: From<u128> + Display,
 {
-    async fn exec(&self, client: &dyn AbstractClient<T, P>) -> Result<()> {
+    async fn exec(&self, client: &dyn AbstractClient<T, P>) -> Result<Output> {
         let event = client
             .make_prop_donation_without_fee(self.org.into(), self.amt.into())
             .await?;
-        println!(
-            "AccountId {:?} donated {} to OrgId {} (without the module fee)",
-            event.sender, event.amt, event.org
-        );
-        Ok(())
+        Ok(Output::new(
+            format!(
+                "AccountId {:?} donated {} to OrgId {} (without the module fee)",
+                event.sender, event.amt, event.org
+            ),
+            serde_json::json!({
+                "sender": event.sender.to_string(),
+                "org": event.org,
+                "amount": event.amt.to_string(),
+                "fee": event.fee,
+            }),
+        ))
     }
 }
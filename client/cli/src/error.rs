@@ -13,6 +13,8 @@ pub enum Error {
     #[error(transparent)]
     Ipfsembed(#[from] ipfs_embed::Error),
 
+    #[error("Failed to start the light client: {0}")]
+    LightClient(String),
     #[error("Failed to find config dir. Use `--path` to supply a suitable directory.")]
     ConfigDirNotFound,
     #[error(transparent)]
@@ -31,6 +33,62 @@ pub enum Error {
     PasswordMismatch,
     #[error("Input error for posting bounty.")]
     PostBountyInputError,
+    #[error("Organization not found.")]
+    OrgNotFound,
+    #[error("Bank account not found.")]
+    BankNotFound,
+    #[error("Bounty not found.")]
+    BountyNotFound,
+    #[error("Bounty application not found.")]
+    ApplicationNotFound,
+    #[error("Milestone submission not found.")]
+    MilestoneNotFound,
+    #[error("Invalid output format, expected `text` or `json`.")]
+    InvalidOutputFormat,
+    #[error("Invalid transaction file.")]
+    InvalidTransactionFile,
+    #[error("Invalid plan: {0}")]
+    InvalidPlan(String),
+}
+
+impl Error {
+    /// A stable identifier of the error for scripts, unlike the message it
+    /// does not change between releases
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Bounty(err) => err.code(),
+            Self::Subxt(_) => "rpc",
+            Self::Io(_) => "io",
+            Self::Keystore(_) => "keystore",
+            Self::Ipfsembed(_) => "ipfs",
+            Self::LightClient(_) => "light_client",
+            Self::ConfigDirNotFound => "config_dir_not_found",
+            Self::InvalidSuri(_) => "invalid_suri",
+            Self::TransferEventDecode => "transfer_event_decode",
+            Self::TransferEventFind => "transfer_event_not_found",
+            Self::HasDeviceKey => "has_device_key",
+            Self::InvalidMnemonic => "invalid_mnemonic",
+            Self::PasswordTooShort => "password_too_short",
+            Self::PasswordMismatch => "password_mismatch",
+            Self::PostBountyInputError => "post_bounty_input",
+            Self::OrgNotFound => "org_not_found",
+            Self::BankNotFound => "bank_not_found",
+            Self::BountyNotFound => "bounty_not_found",
+            Self::ApplicationNotFound => "application_not_found",
+            Self::MilestoneNotFound => "milestone_not_found",
+            Self::InvalidOutputFormat => "invalid_output_format",
+            Self::InvalidTransactionFile => "invalid_transaction_file",
+            Self::InvalidPlan(_) => "invalid_plan",
+        }
+    }
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "error": {
+                "code": self.code(),
+                "message": self.to_string(),
+            }
+        })
+    }
 }
 
 pub type Result<T> = core::result::Result<T, Error>;
@@ -1,19 +1,33 @@
 use crate::{
+    ask_for_new_password,
     ask_for_password,
     async_trait,
+    device_account,
     set_device_key,
+    set_keystore_device_key,
     AbstractClient,
     Bank,
     Bounty,
     Command,
     Donate,
+    Error,
     Org,
+    Output,
     Pair,
     Result,
     Runtime,
     Vote,
 };
 use clap::Clap;
+use keystore::{
+    bip39::{
+        Language,
+        Mnemonic,
+    },
+    DeviceKey,
+    KeyStore,
+};
+use substrate_subxt::system::System;
 
 #[derive(Clone, Debug, Clap)]
 pub struct KeySetCommand {
@@ -36,7 +50,7 @@ impl<T: Runtime + Org + Vote + Donate + Bank + Bounty, P: Pair> Command<T, P>
 where
     P::Seed: Into<[u8; 32]> + Copy + Send + Sync,
 {
-    async fn exec(&self, client: &dyn AbstractClient<T, P>) -> Result<()> {
+    async fn exec(&self, client: &dyn AbstractClient<T, P>) -> Result<Output> {
         let account_id = set_device_key(
             client,
             self.paperkey,
@@ -45,8 +59,10 @@ where
         )
         .await?;
         let account_id_str = account_id.to_string();
-        println!("Your device id is {}", &account_id_str);
-        Ok(())
+        Ok(Output::new(
+            format!("Your device id is {}", &account_id_str),
+            serde_json::json!({ "account": account_id_str }),
+        ))
     }
 }
 
@@ -57,9 +73,9 @@ pub struct KeyLockCommand;
 impl<T: Runtime + Org + Vote + Donate + Bank + Bounty, P: Pair> Command<T, P>
     for KeyLockCommand
 {
-    async fn exec(&self, client: &dyn AbstractClient<T, P>) -> Result<()> {
+    async fn exec(&self, client: &dyn AbstractClient<T, P>) -> Result<Output> {
         client.lock().await?;
-        Ok(())
+        Ok(Output::text("Your keystore is locked".to_string()))
     }
 }
 
@@ -70,10 +86,107 @@ pub struct KeyUnlockCommand;
 impl<T: Runtime + Org + Vote + Donate + Bank + Bounty, P: Pair> Command<T, P>
     for KeyUnlockCommand
 {
-    async fn exec(&self, client: &dyn AbstractClient<T, P>) -> Result<()> {
+    async fn exec(&self, client: &dyn AbstractClient<T, P>) -> Result<Output> {
         let password =
             ask_for_password("Please enter your password (8+ characters):\n")?;
         client.unlock(&password).await?;
-        Ok(())
+        Ok(Output::text("Your keystore is unlocked".to_string()))
+    }
+}
+
+#[derive(Clone, Debug, Clap)]
+pub struct KeyGenerateCommand {
+    /// Overwrite existing keys.
+    #[clap(short = "f", long = "force")]
+    pub force: bool,
+}
+
+impl KeyGenerateCommand {
+    pub async fn exec_offline<T: System, P: Pair>(
+        &self,
+        keystore: &KeyStore,
+    ) -> Result<Output>
+    where
+        P::Seed: From<[u8; 32]>,
+        <P as Pair>::Public: Into<<T as System>::AccountId>,
+    {
+        if keystore.is_initialized().await && !self.force {
+            return Err(Error::HasDeviceKey)
+        }
+        let password = ask_for_new_password()?;
+        if password.expose_secret().len() < 8 {
+            return Err(Error::PasswordTooShort)
+        }
+        let dk = DeviceKey::generate().await;
+        keystore.initialize(&dk, &password).await?;
+        let account_id = device_account::<T, P>(&dk);
+        let paperkey =
+            Mnemonic::from_entropy(dk.expose_secret(), Language::English)
+                .map_err(|_| Error::InvalidMnemonic)?;
+        Ok(Output::new(
+            format!(
+                "Your device id is {}\nYour paper key is:\n{}\nWrite it down, it is the only backup of your device key.",
+                account_id.to_string(),
+                paperkey.phrase()
+            ),
+            serde_json::json!({
+                "account": account_id.to_string(),
+                "paperkey": paperkey.phrase(),
+            }),
+        ))
+    }
+}
+
+#[derive(Clone, Debug, Clap)]
+pub struct KeyImportCommand {
+    /// Overwrite existing keys.
+    #[clap(short = "f", long = "force")]
+    pub force: bool,
+
+    /// Suri, the paper key is asked for when it is missing.
+    #[clap(long = "suri")]
+    pub suri: Option<String>,
+}
+
+impl KeyImportCommand {
+    pub async fn exec_offline<T: System, P: Pair>(
+        &self,
+        keystore: &KeyStore,
+    ) -> Result<Output>
+    where
+        P::Seed: From<[u8; 32]> + Into<[u8; 32]> + Copy + Send + Sync,
+        <P as Pair>::Public: Into<<T as System>::AccountId>,
+    {
+        let account_id = set_keystore_device_key::<T, P>(
+            keystore,
+            self.suri.is_none(),
+            self.suri.as_deref(),
+            self.force,
+        )
+        .await?;
+        Ok(Output::new(
+            format!("Your device id is {}", account_id.to_string()),
+            serde_json::json!({ "account": account_id.to_string() }),
+        ))
+    }
+}
+
+#[derive(Clone, Debug, Clap)]
+pub struct KeyExportPaperkeyCommand;
+
+impl KeyExportPaperkeyCommand {
+    pub async fn exec_offline(&self, keystore: &KeyStore) -> Result<Output> {
+        let password =
+            ask_for_password("Please enter your password (8+ characters):\n")?;
+        // the password is checked by unlocking the keystore
+        keystore.unlock(&password).await?;
+        let dk = keystore.device_key().await?;
+        let paperkey =
+            Mnemonic::from_entropy(dk.expose_secret(), Language::English)
+                .map_err(|_| Error::InvalidMnemonic)?;
+        Ok(Output::new(
+            paperkey.phrase().to_string(),
+            serde_json::json!({ "paperkey": paperkey.phrase() }),
+        ))
     }
 }
@@ -1,14 +1,21 @@
+pub mod apply;
 pub mod bank;
 pub mod bounty;
 pub mod donate;
 mod error;
 pub mod key;
 pub mod org;
+mod output;
 pub mod shares;
+pub mod tx;
 pub mod vote;
 pub mod wallet;
+pub mod watch;
 
-pub use crate::error::*;
+pub use crate::{
+    error::*,
+    output::*,
+};
 
 use keystore::{
     bip39::{
@@ -16,6 +23,7 @@ use keystore::{
         Mnemonic,
     },
     DeviceKey,
+    KeyStore,
     Password,
 };
 use substrate_subxt::system::System;
@@ -39,7 +47,7 @@ pub(crate) use substrate_subxt::{
 pub trait Command<T: Runtime + Org + Vote + Donate + Bank + Bounty, P: Pair>:
     Send + Sync
 {
-    async fn exec(&self, client: &dyn AbstractClient<T, P>) -> Result<()>;
+    async fn exec(&self, client: &dyn AbstractClient<T, P>) -> Result<Output>;
 }
 
 pub fn ask_for_new_password() -> Result<Password> {
@@ -53,11 +61,11 @@ pub fn ask_for_new_password() -> Result<Password> {
 }
 
 pub fn ask_for_password(prompt: &str) -> Result<Password> {
-    Ok(Password::from(rpassword::prompt_password_stdout(prompt)?))
+    Ok(Password::from(rpassword::prompt_password_stderr(prompt)?))
 }
 
 pub async fn ask_for_phrase(prompt: &str) -> Result<Mnemonic> {
-    println!("{}", prompt);
+    eprintln!("{}", prompt);
     let mut words = Vec::with_capacity(24);
     while words.len() < 24 {
         let mut line = String::new();
@@ -66,7 +74,7 @@ pub async fn ask_for_phrase(prompt: &str) -> Result<Mnemonic> {
             words.push(word.trim().to_string());
         }
     }
-    println!();
+    eprintln!();
     Ok(Mnemonic::from_phrase(&words.join(" "), Language::English)
         .map_err(|_| Error::InvalidMnemonic)?)
 }
@@ -90,7 +98,56 @@ where
     if password.expose_secret().len() < 8 {
         return Err(Error::PasswordTooShort)
     }
-    let dk = if paperkey {
+    let dk = read_device_key::<P>(paperkey, suri).await?;
+    Ok(client.set_device_key(&dk, &password, force).await?)
+}
+
+/// Initializes the keystore of an account without a client, so that keys
+/// are managed without a connection to a node
+pub async fn set_keystore_device_key<T: System, P: Pair>(
+    keystore: &KeyStore,
+    paperkey: bool,
+    suri: Option<&str>,
+    force: bool,
+) -> Result<<T as System>::AccountId>
+where
+    P::Seed: From<[u8; 32]> + Into<[u8; 32]> + Copy + Send + Sync,
+    <P as Pair>::Public: Into<<T as System>::AccountId>,
+{
+    if keystore.is_initialized().await && !force {
+        return Err(Error::HasDeviceKey)
+    }
+    let password = ask_for_new_password()?;
+    if password.expose_secret().len() < 8 {
+        return Err(Error::PasswordTooShort)
+    }
+    let dk = read_device_key::<P>(paperkey, suri).await?;
+    keystore.initialize(&dk, &password).await?;
+    Ok(device_account::<T, P>(&dk))
+}
+
+/// The account of the device key
+pub fn device_account<T: System, P: Pair>(
+    dk: &DeviceKey,
+) -> <T as System>::AccountId
+where
+    P::Seed: From<[u8; 32]>,
+    <P as Pair>::Public: Into<<T as System>::AccountId>,
+{
+    P::from_seed(&P::Seed::from(*dk.expose_secret()))
+        .public()
+        .into()
+}
+
+/// Reads the device key from the paper key or the suri, or generates one
+async fn read_device_key<P: Pair>(
+    paperkey: bool,
+    suri: Option<&str>,
+) -> Result<DeviceKey>
+where
+    P::Seed: Into<[u8; 32]> + Copy + Send + Sync,
+{
+    Ok(if paperkey {
         let mnemonic =
             ask_for_phrase("Please enter your backup phrase:").await?;
         DeviceKey::from_mnemonic(&mnemonic)
@@ -100,6 +157,5 @@ where
         DeviceKey::from_seed(suri.0.into())
     } else {
         DeviceKey::generate().await
-    };
-    Ok(client.set_device_key(&dk, &password, force).await?)
+    })
 }
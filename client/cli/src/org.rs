@@ -5,7 +5,9 @@ use crate::{
     Bounty,
     Command,
     Donate,
+    Error,
     Org,
+    Output,
     Pair,
     Result,
     Runtime,
@@ -14,19 +16,14 @@ use crate::{
 use bounty_client::{
     Account,
     AccountShare,
+    ConstitutionDocument,
+    Page,
 };
 use clap::Clap;
 use core::fmt::{
     Debug,
     Display,
 };
-use libipld::{
-    cid::{
-        Cid,
-        Codec,
-    },
-    multihash::Blake2b256,
-};
 use substrate_subxt::{
     sp_core::crypto::Ss58Codec,
     system::System,
@@ -50,7 +47,7 @@ where
     <T as Org>::Shares: From<u64> + Display,
     <T as Org>::IpfsReference: From<CidBytes> + Debug,
 {
-    async fn exec(&self, client: &dyn AbstractClient<T, P>) -> Result<()> {
+    async fn exec(&self, client: &dyn AbstractClient<T, P>) -> Result<Output> {
         let sudo: Option<T::AccountId> = if let Some(acc) = &self.sudo {
             let new_acc: Account<T> = acc.parse()?;
             Some(new_acc.id)
@@ -62,12 +59,11 @@ where
         } else {
             None
         };
-        let constitution: CidBytes = {
-            let content = self.constitution.as_bytes();
-            let hash = Blake2b256::digest(&content[..]);
-            let cid = Cid::new_v1(Codec::Raw, hash);
-            CidBytes::from(&cid)
-        };
+        let constitution = client
+            .insert_constitution(&ConstitutionDocument {
+                constitution: self.constitution.clone(),
+            })
+            .await?;
         let members = self
             .members
             .iter()
@@ -77,13 +73,20 @@ where
             })
             .collect::<Result<Vec<T::AccountId>>>()?;
         let event = client
-            .register_flat_org(sudo, parent_org, constitution.into(), &members)
+            .register_flat_org(sudo, parent_org, constitution, &members)
             .await?;
-        println!(
-            "Account {} created a flat organization with OrgId: {}, constitution: {:?} and {} members of equal ownership weight",
-            event.caller, event.new_id, event.constitution, event.total
-        );
-        Ok(())
+        Ok(Output::new(
+            format!(
+                "Account {} created a flat organization with OrgId: {}, constitution: {:?} and {} members of equal ownership weight",
+                event.caller, event.new_id, event.constitution, event.total
+            ),
+            serde_json::json!({
+                "caller": event.caller.to_string(),
+                "org": event.new_id,
+                "constitution": format!("{:?}", event.constitution),
+                "members": event.total,
+            }),
+        ))
     }
 }
 
@@ -104,7 +107,7 @@ where
     <T as Org>::Shares: From<u64> + Display,
     <T as Org>::IpfsReference: From<CidBytes> + Debug,
 {
-    async fn exec(&self, client: &dyn AbstractClient<T, P>) -> Result<()> {
+    async fn exec(&self, client: &dyn AbstractClient<T, P>) -> Result<Output> {
         let sudo: Option<T::AccountId> = if let Some(acc) = &self.sudo {
             let new_acc: Account<T> = acc.parse()?;
             Some(new_acc.id)
@@ -116,12 +119,11 @@ where
         } else {
             None
         };
-        let constitution: CidBytes = {
-            let content = self.constitution.as_bytes();
-            let hash = Blake2b256::digest(&content[..]);
-            let cid = Cid::new_v1(Codec::Raw, hash);
-            CidBytes::from(&cid)
-        };
+        let constitution = client
+            .insert_constitution(&ConstitutionDocument {
+                constitution: self.constitution.clone(),
+            })
+            .await?;
         let members = self
             .members
             .iter()
@@ -132,17 +134,153 @@ where
             })
             .collect::<Result<Vec<(T::AccountId, T::Shares)>>>()?;
         let event = client
-            .register_weighted_org(
-                sudo,
-                parent_org,
-                constitution.into(),
-                &members,
-            )
+            .register_weighted_org(sudo, parent_org, constitution, &members)
             .await?;
-        println!(
-            "Account {} created a weighted organization with OrgId: {}, constitution: {:?} and {} total shares minted for new members",
-            event.caller, event.new_id, event.constitution, event.total
-        );
-        Ok(())
+        Ok(Output::new(
+            format!(
+                "Account {} created a weighted organization with OrgId: {}, constitution: {:?} and {} total shares minted for new members",
+                event.caller, event.new_id, event.constitution, event.total
+            ),
+            serde_json::json!({
+                "caller": event.caller.to_string(),
+                "org": event.new_id,
+                "constitution": format!("{:?}", event.constitution),
+                "shares": event.total,
+            }),
+        ))
+    }
+}
+
+#[derive(Clone, Debug, Clap)]
+pub struct OrgShowCommand {
+    pub org: u64,
+}
+
+#[async_trait]
+impl<T: Runtime + Org + Vote + Donate + Bank + Bounty, P: Pair> Command<T, P>
+    for OrgShowCommand
+where
+    <T as System>::AccountId: Ss58Codec,
+    <T as Org>::OrgId: From<u64> + Display,
+{
+    async fn exec(&self, client: &dyn AbstractClient<T, P>) -> Result<Output> {
+        let org = client
+            .organization(self.org.into())
+            .await?
+            .ok_or(Error::OrgNotFound)?;
+        let constitution = client
+            .get_constitution(&org.constitution())
+            .await
+            .ok()
+            .map(|doc| doc.constitution);
+        Ok(Output::new(
+            format!(
+                "OrgId {} with supervisor {:?}, parent {:?}, constitution {:?} ({:?})",
+                self.org,
+                org.sudo(),
+                org.parent(),
+                org.constitution(),
+                constitution
+            ),
+            serde_json::json!({
+                "org": self.org,
+                "supervisor": org.sudo().map(|sudo| sudo.to_string()),
+                "parent": org.parent(),
+                "constitution": format!("{:?}", org.constitution()),
+                "constitution_document": constitution,
+            }),
+        ))
+    }
+}
+
+#[derive(Clone, Debug, Clap)]
+pub struct OrgListCommand {
+    #[clap(long = "offset", default_value = "0")]
+    pub offset: u32,
+    #[clap(long = "limit", default_value = "20")]
+    pub limit: u32,
+}
+
+#[async_trait]
+impl<T: Runtime + Org + Vote + Donate + Bank + Bounty, P: Pair> Command<T, P>
+    for OrgListCommand
+where
+    <T as System>::AccountId: Ss58Codec,
+    <T as Org>::OrgId: Display,
+{
+    async fn exec(&self, client: &dyn AbstractClient<T, P>) -> Result<Output> {
+        let page = Page {
+            offset: self.offset,
+            limit: self.limit,
+        };
+        let orgs = client
+            .organizations(page)
+            .await?
+            .into_iter()
+            .map(|(id, org)| {
+                Output::new(
+                    format!(
+                        "OrgId {} with supervisor {:?}, parent {:?} and constitution {:?}",
+                        id,
+                        org.sudo(),
+                        org.parent(),
+                        org.constitution()
+                    ),
+                    serde_json::json!({
+                        "org": id,
+                        "supervisor": org.sudo().map(|sudo| sudo.to_string()),
+                        "parent": org.parent(),
+                        "constitution": format!("{:?}", org.constitution()),
+                    }),
+                )
+            })
+            .collect();
+        Ok(Output::list(orgs))
+    }
+}
+
+#[derive(Clone, Debug, Clap)]
+pub struct OrgMembersCommand {
+    pub org: u64,
+    #[clap(long = "offset", default_value = "0")]
+    pub offset: u32,
+    #[clap(long = "limit", default_value = "20")]
+    pub limit: u32,
+}
+
+#[async_trait]
+impl<T: Runtime + Org + Vote + Donate + Bank + Bounty, P: Pair> Command<T, P>
+    for OrgMembersCommand
+where
+    <T as System>::AccountId: Ss58Codec,
+    <T as Org>::OrgId: From<u64> + Display,
+    <T as Org>::Shares: Display,
+{
+    async fn exec(&self, client: &dyn AbstractClient<T, P>) -> Result<Output> {
+        let page = Page {
+            offset: self.offset,
+            limit: self.limit,
+        };
+        let members = client
+            .members(self.org.into(), page)
+            .await?
+            .into_iter()
+            .map(|(who, profile)| {
+                Output::new(
+                    format!(
+                        "Account {} holds {} shares of OrgId {}",
+                        who,
+                        profile.total(),
+                        self.org
+                    ),
+                    serde_json::json!({
+                        "org": self.org,
+                        "account": who.to_string(),
+                        "shares": profile.total(),
+                    }),
+                )
+            })
+            .collect();
+        Ok(Output::list(members))
     }
 }
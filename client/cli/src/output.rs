@@ -0,0 +1,78 @@
+use crate::Error;
+use serde_json::Value;
+use std::str::FromStr;
+
+/// The format the result of a command is printed in
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum OutputFormat {
+    Text,
+    Json,
+}
+
+impl FromStr for OutputFormat {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            _ => Err(Error::InvalidOutputFormat),
+        }
+    }
+}
+
+/// The result of a command, a sentence for people and a json value with the
+/// same fields for scripts
+#[derive(Clone, Debug, Default)]
+pub struct Output {
+    text: Vec<String>,
+    json: Option<Value>,
+}
+
+impl Output {
+    pub fn new(text: String, json: Value) -> Self {
+        Self {
+            text: vec![text],
+            json: Some(json),
+        }
+    }
+    /// The result of a command which only prints a sentence
+    pub fn text(text: String) -> Self {
+        Self {
+            text: vec![text],
+            json: Some(Value::Object(Default::default())),
+        }
+    }
+    /// The result of a command which prints nothing, like `watch` which
+    /// streams its results while it runs
+    pub fn none() -> Self {
+        Self::default()
+    }
+    /// Collects the results of a listing, a line each and a json array
+    pub fn list(items: Vec<Output>) -> Self {
+        let mut text = Vec::with_capacity(items.len());
+        let mut json = Vec::with_capacity(items.len());
+        for item in items {
+            text.extend(item.text);
+            json.extend(item.json);
+        }
+        Self {
+            text,
+            json: Some(Value::Array(json)),
+        }
+    }
+    pub fn print(&self, format: OutputFormat) {
+        match format {
+            OutputFormat::Text => {
+                for line in &self.text {
+                    println!("{}", line);
+                }
+            }
+            OutputFormat::Json => {
+                if let Some(json) = &self.json {
+                    println!("{}", json);
+                }
+            }
+        }
+    }
+}
@@ -6,6 +6,7 @@ use crate::{
     Command,
     Donate,
     Org,
+    Output,
     Pair,
     Result,
     Runtime,
@@ -24,12 +25,16 @@ use substrate_subxt::{
     sp_core::crypto::Ss58Codec,
     system::System,
 };
+use util::share::VestingTerms;
 
 #[derive(Clone, Debug, Clap)]
 pub struct SharesIssueCommand {
     pub organization: u64,
     pub dest: String,
     pub shares: u64,
+    // the shares vest linearly over the vesting period, nothing vests before the cliff
+    pub vesting_period: Option<u32>,
+    pub cliff: Option<u32>,
 }
 
 #[async_trait]
@@ -39,21 +44,60 @@ where
     <T as System>::AccountId: Ss58Codec,
     <T as Org>::OrgId: From<u64> + Display,
     <T as Org>::Shares: From<u64> + Display,
+    <T as System>::BlockNumber: From<u32> + Display,
 {
-    async fn exec(&self, client: &dyn AbstractClient<T, P>) -> Result<()> {
+    async fn exec(&self, client: &dyn AbstractClient<T, P>) -> Result<Output> {
         let account: Account<T> = self.dest.parse()?;
-        let event = client
-            .issue_shares(
-                self.organization.into(),
-                account.id,
-                self.shares.into(),
-            )
-            .await?;
-        println!(
-            "{} shares minted for account {:?} in the context of Org {}",
-            event.shares, event.who, event.organization
-        );
-        Ok(())
+        if let Some(period) = self.vesting_period {
+            let terms = VestingTerms::new(
+                self.cliff.unwrap_or_default().into(),
+                period.into(),
+            );
+            let event = client
+                .issue_vesting_shares(
+                    self.organization.into(),
+                    account.id,
+                    self.shares.into(),
+                    terms,
+                )
+                .await?;
+            Ok(Output::new(
+                format!(
+                    "{} shares minted for account {:?} in the context of Org {}, vesting over {} blocks after a cliff of {} blocks",
+                    event.shares,
+                    event.who,
+                    event.organization,
+                    event.terms.period(),
+                    event.terms.cliff()
+                ),
+                serde_json::json!({
+                    "org": event.organization,
+                    "account": event.who.to_string(),
+                    "shares": event.shares,
+                    "vesting_period": event.terms.period(),
+                    "cliff": event.terms.cliff(),
+                }),
+            ))
+        } else {
+            let event = client
+                .issue_shares(
+                    self.organization.into(),
+                    account.id,
+                    self.shares.into(),
+                )
+                .await?;
+            Ok(Output::new(
+                format!(
+                    "{} shares minted for account {:?} in the context of Org {}",
+                    event.shares, event.who, event.organization
+                ),
+                serde_json::json!({
+                    "org": event.organization,
+                    "account": event.who.to_string(),
+                    "shares": event.shares,
+                }),
+            ))
+        }
     }
 }
 
@@ -71,7 +115,7 @@ where
     <T as Org>::OrgId: From<u64> + Display,
     <T as Org>::Shares: From<u64> + Display,
 {
-    async fn exec(&self, client: &dyn AbstractClient<T, P>) -> Result<()> {
+    async fn exec(&self, client: &dyn AbstractClient<T, P>) -> Result<Output> {
         let accounts = self.new_accounts.iter().map(|acc_share| -> Result<(<T as System>::AccountId, <T as Org>::Shares)> {
             let account: Account<T> = acc_share.0.parse()?;
             let amount_issued: T::Shares = (acc_share.1).into();
@@ -80,11 +124,16 @@ where
         let event = client
             .batch_issue_shares(self.organization.into(), accounts.as_slice())
             .await?;
-        println!(
-            "{} new shares minted in the context of Org {}",
-            event.total_new_shares_minted, event.organization
-        );
-        Ok(())
+        Ok(Output::new(
+            format!(
+                "{} new shares minted in the context of Org {}",
+                event.total_new_shares_minted, event.organization
+            ),
+            serde_json::json!({
+                "org": event.organization,
+                "shares": event.total_new_shares_minted,
+            }),
+        ))
     }
 }
 
@@ -102,7 +151,7 @@ where
     <T as Org>::OrgId: From<u64> + Display,
     <T as Org>::Shares: From<u64> + Display,
 {
-    async fn exec(&self, client: &dyn AbstractClient<T, P>) -> Result<()> {
+    async fn exec(&self, client: &dyn AbstractClient<T, P>) -> Result<Output> {
         let accounts = self.old_accounts.iter().map(|acc_share| -> Result<(<T as System>::AccountId, <T as Org>::Shares)> {
             let account: Account<T> = acc_share.0.parse()?;
             let amount_burned: T::Shares = (acc_share.1).into();
@@ -111,11 +160,16 @@ where
         let event = client
             .batch_issue_shares(self.organization.into(), accounts.as_slice())
             .await?;
-        println!(
-            "{} new shares minted in the context of Org {}",
-            event.total_new_shares_minted, event.organization
-        );
-        Ok(())
+        Ok(Output::new(
+            format!(
+                "{} new shares minted in the context of Org {}",
+                event.total_new_shares_minted, event.organization
+            ),
+            serde_json::json!({
+                "org": event.organization,
+                "shares": event.total_new_shares_minted,
+            }),
+        ))
     }
 }
 
@@ -134,7 +188,7 @@ where
     <T as Org>::OrgId: From<u64> + Display,
     <T as Org>::Shares: From<u64> + Display,
 {
-    async fn exec(&self, client: &dyn AbstractClient<T, P>) -> Result<()> {
+    async fn exec(&self, client: &dyn AbstractClient<T, P>) -> Result<Output> {
         let account: Account<T> = self.burner.parse()?;
         let event = client
             .issue_shares(
@@ -143,11 +197,17 @@ where
                 self.shares.into(),
             )
             .await?;
-        println!(
-            "{} shares burned from account {:?} in the context of Org {}",
-            event.shares, event.who, event.organization
-        );
-        Ok(())
+        Ok(Output::new(
+            format!(
+                "{} shares burned from account {:?} in the context of Org {}",
+                event.shares, event.who, event.organization
+            ),
+            serde_json::json!({
+                "org": event.organization,
+                "account": event.who.to_string(),
+                "shares": event.shares,
+            }),
+        ))
     }
 }
 
@@ -165,16 +225,22 @@ where
     <T as Org>::OrgId: From<u64> + Display,
     <T as Org>::Shares: From<u64> + Display,
 {
-    async fn exec(&self, client: &dyn AbstractClient<T, P>) -> Result<()> {
+    async fn exec(&self, client: &dyn AbstractClient<T, P>) -> Result<Output> {
         let account: Account<T> = self.who.parse()?;
         let event = client
             .reserve_shares(self.organization.into(), &account.id)
             .await?;
-        println!(
-            "Account {} reserves {:?} shares in the context of Org {}",
-            event.who, event.amount_reserved, event.organization
-        );
-        Ok(())
+        Ok(Output::new(
+            format!(
+                "Account {} reserves {:?} shares in the context of Org {}",
+                event.who, event.amount_reserved, event.organization
+            ),
+            serde_json::json!({
+                "org": event.organization,
+                "account": event.who.to_string(),
+                "shares": event.amount_reserved,
+            }),
+        ))
     }
 }
 
@@ -192,16 +258,22 @@ where
     <T as Org>::OrgId: From<u64> + Display,
     <T as Org>::Shares: From<u64> + Display,
 {
-    async fn exec(&self, client: &dyn AbstractClient<T, P>) -> Result<()> {
+    async fn exec(&self, client: &dyn AbstractClient<T, P>) -> Result<Output> {
         let account: Account<T> = self.who.parse()?;
         let event = client
             .unreserve_shares(self.organization.into(), &account.id)
             .await?;
-        println!(
-            "Account {} unreserves {:?} shares in the context of Org {}",
-            event.who, event.amount_unreserved, event.organization
-        );
-        Ok(())
+        Ok(Output::new(
+            format!(
+                "Account {} unreserves {:?} shares in the context of Org {}",
+                event.who, event.amount_unreserved, event.organization
+            ),
+            serde_json::json!({
+                "org": event.organization,
+                "account": event.who.to_string(),
+                "shares": event.amount_unreserved,
+            }),
+        ))
     }
 }
 
@@ -219,16 +291,21 @@ where
     <T as Org>::OrgId: From<u64> + Display,
     <T as Org>::Shares: From<u64> + Display,
 {
-    async fn exec(&self, client: &dyn AbstractClient<T, P>) -> Result<()> {
+    async fn exec(&self, client: &dyn AbstractClient<T, P>) -> Result<Output> {
         let account: Account<T> = self.who.parse()?;
         let event = client
             .lock_shares(self.organization.into(), &account.id)
             .await?;
-        println!(
-            "Locked shares for Account {} in the context of Org {}",
-            event.who, event.organization
-        );
-        Ok(())
+        Ok(Output::new(
+            format!(
+                "Locked shares for Account {} in the context of Org {}",
+                event.who, event.organization
+            ),
+            serde_json::json!({
+                "org": event.organization,
+                "account": event.who.to_string(),
+            }),
+        ))
     }
 }
 
@@ -246,15 +323,20 @@ where
     <T as Org>::OrgId: From<u64> + Display,
     <T as Org>::Shares: From<u64> + Display,
 {
-    async fn exec(&self, client: &dyn AbstractClient<T, P>) -> Result<()> {
+    async fn exec(&self, client: &dyn AbstractClient<T, P>) -> Result<Output> {
         let account: Account<T> = self.who.parse()?;
         let event = client
             .unlock_shares(self.organization.into(), &account.id)
             .await?;
-        println!(
-            "Unlocked shares for Account {} in the context of Org {}",
-            event.who, event.organization
-        );
-        Ok(())
+        Ok(Output::new(
+            format!(
+                "Unlocked shares for Account {} in the context of Org {}",
+                event.who, event.organization
+            ),
+            serde_json::json!({
+                "org": event.organization,
+                "account": event.who.to_string(),
+            }),
+        ))
     }
 }
@@ -0,0 +1,326 @@
+use crate::{
+    ask_for_password,
+    async_trait,
+    AbstractClient,
+    Bank,
+    Bounty,
+    Command,
+    Donate,
+    Error,
+    Org,
+    Output,
+    Pair,
+    Result,
+    Runtime,
+    Vote,
+};
+use bounty_client::{
+    Account,
+    OfflineCall,
+    SignedTransaction,
+    UnsignedTransaction,
+};
+use clap::Clap;
+use codec::{
+    Decode,
+    Encode,
+};
+use core::fmt::Display;
+use keystore::KeyStore;
+use std::path::{
+    Path,
+    PathBuf,
+};
+use substrate_subxt::{
+    sp_core::{
+        bytes::{
+            from_hex,
+            to_hex,
+        },
+        crypto::Ss58Codec,
+    },
+    system::System,
+};
+use util::bank::OnChainTreasuryID;
+
+/// Transactions are moved between devices as hex encoded scale
+async fn write_transaction<E: Encode>(path: &Path, tx: &E) -> Result<()> {
+    async_std::fs::write(path, to_hex(&tx.encode(), false)).await?;
+    Ok(())
+}
+
+async fn read_transaction<D: Decode>(path: &Path) -> Result<D> {
+    let hex = async_std::fs::read_to_string(path).await?;
+    let bytes =
+        from_hex(hex.trim()).map_err(|_| Error::InvalidTransactionFile)?;
+    D::decode(&mut &bytes[..]).map_err(|_| Error::InvalidTransactionFile)
+}
+
+/// The calls that can be prepared for an offline signer
+#[derive(Clone, Debug, Clap)]
+pub enum TxCall {
+    IssueShares {
+        org: u64,
+        who: String,
+        shares: u64,
+    },
+    BurnShares {
+        org: u64,
+        who: String,
+        shares: u64,
+    },
+    LockShares {
+        org: u64,
+        who: String,
+    },
+    UnlockShares {
+        org: u64,
+        who: String,
+    },
+    ProposeSpend {
+        bank_id: u64,
+        amount: u128,
+        dest: String,
+    },
+    SudoApproveSpend {
+        bank_id: u64,
+        spend_id: u64,
+    },
+    CloseBankAccount {
+        bank_id: u64,
+    },
+    SudoApproveApplication {
+        bounty_id: u64,
+        app_id: u64,
+    },
+    SudoApproveMilestone {
+        bounty_id: u64,
+        milestone_id: u64,
+    },
+}
+
+impl TxCall {
+    fn to_call<T: Runtime + Bounty>(&self) -> Result<OfflineCall<T>>
+    where
+        <T as System>::AccountId: Ss58Codec,
+        <T as Org>::OrgId: From<u64>,
+        <T as Org>::Shares: From<u64>,
+        <T as Bank>::Currency: From<u128>,
+        <T as Bank>::SpendId: From<u64>,
+        <T as Bounty>::BountyId: From<u64>,
+    {
+        let account = |account: &str| -> Result<<T as System>::AccountId> {
+            let account: Account<T> = account.parse()?;
+            Ok(account.id)
+        };
+        let bank = |bank_id: u64| OnChainTreasuryID(bank_id.to_be_bytes());
+        Ok(match self {
+            Self::IssueShares { org, who, shares } => {
+                OfflineCall::IssueShares {
+                    org: (*org).into(),
+                    who: account(who)?,
+                    shares: (*shares).into(),
+                }
+            }
+            Self::BurnShares { org, who, shares } => {
+                OfflineCall::BurnShares {
+                    org: (*org).into(),
+                    who: account(who)?,
+                    shares: (*shares).into(),
+                }
+            }
+            Self::LockShares { org, who } => {
+                OfflineCall::LockShares {
+                    org: (*org).into(),
+                    who: account(who)?,
+                }
+            }
+            Self::UnlockShares { org, who } => {
+                OfflineCall::UnlockShares {
+                    org: (*org).into(),
+                    who: account(who)?,
+                }
+            }
+            Self::ProposeSpend {
+                bank_id,
+                amount,
+                dest,
+            } => {
+                OfflineCall::ProposeSpend {
+                    bank_id: bank(*bank_id),
+                    amount: (*amount).into(),
+                    dest: account(dest)?,
+                }
+            }
+            Self::SudoApproveSpend { bank_id, spend_id } => {
+                OfflineCall::SudoApproveSpend {
+                    bank_id: bank(*bank_id),
+                    spend_id: (*spend_id).into(),
+                }
+            }
+            Self::CloseBankAccount { bank_id } => {
+                OfflineCall::CloseBankAccount {
+                    bank_id: bank(*bank_id),
+                }
+            }
+            Self::SudoApproveApplication { bounty_id, app_id } => {
+                OfflineCall::SudoApproveApplication {
+                    bounty_id: (*bounty_id).into(),
+                    application_id: (*app_id).into(),
+                }
+            }
+            Self::SudoApproveMilestone {
+                bounty_id,
+                milestone_id,
+            } => {
+                OfflineCall::SudoApproveMilestone {
+                    bounty_id: (*bounty_id).into(),
+                    milestone_id: (*milestone_id).into(),
+                }
+            }
+        })
+    }
+}
+
+/// Writes a transaction of `--signer` to a file, to be signed with `tx sign`
+/// on the device holding its key
+#[derive(Clone, Debug, Clap)]
+pub struct TxPrepareCommand {
+    /// The account signing the transaction.
+    #[clap(long = "signer")]
+    pub signer: String,
+    /// Overrides the next nonce of the signer, to prepare several
+    /// transactions before submitting them.
+    #[clap(long = "nonce")]
+    pub nonce: Option<u32>,
+    /// The number of blocks the transaction stays valid for, rounded to a
+    /// power of two.
+    #[clap(long = "era-period", default_value = "64")]
+    pub era_period: u64,
+    /// The file the unsigned transaction is written to.
+    pub file: PathBuf,
+    #[clap(subcommand)]
+    pub call: TxCall,
+}
+
+#[async_trait]
+impl<T: Runtime + Org + Vote + Donate + Bank + Bounty, P: Pair> Command<T, P>
+    for TxPrepareCommand
+where
+    <T as System>::AccountId: Ss58Codec,
+    <T as System>::Index: From<u32> + Display,
+    <T as Org>::OrgId: From<u64>,
+    <T as Org>::Shares: From<u64>,
+    <T as Bank>::Currency: From<u128>,
+    <T as Bank>::SpendId: From<u64>,
+    <T as Bounty>::BountyId: From<u64>,
+{
+    async fn exec(&self, client: &dyn AbstractClient<T, P>) -> Result<Output> {
+        let signer: Account<T> = self.signer.parse()?;
+        let tx = client
+            .prepare_transaction(
+                &signer.id,
+                self.nonce.map(Into::into),
+                self.era_period,
+                self.call.to_call()?,
+            )
+            .await?;
+        write_transaction(&self.file, &tx).await?;
+        Ok(Output::new(
+            format!(
+                "Wrote the transaction of {} with nonce {}, valid from block {:?} for {:?}, to {}",
+                tx.signer,
+                tx.nonce,
+                tx.checkpoint,
+                tx.era,
+                self.file.display()
+            ),
+            serde_json::json!({
+                "signer": tx.signer.to_string(),
+                "nonce": tx.nonce.to_string(),
+                "checkpoint": format!("{:?}", tx.checkpoint),
+                "file": self.file.display().to_string(),
+            }),
+        ))
+    }
+}
+
+/// Signs a prepared transaction with the device key, without connecting to
+/// a node
+#[derive(Clone, Debug, Clap)]
+pub struct TxSignCommand {
+    /// The file of the unsigned transaction.
+    pub unsigned: PathBuf,
+    /// The file the signed transaction is written to.
+    pub signed: PathBuf,
+}
+
+impl TxSignCommand {
+    pub async fn exec_offline<T: Runtime, P: Pair>(
+        &self,
+        keystore: &KeyStore,
+    ) -> Result<Output>
+    where
+        <T as System>::AccountId: Ss58Codec,
+        <T as System>::Index: Display,
+        T::Signature: From<P::Signature>,
+        <P as Pair>::Public: Into<<T as System>::AccountId>,
+        <P as Pair>::Seed: From<[u8; 32]>,
+    {
+        let tx: UnsignedTransaction<T> =
+            read_transaction(&self.unsigned).await?;
+        eprintln!(
+            "Signing the transaction of {} with nonce {}, era {:?} from block {:?} and genesis hash {:?}",
+            tx.signer, tx.nonce, tx.era, tx.checkpoint, tx.genesis_hash
+        );
+        let password = ask_for_password("Please enter your password:\n")?;
+        keystore.unlock(&password).await?;
+        let dk = keystore.device_key().await?;
+        let pair = P::from_seed(&P::Seed::from(*dk.expose_secret()));
+        let signed = tx.sign(&pair)?;
+        write_transaction(&self.signed, &signed).await?;
+        Ok(Output::new(
+            format!(
+                "Wrote the signed transaction of {} to {}",
+                signed.signer,
+                self.signed.display()
+            ),
+            serde_json::json!({
+                "signer": signed.signer.to_string(),
+                "file": self.signed.display().to_string(),
+            }),
+        ))
+    }
+}
+
+/// Submits a transaction signed with `tx sign` and prints its events
+#[derive(Clone, Debug, Clap)]
+pub struct TxSubmitCommand {
+    /// The file of the signed transaction.
+    pub file: PathBuf,
+}
+
+#[async_trait]
+impl<T: Runtime + Org + Vote + Donate + Bank + Bounty, P: Pair> Command<T, P>
+    for TxSubmitCommand
+{
+    async fn exec(&self, client: &dyn AbstractClient<T, P>) -> Result<Output> {
+        let tx: SignedTransaction<T> = read_transaction(&self.file).await?;
+        let events = client
+            .submit_transaction(tx)
+            .await?
+            .into_iter()
+            .map(|event| {
+                Output::new(
+                    format!("{}::{} {:?}", event.module(), event.name(), event),
+                    serde_json::json!({
+                        "module": event.module(),
+                        "event": event.name(),
+                        "data": format!("{:?}", event),
+                    }),
+                )
+            })
+            .collect();
+        Ok(Output::list(events))
+    }
+}
@@ -6,11 +6,13 @@ use crate::{
     Command,
     Donate,
     Org,
+    Output,
     Pair,
     Result,
     Runtime,
     Vote,
 };
+use bounty_client::JustificationDocument;
 use clap::Clap;
 use core::fmt::{
     Debug,
@@ -50,7 +52,7 @@ where
     <T as Vote>::Signal: From<u64> + Display,
     <T as Vote>::VoteId: From<u64> + Display,
 {
-    async fn exec(&self, client: &dyn AbstractClient<T, P>) -> Result<()> {
+    async fn exec(&self, client: &dyn AbstractClient<T, P>) -> Result<Output> {
         let topic: Option<T::IpfsReference> =
             if let Some(topic_ref) = &self.topic {
                 let content = topic_ref.as_bytes();
@@ -81,11 +83,17 @@ where
                 duration,
             )
             .await?;
-        println!(
-            "Account {} created a threshold approval vote for OrgId {} with VoteId {}",
-            event.caller, event.org, event.new_vote_id
-        );
-        Ok(())
+        Ok(Output::new(
+            format!(
+                "Account {} created a threshold approval vote for OrgId {} with VoteId {}",
+                event.caller, event.org, event.new_vote_id
+            ),
+            serde_json::json!({
+                "caller": event.caller.to_string(),
+                "org": event.org,
+                "vote": event.new_vote_id,
+            }),
+        ))
     }
 }
 
@@ -107,7 +115,7 @@ where
     <T as Vote>::Signal: From<u64> + Display,
     <T as Vote>::VoteId: From<u64> + Display,
 {
-    async fn exec(&self, client: &dyn AbstractClient<T, P>) -> Result<()> {
+    async fn exec(&self, client: &dyn AbstractClient<T, P>) -> Result<Output> {
         let topic: Option<T::IpfsReference> =
             if let Some(topic_ref) = &self.topic {
                 let content = topic_ref.as_bytes();
@@ -130,11 +138,17 @@ where
                 duration,
             )
             .await?;
-        println!(
-            "Account {} created a unanimous consent vote for OrgId {} with VoteId {}",
-            event.caller, event.org, event.new_vote_id
-        );
-        Ok(())
+        Ok(Output::new(
+            format!(
+                "Account {} created a unanimous consent vote for OrgId {} with VoteId {}",
+                event.caller, event.org, event.new_vote_id
+            ),
+            serde_json::json!({
+                "caller": event.caller.to_string(),
+                "org": event.org,
+                "vote": event.new_vote_id,
+            }),
+        ))
     }
 }
 
@@ -153,13 +167,16 @@ where
     <T as Org>::IpfsReference: From<CidBytes> + Debug,
     <T as Vote>::VoteId: From<u64> + Display,
 {
-    async fn exec(&self, client: &dyn AbstractClient<T, P>) -> Result<()> {
+    async fn exec(&self, client: &dyn AbstractClient<T, P>) -> Result<Output> {
         let justification: Option<T::IpfsReference> =
-            if let Some(topic_ref) = &self.justification {
-                let content = topic_ref.as_bytes();
-                let hash = Blake2b256::digest(&content[..]);
-                let cid = Cid::new_v1(Codec::Raw, hash);
-                Some(CidBytes::from(&cid).into())
+            if let Some(justification) = &self.justification {
+                Some(
+                    client
+                        .insert_justification(&JustificationDocument {
+                            justification: justification.clone(),
+                        })
+                        .await?,
+                )
             } else {
                 None
             };
@@ -169,12 +186,18 @@ where
             _ => VoterView::Abstain,
         };
         let event = client
-            .submit_vote(self.vote_id.into(), voter_view, justification.into())
+            .submit_vote(self.vote_id.into(), voter_view, justification)
             .await?;
-        println!(
-            "Account {} voted with view {:?} in VoteId {}",
-            event.voter, event.view, event.vote_id
-        );
-        Ok(())
+        Ok(Output::new(
+            format!(
+                "Account {} voted with view {:?} in VoteId {}",
+                event.voter, event.view, event.vote_id
+            ),
+            serde_json::json!({
+                "voter": event.voter.to_string(),
+                "vote": event.vote_id,
+                "view": format!("{:?}", event.view),
+            }),
+        ))
     }
 }
@@ -7,6 +7,7 @@ use crate::{
     Donate,
     Error,
     Org,
+    Output,
     Pair,
     Result,
     Runtime,
@@ -44,14 +45,19 @@ impl<T: Runtime + Org + Vote + Donate + Bank + Bounty + Balances, P: Pair>
 where
     <T as System>::AccountId: Ss58Codec,
 {
-    async fn exec(&self, client: &dyn AbstractClient<T, P>) -> Result<()> {
+    async fn exec(&self, client: &dyn AbstractClient<T, P>) -> Result<Output> {
         let account: Account<T> = self.account.parse()?;
         let amount = client.subxt().account(&account.id, None).await?;
-        println!(
-            "AccountId {:?} has account balance data {:?}",
-            account.id, amount.data
-        );
-        Ok(())
+        Ok(Output::new(
+            format!(
+                "AccountId {:?} has account balance data {:?}",
+                account.id, amount.data
+            ),
+            serde_json::json!({
+                "account": account.id.to_string(),
+                "data": format!("{:?}", amount.data),
+            }),
+        ))
     }
 }
 
@@ -69,7 +75,7 @@ where
         Send + Sync,
     <T as Balances>::Balance: From<u128> + Display,
 {
-    async fn exec(&self, client: &dyn AbstractClient<T, P>) -> Result<()> {
+    async fn exec(&self, client: &dyn AbstractClient<T, P>) -> Result<Output> {
         let account: Account<T> = self.dest.parse()?;
         let signer = client.signer().await?;
         let event = client
@@ -79,7 +85,13 @@ where
             .transfer()
             .map_err(|_| Error::TransferEventDecode)?
             .ok_or(Error::TransferEventFind)?;
-        println!("transferred {} to {}", event.amount, event.to.to_string());
-        Ok(())
+        Ok(Output::new(
+            format!("transferred {} to {}", event.amount, event.to.to_string()),
+            serde_json::json!({
+                "from": event.from.to_string(),
+                "to": event.to.to_string(),
+                "amount": event.amount.to_string(),
+            }),
+        ))
     }
 }
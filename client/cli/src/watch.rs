@@ -0,0 +1,82 @@
+use crate::{
+    async_trait,
+    AbstractClient,
+    Bank,
+    Bounty,
+    Command,
+    Donate,
+    Org,
+    Output,
+    Pair,
+    Result,
+    Runtime,
+    Vote,
+};
+use bounty_client::{
+    Account,
+    EventFilter,
+};
+use clap::Clap;
+use substrate_subxt::{
+    sp_core::crypto::Ss58Codec,
+    system::System,
+};
+
+/// Streams the events of finalized blocks to stdout, one JSON object per line
+#[derive(Clone, Debug, Clap)]
+pub struct WatchCommand {
+    #[clap(long = "org")]
+    pub org: Option<u64>,
+    #[clap(long = "bounty")]
+    pub bounty: Option<u64>,
+    #[clap(long = "account")]
+    pub account: Option<String>,
+    #[clap(long = "from-block")]
+    pub from_block: Option<u32>,
+}
+
+#[async_trait]
+impl<T: Runtime + Org + Vote + Donate + Bank + Bounty, P: Pair> Command<T, P>
+    for WatchCommand
+where
+    <T as System>::AccountId: Ss58Codec,
+    <T as System>::BlockNumber: From<u32>,
+    <T as Org>::OrgId: From<u64>,
+    <T as Bounty>::BountyId: From<u64>,
+{
+    async fn exec(&self, client: &dyn AbstractClient<T, P>) -> Result<Output> {
+        let account = if let Some(account) = &self.account {
+            let account: Account<T> = account.parse()?;
+            Some(account.id)
+        } else {
+            None
+        };
+        let filter = EventFilter {
+            org: self.org.map(Into::into),
+            bounty: self.bounty.map(Into::into),
+            account,
+        };
+        let mut events = client
+            .watch_events(filter, self.from_block.map(Into::into))
+            .await?;
+        while let Some(event) = events.next().await {
+            let (block, event) = event?;
+            let accounts: Vec<String> = event
+                .accounts()
+                .into_iter()
+                .map(|account| account.to_ss58check())
+                .collect();
+            let line = serde_json::json!({
+                "block": block,
+                "module": event.module(),
+                "event": event.name(),
+                "orgs": event.orgs(),
+                "bounties": event.bounties(),
+                "accounts": accounts,
+                "data": format!("{:?}", event),
+            });
+            println!("{}", line);
+        }
+        Ok(Output::none())
+    }
+}
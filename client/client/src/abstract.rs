@@ -1,5 +1,17 @@
 use crate::{
+    document::*,
     error::Result,
+    events::{
+        EventFilter,
+        EventSubscription,
+        SunshineEvent,
+    },
+    offline::{
+        MortalExtra,
+        OfflineCall,
+        SignedTransaction,
+        UnsignedTransaction,
+    },
     srml::{
         bank::*,
         bounty::*,
@@ -7,11 +19,13 @@ use crate::{
         org::*,
         vote::*,
     },
+    utils::Page,
     Client,
 };
 use async_trait::async_trait;
 use codec::Decode;
 use keystore::{
+    bip39::Mnemonic,
     DeviceKey,
     Password,
 };
@@ -34,9 +48,19 @@ use substrate_subxt::{
     Signer,
 };
 use util::{
+    bank::{
+        BankState,
+        OnChainTreasuryID,
+    },
     court::ResolutionMetadata,
+    organization::Organization,
+    share::{
+        ShareProfile,
+        VestingTerms,
+    },
     vote::VoterView,
 };
+use utils_identity::cid::CidBytes;
 
 #[async_trait]
 pub trait AbstractClient<
@@ -55,6 +79,150 @@ pub trait AbstractClient<
     async fn signer(&self) -> Result<Box<dyn Signer<T> + Send + Sync>>;
     async fn lock(&self) -> Result<()>;
     async fn unlock(&self, password: &Password) -> Result<()>;
+    async fn export_paperkey(&self, password: &Password) -> Result<Mnemonic>;
+    // ipfs documents
+    async fn insert_constitution(
+        &self,
+        constitution: &ConstitutionDocument,
+    ) -> Result<<T as Org>::IpfsReference>;
+    async fn insert_justification(
+        &self,
+        justification: &JustificationDocument,
+    ) -> Result<<T as Org>::IpfsReference>;
+    async fn get_constitution(
+        &self,
+        reference: &<T as Org>::IpfsReference,
+    ) -> Result<ConstitutionDocument>;
+    async fn get_justification(
+        &self,
+        reference: &<T as Org>::IpfsReference,
+    ) -> Result<JustificationDocument>;
+    async fn get_bounty_description(
+        &self,
+        reference: &<T as Org>::IpfsReference,
+    ) -> Result<BountyDocument>;
+    async fn get_application(
+        &self,
+        reference: &<T as Org>::IpfsReference,
+    ) -> Result<ApplicationDocument>;
+    async fn get_milestone(
+        &self,
+        reference: &<T as Org>::IpfsReference,
+    ) -> Result<MilestoneDocument>;
+    // events
+    async fn watch_events(
+        &self,
+        filter: EventFilter<T>,
+        from: Option<<T as System>::BlockNumber>,
+    ) -> Result<EventSubscription<T>>;
+    // offline transactions
+    async fn prepare_transaction(
+        &self,
+        signer: &<T as System>::AccountId,
+        nonce: Option<<T as System>::Index>,
+        era_period: u64,
+        call: OfflineCall<T>,
+    ) -> Result<UnsignedTransaction<T>>;
+    async fn sign_transaction(
+        &self,
+        tx: &UnsignedTransaction<T>,
+    ) -> Result<SignedTransaction<T>>;
+    async fn submit_transaction(
+        &self,
+        tx: SignedTransaction<T>,
+    ) -> Result<Vec<SunshineEvent<T>>>;
+    // storage queries
+    async fn organization(
+        &self,
+        org: <T as Org>::OrgId,
+    ) -> Result<
+        Option<
+            Organization<
+                <T as System>::AccountId,
+                <T as Org>::OrgId,
+                <T as Org>::IpfsReference,
+            >,
+        >,
+    >;
+    async fn organizations(
+        &self,
+        page: Page,
+    ) -> Result<
+        Vec<(
+            <T as Org>::OrgId,
+            Organization<
+                <T as System>::AccountId,
+                <T as Org>::OrgId,
+                <T as Org>::IpfsReference,
+            >,
+        )>,
+    >;
+    async fn member(
+        &self,
+        org: <T as Org>::OrgId,
+        who: &<T as System>::AccountId,
+    ) -> Result<Option<ShareProfile<<T as Org>::Shares>>>;
+    async fn members(
+        &self,
+        org: <T as Org>::OrgId,
+        page: Page,
+    ) -> Result<Vec<(<T as System>::AccountId, ShareProfile<<T as Org>::Shares>)>>;
+    async fn bank(
+        &self,
+        id: OnChainTreasuryID,
+    ) -> Result<Option<BankState<<T as System>::AccountId, <T as Org>::OrgId>>>;
+    async fn org_banks(
+        &self,
+        org: <T as Org>::OrgId,
+        page: Page,
+    ) -> Result<
+        Vec<(
+            OnChainTreasuryID,
+            BankState<<T as System>::AccountId, <T as Org>::OrgId>,
+        )>,
+    >;
+    async fn bank_account_data(
+        &self,
+        id: OnChainTreasuryID,
+    ) -> Result<<T as System>::AccountData>;
+    async fn spend_proposal(
+        &self,
+        bank_id: OnChainTreasuryID,
+        spend_id: <T as Bank>::SpendId,
+    ) -> Result<Option<SpendProposalOf<T>>>;
+    async fn spend_proposals(
+        &self,
+        bank_id: OnChainTreasuryID,
+        page: Page,
+    ) -> Result<Vec<(<T as Bank>::SpendId, SpendProposalOf<T>)>>;
+    async fn bounty(
+        &self,
+        id: <T as Bounty>::BountyId,
+    ) -> Result<Option<BountyInformationOf<T>>>;
+    async fn bounties(
+        &self,
+        page: Page,
+    ) -> Result<Vec<(<T as Bounty>::BountyId, BountyInformationOf<T>)>>;
+    async fn application(
+        &self,
+        bounty_id: <T as Bounty>::BountyId,
+        application_id: <T as Bounty>::BountyId,
+    ) -> Result<Option<GrantApplicationOf<T>>>;
+    async fn applications(
+        &self,
+        bounty_id: <T as Bounty>::BountyId,
+        page: Page,
+    ) -> Result<Vec<(<T as Bounty>::BountyId, GrantApplicationOf<T>)>>;
+    async fn milestone(
+        &self,
+        bounty_id: <T as Bounty>::BountyId,
+        milestone_id: <T as Bounty>::BountyId,
+    ) -> Result<Option<MilestoneSubmissionOf<T>>>;
+    async fn milestones(
+        &self,
+        bounty_id: <T as Bounty>::BountyId,
+        page: Page,
+    ) -> Result<Vec<(<T as Bounty>::BountyId, MilestoneSubmissionOf<T>)>>;
     // org module calls
     async fn register_flat_org(
         &self,
@@ -76,6 +244,13 @@ pub trait AbstractClient<
         who: <T as System>::AccountId,
         shares: <T as Org>::Shares,
     ) -> Result<SharesIssuedEvent<T>>;
+    async fn issue_vesting_shares(
+        &self,
+        organization: <T as Org>::OrgId,
+        who: <T as System>::AccountId,
+        shares: <T as Org>::Shares,
+        terms: VestingTerms<<T as System>::BlockNumber>,
+    ) -> Result<VestingSharesIssuedEvent<T>>;
     async fn burn_shares(
         &self,
         organization: <T as Org>::OrgId,
@@ -150,11 +325,36 @@ pub trait AbstractClient<
         seed: BalanceOf<T>,
         hosting_org: <T as Org>::OrgId,
         bank_operator: Option<<T as System>::AccountId>,
-    ) -> Result<OrgBankAccountOpenedEvent<T>>;
+    ) -> Result<BankAccountOpenedEvent<T>>;
+    async fn member_proposes_spend(
+        &self,
+        bank_id: OnChainTreasuryID,
+        amount: BalanceOf<T>,
+        dest: <T as System>::AccountId,
+    ) -> Result<SpendProposedByMemberEvent<T>>;
+    async fn member_triggers_vote_on_spend_proposal(
+        &self,
+        bank_id: OnChainTreasuryID,
+        spend_id: <T as Bank>::SpendId,
+    ) -> Result<VoteTriggeredOnSpendProposalEvent<T>>;
+    async fn member_sudo_approves_spend_proposal(
+        &self,
+        bank_id: OnChainTreasuryID,
+        spend_id: <T as Bank>::SpendId,
+    ) -> Result<SudoApprovedSpendProposalEvent<T>>;
+    async fn member_polls_spend_proposal(
+        &self,
+        bank_id: OnChainTreasuryID,
+        spend_id: <T as Bank>::SpendId,
+    ) -> Result<SpendProposalPolledEvent<T>>;
+    async fn close_org_bank_account(
+        &self,
+        bank_id: OnChainTreasuryID,
+    ) -> Result<BankAccountClosedEvent<T>>;
     // bounty module calls
     async fn account_posts_bounty(
         &self,
-        description: <T as Org>::IpfsReference,
+        description: &BountyDocument,
         amount_reserved_for_bounty: BalanceOf<T>,
         acceptance_committee: ResolutionMetadata<
             <T as Org>::OrgId,
@@ -168,13 +368,16 @@ pub trait AbstractClient<
                 <T as System>::BlockNumber,
             >,
         >,
-    ) -> Result<BountyPostedEvent<T>>;
+    ) -> Result<(<T as Org>::IpfsReference, BountyPostedEvent<T>)>;
     async fn account_applies_for_bounty(
         &self,
         bounty_id: <T as Bounty>::BountyId,
-        description: <T as Org>::IpfsReference,
+        description: &ApplicationDocument,
         total_amount: BalanceOf<T>,
-    ) -> Result<BountyApplicationSubmittedEvent<T>>;
+    ) -> Result<(
+        <T as Org>::IpfsReference,
+        BountyApplicationSubmittedEvent<T>,
+    )>;
     async fn account_triggers_application_review(
         &self,
         bounty_id: <T as Bounty>::BountyId,
@@ -194,9 +397,9 @@ pub trait AbstractClient<
         &self,
         bounty_id: <T as Bounty>::BountyId,
         application_id: <T as Bounty>::BountyId,
-        submission_reference: <T as Org>::IpfsReference,
+        submission: &MilestoneDocument,
         amount_requested: BalanceOf<T>,
-    ) -> Result<MilestoneSubmittedEvent<T>>;
+    ) -> Result<(<T as Org>::IpfsReference, MilestoneSubmittedEvent<T>)>;
     async fn trigger_milestone_review(
         &self,
         bounty_id: <T as Bounty>::BountyId,
@@ -225,9 +428,11 @@ where
         From<P::Public> + IdentifyAccount<AccountId = <T as System>::AccountId>,
     <<T::Extra as SignedExtra<T>>::Extra as SignedExtension>::AdditionalSigned:
         Send + Sync,
+    <T::Extra as SignedExtra<T>>::Extra: MortalExtra<T>,
     P: Pair,
     <P as Pair>::Public: Into<<T as System>::AccountId>,
     <P as Pair>::Seed: From<[u8; 32]>,
+    <T as Org>::IpfsReference: From<CidBytes> + Into<CidBytes>,
     I: Store + Send + Sync,
 {
     async fn has_device_key(&self) -> bool {
@@ -255,6 +460,229 @@ where
         self.unlock(password).await
     }
 
+    async fn export_paperkey(&self, password: &Password) -> Result<Mnemonic> {
+        self.export_paperkey(password).await
+    }
+
+    async fn insert_constitution(
+        &self,
+        constitution: &ConstitutionDocument,
+    ) -> Result<<T as Org>::IpfsReference> {
+        self.insert_constitution(constitution).await
+    }
+
+    async fn insert_justification(
+        &self,
+        justification: &JustificationDocument,
+    ) -> Result<<T as Org>::IpfsReference> {
+        self.insert_justification(justification).await
+    }
+
+    async fn get_constitution(
+        &self,
+        reference: &<T as Org>::IpfsReference,
+    ) -> Result<ConstitutionDocument> {
+        self.get_constitution(reference).await
+    }
+
+    async fn get_justification(
+        &self,
+        reference: &<T as Org>::IpfsReference,
+    ) -> Result<JustificationDocument> {
+        self.get_justification(reference).await
+    }
+
+    async fn get_bounty_description(
+        &self,
+        reference: &<T as Org>::IpfsReference,
+    ) -> Result<BountyDocument> {
+        self.get_bounty_description(reference).await
+    }
+
+    async fn get_application(
+        &self,
+        reference: &<T as Org>::IpfsReference,
+    ) -> Result<ApplicationDocument> {
+        self.get_application(reference).await
+    }
+
+    async fn get_milestone(
+        &self,
+        reference: &<T as Org>::IpfsReference,
+    ) -> Result<MilestoneDocument> {
+        self.get_milestone(reference).await
+    }
+
+    async fn watch_events(
+        &self,
+        filter: EventFilter<T>,
+        from: Option<<T as System>::BlockNumber>,
+    ) -> Result<EventSubscription<T>> {
+        self.watch_events(filter, from).await
+    }
+
+    async fn prepare_transaction(
+        &self,
+        signer: &<T as System>::AccountId,
+        nonce: Option<<T as System>::Index>,
+        era_period: u64,
+        call: OfflineCall<T>,
+    ) -> Result<UnsignedTransaction<T>> {
+        self.prepare_transaction(signer, nonce, era_period, call).await
+    }
+
+    async fn sign_transaction(
+        &self,
+        tx: &UnsignedTransaction<T>,
+    ) -> Result<SignedTransaction<T>> {
+        self.sign_transaction(tx).await
+    }
+
+    async fn submit_transaction(
+        &self,
+        tx: SignedTransaction<T>,
+    ) -> Result<Vec<SunshineEvent<T>>> {
+        self.submit_transaction(tx).await
+    }
+
+    async fn organization(
+        &self,
+        org: <T as Org>::OrgId,
+    ) -> Result<
+        Option<
+            Organization<
+                <T as System>::AccountId,
+                <T as Org>::OrgId,
+                <T as Org>::IpfsReference,
+            >,
+        >,
+    > {
+        self.organization(org).await
+    }
+
+    async fn organizations(
+        &self,
+        page: Page,
+    ) -> Result<
+        Vec<(
+            <T as Org>::OrgId,
+            Organization<
+                <T as System>::AccountId,
+                <T as Org>::OrgId,
+                <T as Org>::IpfsReference,
+            >,
+        )>,
+    > {
+        self.organizations(page).await
+    }
+
+    async fn member(
+        &self,
+        org: <T as Org>::OrgId,
+        who: &<T as System>::AccountId,
+    ) -> Result<Option<ShareProfile<<T as Org>::Shares>>> {
+        self.member(org, who).await
+    }
+
+    async fn members(
+        &self,
+        org: <T as Org>::OrgId,
+        page: Page,
+    ) -> Result<Vec<(<T as System>::AccountId, ShareProfile<<T as Org>::Shares>)>>
+    {
+        self.members(org, page).await
+    }
+
+    async fn bank(
+        &self,
+        id: OnChainTreasuryID,
+    ) -> Result<Option<BankState<<T as System>::AccountId, <T as Org>::OrgId>>>
+    {
+        self.bank(id).await
+    }
+
+    async fn org_banks(
+        &self,
+        org: <T as Org>::OrgId,
+        page: Page,
+    ) -> Result<
+        Vec<(
+            OnChainTreasuryID,
+            BankState<<T as System>::AccountId, <T as Org>::OrgId>,
+        )>,
+    > {
+        self.org_banks(org, page).await
+    }
+
+    async fn bank_account_data(
+        &self,
+        id: OnChainTreasuryID,
+    ) -> Result<<T as System>::AccountData> {
+        self.bank_account_data(id).await
+    }
+
+    async fn spend_proposal(
+        &self,
+        bank_id: OnChainTreasuryID,
+        spend_id: <T as Bank>::SpendId,
+    ) -> Result<Option<SpendProposalOf<T>>> {
+        self.spend_proposal(bank_id, spend_id).await
+    }
+
+    async fn spend_proposals(
+        &self,
+        bank_id: OnChainTreasuryID,
+        page: Page,
+    ) -> Result<Vec<(<T as Bank>::SpendId, SpendProposalOf<T>)>> {
+        self.spend_proposals(bank_id, page).await
+    }
+
+    async fn bounty(
+        &self,
+        id: <T as Bounty>::BountyId,
+    ) -> Result<Option<BountyInformationOf<T>>> {
+        self.bounty(id).await
+    }
+
+    async fn bounties(
+        &self,
+        page: Page,
+    ) -> Result<Vec<(<T as Bounty>::BountyId, BountyInformationOf<T>)>> {
+        self.bounties(page).await
+    }
+
+    async fn application(
+        &self,
+        bounty_id: <T as Bounty>::BountyId,
+        application_id: <T as Bounty>::BountyId,
+    ) -> Result<Option<GrantApplicationOf<T>>> {
+        self.application(bounty_id, application_id).await
+    }
+
+    async fn applications(
+        &self,
+        bounty_id: <T as Bounty>::BountyId,
+        page: Page,
+    ) -> Result<Vec<(<T as Bounty>::BountyId, GrantApplicationOf<T>)>> {
+        self.applications(bounty_id, page).await
+    }
+
+    async fn milestone(
+        &self,
+        bounty_id: <T as Bounty>::BountyId,
+        milestone_id: <T as Bounty>::BountyId,
+    ) -> Result<Option<MilestoneSubmissionOf<T>>> {
+        self.milestone(bounty_id, milestone_id).await
+    }
+
+    async fn milestones(
+        &self,
+        bounty_id: <T as Bounty>::BountyId,
+        page: Page,
+    ) -> Result<Vec<(<T as Bounty>::BountyId, MilestoneSubmissionOf<T>)>> {
+        self.milestones(bounty_id, page).await
+    }
+
     async fn register_flat_org(
         &self,
         sudo: Option<<T as System>::AccountId>,
@@ -291,6 +719,17 @@ where
         self.issue_shares(organization, who, shares).await
     }
 
+    async fn issue_vesting_shares(
+        &self,
+        organization: <T as Org>::OrgId,
+        who: <T as System>::AccountId,
+        shares: <T as Org>::Shares,
+        terms: VestingTerms<<T as System>::BlockNumber>,
+    ) -> Result<VestingSharesIssuedEvent<T>> {
+        self.issue_vesting_shares(organization, who, shares, terms)
+            .await
+    }
+
     async fn burn_shares(
         &self,
         organization: <T as Org>::OrgId,
@@ -410,14 +849,56 @@ where
         seed: BalanceOf<T>,
         hosting_org: <T as Org>::OrgId,
         bank_operator: Option<<T as System>::AccountId>,
-    ) -> Result<OrgBankAccountOpenedEvent<T>> {
+    ) -> Result<BankAccountOpenedEvent<T>> {
         self.open_org_bank_account(seed, hosting_org, bank_operator)
             .await
     }
 
+    async fn member_proposes_spend(
+        &self,
+        bank_id: OnChainTreasuryID,
+        amount: BalanceOf<T>,
+        dest: <T as System>::AccountId,
+    ) -> Result<SpendProposedByMemberEvent<T>> {
+        self.member_proposes_spend(bank_id, amount, dest).await
+    }
+
+    async fn member_triggers_vote_on_spend_proposal(
+        &self,
+        bank_id: OnChainTreasuryID,
+        spend_id: <T as Bank>::SpendId,
+    ) -> Result<VoteTriggeredOnSpendProposalEvent<T>> {
+        self.member_triggers_vote_on_spend_proposal(bank_id, spend_id)
+            .await
+    }
+
+    async fn member_sudo_approves_spend_proposal(
+        &self,
+        bank_id: OnChainTreasuryID,
+        spend_id: <T as Bank>::SpendId,
+    ) -> Result<SudoApprovedSpendProposalEvent<T>> {
+        self.member_sudo_approves_spend_proposal(bank_id, spend_id)
+            .await
+    }
+
+    async fn member_polls_spend_proposal(
+        &self,
+        bank_id: OnChainTreasuryID,
+        spend_id: <T as Bank>::SpendId,
+    ) -> Result<SpendProposalPolledEvent<T>> {
+        self.member_polls_spend_proposal(bank_id, spend_id).await
+    }
+
+    async fn close_org_bank_account(
+        &self,
+        bank_id: OnChainTreasuryID,
+    ) -> Result<BankAccountClosedEvent<T>> {
+        self.close_org_bank_account(bank_id).await
+    }
+
     async fn account_posts_bounty(
         &self,
-        description: <T as Org>::IpfsReference,
+        description: &BountyDocument,
         amount_reserved_for_bounty: BalanceOf<T>,
         acceptance_committee: ResolutionMetadata<
             <T as Org>::OrgId,
@@ -431,7 +912,7 @@ where
                 <T as System>::BlockNumber,
             >,
         >,
-    ) -> Result<BountyPostedEvent<T>> {
+    ) -> Result<(<T as Org>::IpfsReference, BountyPostedEvent<T>)> {
         self.account_posts_bounty(
             description,
             amount_reserved_for_bounty,
@@ -444,9 +925,12 @@ where
     async fn account_applies_for_bounty(
         &self,
         bounty_id: <T as Bounty>::BountyId,
-        description: <T as Org>::IpfsReference,
+        description: &ApplicationDocument,
         total_amount: BalanceOf<T>,
-    ) -> Result<BountyApplicationSubmittedEvent<T>> {
+    ) -> Result<(
+        <T as Org>::IpfsReference,
+        BountyApplicationSubmittedEvent<T>,
+    )> {
         self.account_applies_for_bounty(bounty_id, description, total_amount)
             .await
     }
@@ -481,13 +965,13 @@ where
         &self,
         bounty_id: <T as Bounty>::BountyId,
         application_id: <T as Bounty>::BountyId,
-        submission_reference: <T as Org>::IpfsReference,
+        submission: &MilestoneDocument,
         amount_requested: BalanceOf<T>,
-    ) -> Result<MilestoneSubmittedEvent<T>> {
+    ) -> Result<(<T as Org>::IpfsReference, MilestoneSubmittedEvent<T>)> {
         self.submit_milestone(
             bounty_id,
             application_id,
-            submission_reference,
+            submission,
             amount_requested,
         )
         .await
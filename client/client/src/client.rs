@@ -1,8 +1,21 @@
 use crate::{
+    document::*,
     error::{
         Error,
         Result,
     },
+    events::{
+        EventFilter,
+        EventSubscription,
+        SunshineEvent,
+    },
+    offline::{
+        MortalExtra,
+        OfflineCall,
+        PayloadRecorder,
+        SignedTransaction,
+        UnsignedTransaction,
+    },
     srml::{
         bank::*,
         bounty::*,
@@ -10,42 +23,91 @@ use crate::{
         org::*,
         vote::*,
     },
+    utils::{
+        ids_up_to,
+        Page,
+    },
 };
 use async_std::sync::{
     Mutex,
     RwLock,
 };
-use codec::Decode;
+use codec::{
+    Decode,
+    Encode,
+};
 use core::marker::PhantomData;
 use ipld_block_builder::{
     BlockBuilder,
     Codec,
 };
 use keystore::{
+    bip39::{
+        Language,
+        Mnemonic,
+    },
     DeviceKey,
     KeyStore,
     Password,
 };
-use libipld::store::Store;
+use libipld::{
+    cid::Cid,
+    codec::{
+        Decode as IpldDecode,
+        Encode as IpldEncode,
+    },
+    store::Store,
+};
+use std::convert::TryFrom;
 use substrate_subxt::{
-    sp_core::crypto::{
-        Pair,
-        Ss58Codec,
+    sp_core::{
+        blake2_128,
+        crypto::{
+            Pair,
+            Ss58Codec,
+        },
+        storage::StorageKey,
+        twox_128,
+    },
+    sp_runtime::{
+        generic::Era,
+        traits::{
+            AccountIdConversion,
+            Header,
+            IdentifyAccount,
+            SignedExtension,
+            UniqueSaturatedInto,
+            Verify,
+        },
     },
-    sp_runtime::traits::{
-        IdentifyAccount,
-        SignedExtension,
-        Verify,
+    system::{
+        AccountStoreExt,
+        System,
     },
-    system::System,
+    Call,
+    EventsDecoder,
     PairSigner,
     Runtime,
     SignedExtra,
 };
 use util::{
+    bank::{
+        BankState,
+        OnChainTreasuryID,
+    },
+    bounty::BountyMapID,
     court::ResolutionMetadata,
+    organization::Organization,
+    share::{
+        ShareProfile,
+        VestingTerms,
+    },
     vote::VoterView,
 };
+use utils_identity::cid::CidBytes;
+
+/// The number of storage keys fetched per request when listing map entries
+const KEYS_PAGE_SIZE: u32 = 256;
 
 pub struct Client<T, P, I>
 where
@@ -70,9 +132,11 @@ where
         From<P::Public> + IdentifyAccount<AccountId = <T as System>::AccountId>,
     <<T::Extra as SignedExtra<T>>::Extra as SignedExtension>::AdditionalSigned:
         Send + Sync,
+    <T::Extra as SignedExtra<T>>::Extra: MortalExtra<T>,
     P: Pair,
     <P as Pair>::Public: Into<<T as System>::AccountId>,
     <P as Pair>::Seed: From<[u8; 32]>,
+    <T as Org>::IpfsReference: From<CidBytes> + Into<CidBytes>,
     I: Store,
 {
     pub fn new(
@@ -117,6 +181,578 @@ where
             *dk.expose_secret(),
         ))))
     }
+    /// Locks the keystore, signing requires unlocking it again
+    pub async fn lock(&self) -> Result<()> {
+        self.keystore.write().await.lock().await?;
+        Ok(())
+    }
+    /// Unlocks the keystore with the password it was initialized with
+    pub async fn unlock(&self, password: &Password) -> Result<()> {
+        self.keystore.write().await.unlock(password).await?;
+        Ok(())
+    }
+    /// Returns the paper key of the device key, the password is checked by
+    /// unlocking the keystore
+    pub async fn export_paperkey(
+        &self,
+        password: &Password,
+    ) -> Result<Mnemonic> {
+        self.unlock(password).await?;
+        let dk = self.keystore.read().await.device_key().await?;
+        Ok(
+            Mnemonic::from_entropy(dk.expose_secret(), Language::English)
+                .expect("a device key is 32 bytes of entropy; qed"),
+        )
+    }
+    // ipfs logic
+    async fn insert_document<D: IpldEncode<Codec>>(
+        &self,
+        document: &D,
+    ) -> Result<<T as Org>::IpfsReference> {
+        let cid = self.cache.lock().await.insert(document).await?;
+        Ok(CidBytes::from(&cid).into())
+    }
+    async fn get_document<D: IpldDecode<Codec>>(
+        &self,
+        reference: &<T as Org>::IpfsReference,
+    ) -> Result<D> {
+        let cid_bytes: CidBytes = reference.clone().into();
+        let cid = Cid::try_from(&cid_bytes)?;
+        Ok(self.cache.lock().await.get(&cid).await?)
+    }
+    /// Stores the constitution and returns its reference for org registration
+    pub async fn insert_constitution(
+        &self,
+        constitution: &ConstitutionDocument,
+    ) -> Result<<T as Org>::IpfsReference> {
+        self.insert_document(constitution).await
+    }
+    /// Stores the justification and returns its reference for `submit_vote`
+    pub async fn insert_justification(
+        &self,
+        justification: &JustificationDocument,
+    ) -> Result<<T as Org>::IpfsReference> {
+        self.insert_document(justification).await
+    }
+    pub async fn get_constitution(
+        &self,
+        reference: &<T as Org>::IpfsReference,
+    ) -> Result<ConstitutionDocument> {
+        self.get_document(reference).await
+    }
+    pub async fn get_justification(
+        &self,
+        reference: &<T as Org>::IpfsReference,
+    ) -> Result<JustificationDocument> {
+        self.get_document(reference).await
+    }
+    pub async fn get_bounty_description(
+        &self,
+        reference: &<T as Org>::IpfsReference,
+    ) -> Result<BountyDocument> {
+        self.get_document(reference).await
+    }
+    pub async fn get_application(
+        &self,
+        reference: &<T as Org>::IpfsReference,
+    ) -> Result<ApplicationDocument> {
+        self.get_document(reference).await
+    }
+    pub async fn get_milestone(
+        &self,
+        reference: &<T as Org>::IpfsReference,
+    ) -> Result<MilestoneDocument> {
+        self.get_document(reference).await
+    }
+    /// Subscribes to the sunshine events of finalized blocks, replaying the
+    /// events since `from` first
+    pub async fn watch_events(
+        &self,
+        filter: EventFilter<T>,
+        from: Option<<T as System>::BlockNumber>,
+    ) -> Result<EventSubscription<T>> {
+        EventSubscription::new(self.subxt.clone(), filter, from).await
+    }
+    // offline transactions
+    /// The mortal era of `period` blocks starting at the finalized head and
+    /// the hash of the block it starts at
+    async fn mortal_era(
+        &self,
+        period: u64,
+    ) -> Result<(Era, <T as System>::Hash)> {
+        let head = self.subxt.finalized_head().await?;
+        let header = self
+            .subxt
+            .header(Some(head))
+            .await?
+            .ok_or(Error::BlockNotFound)?;
+        let current: u64 = (*header.number()).unique_saturated_into();
+        let era = Era::mortal(period, current);
+        // long periods are quantized and start before the finalized head
+        let birth = era.birth(current);
+        let checkpoint = if birth == current {
+            head
+        } else {
+            let birth: u32 = birth.unique_saturated_into();
+            self.subxt
+                .block_hash(Some(birth.into()))
+                .await?
+                .ok_or(Error::BlockNotFound)?
+        };
+        Ok((era, checkpoint))
+    }
+    async fn prepare<C: Call<T> + Send + Sync>(
+        &self,
+        signer: &<T as System>::AccountId,
+        nonce: <T as System>::Index,
+        era_period: u64,
+        call: C,
+    ) -> Result<UnsignedTransaction<T>> {
+        let (era, checkpoint) = self.mortal_era(era_period).await?;
+        let recorder =
+            PayloadRecorder::new(signer.clone(), nonce, era, checkpoint);
+        let signed = self.subxt.create_signed(call, &recorder).await;
+        match recorder.into_transaction(*self.subxt.genesis()) {
+            Some(tx) => Ok(tx),
+            // subxt failed before asking for the signature
+            None => {
+                Err(signed
+                    .err()
+                    .expect("the recorder never signs a payload; qed")
+                    .into())
+            }
+        }
+    }
+    /// Builds a transaction for `signer` to sign on another device, the
+    /// nonce defaults to the next nonce of the account and the transaction
+    /// is valid for `era_period` blocks
+    pub async fn prepare_transaction(
+        &self,
+        signer: &<T as System>::AccountId,
+        nonce: Option<<T as System>::Index>,
+        era_period: u64,
+        call: OfflineCall<T>,
+    ) -> Result<UnsignedTransaction<T>> {
+        let nonce = match nonce {
+            Some(nonce) => nonce,
+            None => self.subxt.account(signer, None).await?.nonce,
+        };
+        match call {
+            OfflineCall::IssueShares { org, who, shares } => {
+                self.prepare(
+                    signer,
+                    nonce,
+                    era_period,
+                    IssueSharesCall {
+                        organization: org,
+                        who: &who,
+                        shares,
+                        vesting: None,
+                    },
+                )
+                .await
+            }
+            OfflineCall::BurnShares { org, who, shares } => {
+                self.prepare(
+                    signer,
+                    nonce,
+                    era_period,
+                    BurnSharesCall {
+                        organization: org,
+                        who: &who,
+                        shares,
+                    },
+                )
+                .await
+            }
+            OfflineCall::LockShares { org, who } => {
+                self.prepare(
+                    signer,
+                    nonce,
+                    era_period,
+                    LockSharesCall {
+                        organization: org,
+                        who: &who,
+                    },
+                )
+                .await
+            }
+            OfflineCall::UnlockShares { org, who } => {
+                self.prepare(
+                    signer,
+                    nonce,
+                    era_period,
+                    UnlockSharesCall {
+                        organization: org,
+                        who: &who,
+                    },
+                )
+                .await
+            }
+            OfflineCall::ProposeSpend {
+                bank_id,
+                amount,
+                dest,
+            } => {
+                self.prepare(
+                    signer,
+                    nonce,
+                    era_period,
+                    MemberProposesSpendCall {
+                        bank_id,
+                        amount,
+                        dest,
+                    },
+                )
+                .await
+            }
+            OfflineCall::SudoApproveSpend { bank_id, spend_id } => {
+                self.prepare(
+                    signer,
+                    nonce,
+                    era_period,
+                    MemberSudoApprovesSpendProposalCall { bank_id, spend_id },
+                )
+                .await
+            }
+            OfflineCall::CloseBankAccount { bank_id } => {
+                self.prepare(
+                    signer,
+                    nonce,
+                    era_period,
+                    CloseOrgBankAccountCall {
+                        bank_id,
+                        _runtime: PhantomData,
+                    },
+                )
+                .await
+            }
+            OfflineCall::SudoApproveApplication {
+                bounty_id,
+                application_id,
+            } => {
+                self.prepare(
+                    signer,
+                    nonce,
+                    era_period,
+                    AccountSudoApprovesApplicationCall {
+                        bounty_id,
+                        application_id,
+                    },
+                )
+                .await
+            }
+            OfflineCall::SudoApproveMilestone {
+                bounty_id,
+                milestone_id,
+            } => {
+                self.prepare(
+                    signer,
+                    nonce,
+                    era_period,
+                    SudoApprovesMilestoneCall {
+                        bounty_id,
+                        milestone_id,
+                    },
+                )
+                .await
+            }
+        }
+    }
+    /// Signs a prepared transaction with the device key
+    pub async fn sign_transaction(
+        &self,
+        tx: &UnsignedTransaction<T>,
+    ) -> Result<SignedTransaction<T>> {
+        let dk = self.keystore.read().await.device_key().await?;
+        tx.sign(&P::from_seed(&P::Seed::from(*dk.expose_secret())))
+    }
+    /// Submits a transaction signed on another device and returns the
+    /// sunshine events it emitted
+    pub async fn submit_transaction(
+        &self,
+        tx: SignedTransaction<T>,
+    ) -> Result<Vec<SunshineEvent<T>>> {
+        let mut decoder =
+            EventsDecoder::<T>::new(self.subxt.metadata().clone());
+        decoder.with_bounty();
+        let success = self
+            .subxt
+            .submit_and_watch_extrinsic(tx.into_extrinsic()?, decoder)
+            .await?;
+        let mut events = Vec::new();
+        for raw in &success.events {
+            if let Some(event) = SunshineEvent::decode(raw)? {
+                events.push(event);
+            }
+        }
+        Ok(events)
+    }
+    // storage queries
+    pub async fn organization(
+        &self,
+        org: <T as Org>::OrgId,
+    ) -> Result<
+        Option<
+            Organization<
+                <T as System>::AccountId,
+                <T as Org>::OrgId,
+                <T as Org>::IpfsReference,
+            >,
+        >,
+    > {
+        Ok(self
+            .subxt
+            .fetch(&OrganizationStatesStore { org }, None)
+            .await?)
+    }
+    pub async fn organizations(
+        &self,
+        page: Page,
+    ) -> Result<
+        Vec<(
+            <T as Org>::OrgId,
+            Organization<
+                <T as System>::AccountId,
+                <T as Org>::OrgId,
+                <T as Org>::IpfsReference,
+            >,
+        )>,
+    > {
+        let nonce = self
+            .subxt
+            .fetch_or_default(
+                &OrgIdNonceStore {
+                    _runtime: PhantomData,
+                },
+                None,
+            )
+            .await?;
+        page.fetch(ids_up_to(nonce), |org| self.organization(org))
+            .await
+    }
+    pub async fn member(
+        &self,
+        org: <T as Org>::OrgId,
+        who: &<T as System>::AccountId,
+    ) -> Result<Option<ShareProfile<<T as Org>::Shares>>> {
+        Ok(self.subxt.fetch(&MembersStore { org, who }, None).await?)
+    }
+    pub async fn members(
+        &self,
+        org: <T as Org>::OrgId,
+        page: Page,
+    ) -> Result<Vec<(<T as System>::AccountId, ShareProfile<<T as Org>::Shares>)>>
+    {
+        // members are keyed by (org, account), the keys are paged from the
+        // prefix of the org until the keys of the next org
+        let prefix = member_key_prefix::<T>(org);
+        let mut accounts = Vec::new();
+        let mut start_key = Some(StorageKey(prefix.clone()));
+        loop {
+            let keys = self
+                .subxt
+                .fetch_keys::<MembersStore<T>>(KEYS_PAGE_SIZE, start_key, None)
+                .await?;
+            let org_keys = keys
+                .iter()
+                .take_while(|key| key.0.starts_with(&prefix))
+                .collect::<Vec<_>>();
+            for key in org_keys.iter() {
+                let (_, who) = decode_member_key::<T>(key)?;
+                accounts.push(who);
+            }
+            if org_keys.len() < KEYS_PAGE_SIZE as usize {
+                break
+            }
+            start_key = keys.last().cloned();
+        }
+        let members = page
+            .fetch(0..accounts.len(), |i| self.member(org, &accounts[i]))
+            .await?;
+        Ok(members
+            .into_iter()
+            .map(|(i, profile)| (accounts[i].clone(), profile))
+            .collect())
+    }
+    pub async fn bank(
+        &self,
+        id: OnChainTreasuryID,
+    ) -> Result<Option<BankState<<T as System>::AccountId, <T as Org>::OrgId>>>
+    {
+        Ok(self
+            .subxt
+            .fetch(
+                &BankStoresStore {
+                    id,
+                    phantom: PhantomData,
+                },
+                None,
+            )
+            .await?)
+    }
+    pub async fn org_banks(
+        &self,
+        org: <T as Org>::OrgId,
+        page: Page,
+    ) -> Result<
+        Vec<(
+            OnChainTreasuryID,
+            BankState<<T as System>::AccountId, <T as Org>::OrgId>,
+        )>,
+    > {
+        let nonce = self
+            .subxt
+            .fetch_or_default(
+                &TreasuryIDNonceStore {
+                    _runtime: PhantomData,
+                },
+                None,
+            )
+            .await?;
+        let ids = (1..=u64::from_be_bytes(nonce.0))
+            .map(|id| OnChainTreasuryID(id.to_be_bytes()));
+        page.fetch(ids, |id| {
+            async move {
+                Ok(self.bank(id).await?.filter(|bank| bank.is_org(org)))
+            }
+        })
+        .await
+    }
+    /// The account data, including the balance, of the bank account
+    pub async fn bank_account_data(
+        &self,
+        id: OnChainTreasuryID,
+    ) -> Result<<T as System>::AccountData> {
+        let account: <T as System>::AccountId = id.into_account();
+        Ok(self.subxt.account(&account, None).await?.data)
+    }
+    pub async fn spend_proposal(
+        &self,
+        bank_id: OnChainTreasuryID,
+        spend_id: <T as Bank>::SpendId,
+    ) -> Result<Option<SpendProposalOf<T>>> {
+        Ok(self
+            .subxt
+            .fetch(&SpendProposalsStore { bank_id, spend_id }, None)
+            .await?)
+    }
+    pub async fn spend_proposals(
+        &self,
+        bank_id: OnChainTreasuryID,
+        page: Page,
+    ) -> Result<Vec<(<T as Bank>::SpendId, SpendProposalOf<T>)>> {
+        let nonce = self
+            .subxt
+            .fetch_or_default(
+                &SpendNonceMapStore {
+                    bank_id,
+                    phantom: PhantomData,
+                },
+                None,
+            )
+            .await?;
+        page.fetch(ids_up_to(nonce), |id| self.spend_proposal(bank_id, id))
+            .await
+    }
+    pub async fn bounty(
+        &self,
+        id: <T as Bounty>::BountyId,
+    ) -> Result<Option<BountyInformationOf<T>>> {
+        Ok(self.subxt.fetch(&LiveBountiesStore { id }, None).await?)
+    }
+    /// Lists the open bounties, bounties whose funding is paid out are skipped
+    pub async fn bounties(
+        &self,
+        page: Page,
+    ) -> Result<Vec<(<T as Bounty>::BountyId, BountyInformationOf<T>)>> {
+        let nonce = self
+            .subxt
+            .fetch_or_default(
+                &BountyNonceStore {
+                    _runtime: PhantomData,
+                },
+                None,
+            )
+            .await?;
+        page.fetch(ids_up_to(nonce), |id| {
+            async move {
+                Ok(self
+                    .bounty(id)
+                    .await?
+                    .filter(|bounty| !bounty.is_paid_out()))
+            }
+        })
+        .await
+    }
+    pub async fn application(
+        &self,
+        bounty_id: <T as Bounty>::BountyId,
+        application_id: <T as Bounty>::BountyId,
+    ) -> Result<Option<GrantApplicationOf<T>>> {
+        Ok(self
+            .subxt
+            .fetch(
+                &BountyApplicationsStore {
+                    bounty_id,
+                    application_id,
+                },
+                None,
+            )
+            .await?)
+    }
+    pub async fn applications(
+        &self,
+        bounty_id: <T as Bounty>::BountyId,
+        page: Page,
+    ) -> Result<Vec<(<T as Bounty>::BountyId, GrantApplicationOf<T>)>> {
+        let nonce = self
+            .subxt
+            .fetch_or_default(
+                &BountyAssociatedNoncesStore {
+                    bounty_id,
+                    map_id: BountyMapID::ApplicationId,
+                },
+                None,
+            )
+            .await?;
+        page.fetch(ids_up_to(nonce), |id| self.application(bounty_id, id))
+            .await
+    }
+    pub async fn milestone(
+        &self,
+        bounty_id: <T as Bounty>::BountyId,
+        milestone_id: <T as Bounty>::BountyId,
+    ) -> Result<Option<MilestoneSubmissionOf<T>>> {
+        Ok(self
+            .subxt
+            .fetch(
+                &MilestoneSubmissionsStore {
+                    bounty_id,
+                    milestone_id,
+                },
+                None,
+            )
+            .await?)
+    }
+    pub async fn milestones(
+        &self,
+        bounty_id: <T as Bounty>::BountyId,
+        page: Page,
+    ) -> Result<Vec<(<T as Bounty>::BountyId, MilestoneSubmissionOf<T>)>> {
+        let nonce = self
+            .subxt
+            .fetch_or_default(
+                &BountyAssociatedNoncesStore {
+                    bounty_id,
+                    map_id: BountyMapID::MilestoneId,
+                },
+                None,
+            )
+            .await?;
+        page.fetch(ids_up_to(nonce), |id| self.milestone(bounty_id, id))
+            .await
+    }
     // org logic
     pub async fn register_flat_org(
         &self,
@@ -170,12 +806,33 @@ where
     ) -> Result<SharesIssuedEvent<T>> {
         let signer = self.signer().await?;
         self.subxt
-            .issue_shares_and_watch(&signer, organization, &who, shares)
+            .issue_shares_and_watch(&signer, organization, &who, shares, None)
             .await?
             .shares_issued()
             .map_err(substrate_subxt::Error::Codec)?
             .ok_or(Error::EventNotFound)
     }
+    pub async fn issue_vesting_shares(
+        &self,
+        organization: <T as Org>::OrgId,
+        who: <T as System>::AccountId,
+        shares: <T as Org>::Shares,
+        terms: VestingTerms<<T as System>::BlockNumber>,
+    ) -> Result<VestingSharesIssuedEvent<T>> {
+        let signer = self.signer().await?;
+        self.subxt
+            .issue_shares_and_watch(
+                &signer,
+                organization,
+                &who,
+                shares,
+                Some(terms),
+            )
+            .await?
+            .vesting_shares_issued()
+            .map_err(substrate_subxt::Error::Codec)?
+            .ok_or(Error::EventNotFound)
+    }
     pub async fn burn_shares(
         &self,
         organization: <T as Org>::OrgId,
@@ -370,25 +1027,99 @@ where
         seed: BalanceOf<T>,
         hosting_org: <T as Org>::OrgId,
         bank_operator: Option<<T as System>::AccountId>,
-    ) -> Result<OrgBankAccountOpenedEvent<T>> {
+    ) -> Result<BankAccountOpenedEvent<T>> {
         let signer = self.signer().await?;
         self.subxt
             .clone()
             .open_org_bank_account_and_watch(
                 &signer,
-                seed,
                 hosting_org,
+                seed,
                 bank_operator,
             )
             .await?
-            .org_bank_account_opened()
+            .bank_account_opened()
+            .map_err(substrate_subxt::Error::Codec)?
+            .ok_or(Error::EventNotFound)
+    }
+    pub async fn member_proposes_spend(
+        &self,
+        bank_id: OnChainTreasuryID,
+        amount: BalanceOf<T>,
+        dest: <T as System>::AccountId,
+    ) -> Result<SpendProposedByMemberEvent<T>> {
+        let signer = self.signer().await?;
+        self.subxt
+            .clone()
+            .member_proposes_spend_and_watch(&signer, bank_id, amount, dest)
+            .await?
+            .spend_proposed_by_member()
+            .map_err(substrate_subxt::Error::Codec)?
+            .ok_or(Error::EventNotFound)
+    }
+    pub async fn member_triggers_vote_on_spend_proposal(
+        &self,
+        bank_id: OnChainTreasuryID,
+        spend_id: <T as Bank>::SpendId,
+    ) -> Result<VoteTriggeredOnSpendProposalEvent<T>> {
+        let signer = self.signer().await?;
+        self.subxt
+            .clone()
+            .member_triggers_vote_on_spend_proposal_and_watch(
+                &signer, bank_id, spend_id,
+            )
+            .await?
+            .vote_triggered_on_spend_proposal()
+            .map_err(substrate_subxt::Error::Codec)?
+            .ok_or(Error::EventNotFound)
+    }
+    pub async fn member_sudo_approves_spend_proposal(
+        &self,
+        bank_id: OnChainTreasuryID,
+        spend_id: <T as Bank>::SpendId,
+    ) -> Result<SudoApprovedSpendProposalEvent<T>> {
+        let signer = self.signer().await?;
+        self.subxt
+            .clone()
+            .member_sudo_approves_spend_proposal_and_watch(
+                &signer, bank_id, spend_id,
+            )
+            .await?
+            .sudo_approved_spend_proposal()
+            .map_err(substrate_subxt::Error::Codec)?
+            .ok_or(Error::EventNotFound)
+    }
+    pub async fn member_polls_spend_proposal(
+        &self,
+        bank_id: OnChainTreasuryID,
+        spend_id: <T as Bank>::SpendId,
+    ) -> Result<SpendProposalPolledEvent<T>> {
+        let signer = self.signer().await?;
+        self.subxt
+            .clone()
+            .member_polls_spend_proposal_and_watch(&signer, bank_id, spend_id)
+            .await?
+            .spend_proposal_polled()
+            .map_err(substrate_subxt::Error::Codec)?
+            .ok_or(Error::EventNotFound)
+    }
+    pub async fn close_org_bank_account(
+        &self,
+        bank_id: OnChainTreasuryID,
+    ) -> Result<BankAccountClosedEvent<T>> {
+        let signer = self.signer().await?;
+        self.subxt
+            .clone()
+            .close_org_bank_account_and_watch(&signer, bank_id)
+            .await?
+            .bank_account_closed()
             .map_err(substrate_subxt::Error::Codec)?
             .ok_or(Error::EventNotFound)
     }
     // bounty logic
     pub async fn account_posts_bounty(
         &self,
-        description: <T as Org>::IpfsReference,
+        description: &BountyDocument,
         amount_reserved_for_bounty: BalanceOf<T>,
         acceptance_committee: ResolutionMetadata<
             <T as Org>::OrgId,
@@ -402,13 +1133,15 @@ where
                 <T as System>::BlockNumber,
             >,
         >,
-    ) -> Result<BountyPostedEvent<T>> {
+    ) -> Result<(<T as Org>::IpfsReference, BountyPostedEvent<T>)> {
+        let description = self.insert_document(description).await?;
         let signer = self.signer().await?;
-        self.subxt
+        let event = self
+            .subxt
             .clone()
             .account_posts_bounty_and_watch(
                 &signer,
-                description,
+                description.clone(),
                 amount_reserved_for_bounty,
                 acceptance_committee,
                 supervision_committee,
@@ -416,27 +1149,34 @@ where
             .await?
             .bounty_posted()
             .map_err(substrate_subxt::Error::Codec)?
-            .ok_or(Error::EventNotFound)
+            .ok_or(Error::EventNotFound)?;
+        Ok((description, event))
     }
     pub async fn account_applies_for_bounty(
         &self,
         bounty_id: <T as Bounty>::BountyId,
-        description: <T as Org>::IpfsReference,
+        description: &ApplicationDocument,
         total_amount: BalanceOf<T>,
-    ) -> Result<BountyApplicationSubmittedEvent<T>> {
+    ) -> Result<(
+        <T as Org>::IpfsReference,
+        BountyApplicationSubmittedEvent<T>,
+    )> {
+        let description = self.insert_document(description).await?;
         let signer = self.signer().await?;
-        self.subxt
+        let event = self
+            .subxt
             .clone()
             .account_applies_for_bounty_and_watch(
                 &signer,
                 bounty_id,
-                description,
+                description.clone(),
                 total_amount,
             )
             .await?
             .bounty_application_submitted()
             .map_err(substrate_subxt::Error::Codec)?
-            .ok_or(Error::EventNotFound)
+            .ok_or(Error::EventNotFound)?;
+        Ok((description, event))
     }
     pub async fn account_triggers_application_review(
         &self,
@@ -492,23 +1232,26 @@ where
         &self,
         bounty_id: <T as Bounty>::BountyId,
         application_id: <T as Bounty>::BountyId,
-        submission_reference: <T as Org>::IpfsReference,
+        submission: &MilestoneDocument,
         amount_requested: BalanceOf<T>,
-    ) -> Result<MilestoneSubmittedEvent<T>> {
+    ) -> Result<(<T as Org>::IpfsReference, MilestoneSubmittedEvent<T>)> {
+        let submission_reference = self.insert_document(submission).await?;
         let signer = self.signer().await?;
-        self.subxt
+        let event = self
+            .subxt
             .clone()
             .submit_milestone_and_watch(
                 &signer,
                 bounty_id,
                 application_id,
-                submission_reference,
+                submission_reference.clone(),
                 amount_requested,
             )
             .await?
             .milestone_submitted()
             .map_err(substrate_subxt::Error::Codec)?
-            .ok_or(Error::EventNotFound)
+            .ok_or(Error::EventNotFound)?;
+        Ok((submission_reference, event))
     }
     pub async fn trigger_milestone_review(
         &self,
@@ -557,3 +1300,33 @@ where
             .ok_or(Error::EventNotFound)
     }
 }
+
+/// The prefix of the `Members` storage keys of the organization
+fn member_key_prefix<T: Org>(org: <T as Org>::OrgId) -> Vec<u8> {
+    let org = org.encode();
+    let mut prefix = Vec::with_capacity(48 + org.len());
+    prefix.extend(&twox_128(
+        <MembersStore<T> as substrate_subxt::Store<T>>::MODULE.as_bytes(),
+    ));
+    prefix.extend(&twox_128(
+        <MembersStore<T> as substrate_subxt::Store<T>>::FIELD.as_bytes(),
+    ));
+    prefix.extend(&blake2_128(&org));
+    prefix.extend(org);
+    prefix
+}
+
+/// Decodes the organization and account of a `Members` storage key,
+/// both keys are hashed with `blake2_128_concat`
+fn decode_member_key<T: Org>(
+    key: &StorageKey,
+) -> Result<(<T as Org>::OrgId, <T as System>::AccountId)> {
+    // skip the module and storage prefix and the hash of the org
+    let mut input = key.0.get(48..).unwrap_or_default();
+    let org = <T as Org>::OrgId::decode(&mut input)
+        .map_err(substrate_subxt::Error::Codec)?;
+    let mut input = input.get(16..).unwrap_or_default();
+    let who = <T as System>::AccountId::decode(&mut input)
+        .map_err(substrate_subxt::Error::Codec)?;
+    Ok((org, who))
+}
@@ -0,0 +1,31 @@
+use libipld::DagCbor;
+
+/// The description of a bounty, referenced by `account_posts_bounty`
+#[derive(Clone, Debug, Default, Eq, PartialEq, DagCbor)]
+pub struct BountyDocument {
+    pub description: String,
+}
+
+/// The body of a grant application, referenced by `account_applies_for_bounty`
+#[derive(Clone, Debug, Default, Eq, PartialEq, DagCbor)]
+pub struct ApplicationDocument {
+    pub description: String,
+}
+
+/// The body of a milestone submission, referenced by `submit_milestone`
+#[derive(Clone, Debug, Default, Eq, PartialEq, DagCbor)]
+pub struct MilestoneDocument {
+    pub submission: String,
+}
+
+/// The justification of a vote, referenced by `submit_vote`
+#[derive(Clone, Debug, Default, Eq, PartialEq, DagCbor)]
+pub struct JustificationDocument {
+    pub justification: String,
+}
+
+/// The value constitution of an organization
+#[derive(Clone, Debug, Default, Eq, PartialEq, DagCbor)]
+pub struct ConstitutionDocument {
+    pub constitution: String,
+}
@@ -4,23 +4,48 @@ use thiserror::Error;
 pub enum Error {
     #[error("{0}")]
     Subxt(#[from] substrate_subxt::Error),
-    #[cfg(feature = "light-client")]
-    #[error("{0}")]
-    Service(#[from] substrate_subxt_light_client::ServiceError),
     #[error("{0}")]
     Sled(#[from] sled::Error),
     #[error("{0}")]
     Ipfs(#[from] ipfs_embed::Error),
+    #[error("{0}")]
+    Ipld(#[from] libipld::error::Error),
+    #[error("{0}")]
+    Cid(#[from] libipld::cid::Error),
     #[error(transparent)]
     Keystore(#[from] keystore::Error),
     #[error("keystore already initialized")]
     KeystoreInitialized,
     #[error("event not found")]
     EventNotFound,
+    #[error("block not found")]
+    BlockNotFound,
+    #[error("the key is not the key of the transaction signer")]
+    WrongSigner,
     #[error("Account ID cannot be parsed from string.")]
     AccountIdParseFail,
     #[error("Number cannot be parsed from string")]
     ParseIntError,
 }
 
+impl Error {
+    /// A stable identifier of the error for scripts
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Subxt(_) => "rpc",
+            Self::Sled(_) => "db",
+            Self::Ipfs(_) => "ipfs",
+            Self::Ipld(_) => "ipld",
+            Self::Cid(_) => "cid",
+            Self::Keystore(_) => "keystore",
+            Self::KeystoreInitialized => "keystore_initialized",
+            Self::EventNotFound => "event_not_found",
+            Self::BlockNotFound => "block_not_found",
+            Self::WrongSigner => "wrong_signer",
+            Self::AccountIdParseFail => "invalid_account_id",
+            Self::ParseIntError => "invalid_number",
+        }
+    }
+}
+
 pub type Result<T> = core::result::Result<T, Error>;
@@ -0,0 +1,392 @@
+use crate::{
+    error::{
+        Error,
+        Result,
+    },
+    srml::{
+        bank::*,
+        bounty::*,
+        donate::*,
+        org::*,
+        vote::*,
+    },
+};
+use codec::{
+    Decode,
+    Input,
+};
+use std::collections::VecDeque;
+use substrate_subxt::{
+    sp_core::{
+        storage::StorageKey,
+        twox_128,
+    },
+    sp_runtime::traits::{
+        Header,
+        One,
+    },
+    system::System,
+    Event,
+    EventsDecoder,
+    RawEvent,
+    Runtime,
+    RuntimeEvent,
+};
+
+macro_rules! sunshine_events {
+    ($(
+        $variant:ident($event:ident) {
+            orgs: [$($org:ident),*],
+            bounties: [$($bounty:ident),*],
+            accounts: [$($account:ident),*],
+        },
+    )*) => {
+        /// A decoded event emitted by one of the sunshine modules
+        #[derive(Clone, Debug, Eq, PartialEq)]
+        pub enum SunshineEvent<T: Bounty> {
+            $($variant($event<T>),)*
+        }
+
+        impl<T: Bounty> SunshineEvent<T> {
+            /// Decodes a raw event, returns `None` for events of other modules
+            pub fn decode(raw: &RawEvent) -> Result<Option<Self>> {
+                $(
+                    if raw.module == <$event<T> as Event<T>>::MODULE
+                        && raw.variant == <$event<T> as Event<T>>::EVENT
+                    {
+                        let event = $event::<T>::decode(&mut &raw.data[..])
+                            .map_err(substrate_subxt::Error::Codec)?;
+                        return Ok(Some(Self::$variant(event)))
+                    }
+                )*
+                Ok(None)
+            }
+            /// The name of the module which emitted the event
+            pub fn module(&self) -> &'static str {
+                match self {
+                    $(Self::$variant(_) => <$event<T> as Event<T>>::MODULE,)*
+                }
+            }
+            /// The name of the event
+            pub fn name(&self) -> &'static str {
+                match self {
+                    $(Self::$variant(_) => <$event<T> as Event<T>>::EVENT,)*
+                }
+            }
+            /// The organizations named by the event
+            #[allow(unused_variables)]
+            pub fn orgs(&self) -> Vec<<T as Org>::OrgId> {
+                match self {
+                    $(Self::$variant(e) => vec![$(e.$org),*],)*
+                }
+            }
+            /// The bounties named by the event
+            #[allow(unused_variables)]
+            pub fn bounties(&self) -> Vec<<T as Bounty>::BountyId> {
+                match self {
+                    $(Self::$variant(e) => vec![$(e.$bounty),*],)*
+                }
+            }
+            /// The accounts named by the event
+            #[allow(unused_variables)]
+            pub fn accounts(&self) -> Vec<&<T as System>::AccountId> {
+                match self {
+                    $(Self::$variant(e) => vec![$(&e.$account),*],)*
+                }
+            }
+        }
+    };
+}
+
+sunshine_events! {
+    NewFlatOrganizationRegistered(NewFlatOrganizationRegisteredEvent) {
+        orgs: [new_id],
+        bounties: [],
+        accounts: [caller],
+    },
+    NewWeightedOrganizationRegistered(NewWeightedOrganizationRegisteredEvent) {
+        orgs: [new_id],
+        bounties: [],
+        accounts: [caller],
+    },
+    SharesIssued(SharesIssuedEvent) {
+        orgs: [organization],
+        bounties: [],
+        accounts: [who],
+    },
+    VestingSharesIssued(VestingSharesIssuedEvent) {
+        orgs: [organization],
+        bounties: [],
+        accounts: [who],
+    },
+    SharesBurned(SharesBurnedEvent) {
+        orgs: [organization],
+        bounties: [],
+        accounts: [who],
+    },
+    SharesBatchIssued(SharesBatchIssuedEvent) {
+        orgs: [organization],
+        bounties: [],
+        accounts: [],
+    },
+    SharesBatchBurned(SharesBatchBurnedEvent) {
+        orgs: [organization],
+        bounties: [],
+        accounts: [],
+    },
+    SharesLocked(SharesLockedEvent) {
+        orgs: [organization],
+        bounties: [],
+        accounts: [who],
+    },
+    SharesUnlocked(SharesUnlockedEvent) {
+        orgs: [organization],
+        bounties: [],
+        accounts: [who],
+    },
+    SharesReserved(SharesReservedEvent) {
+        orgs: [organization],
+        bounties: [],
+        accounts: [who],
+    },
+    SharesUnReserved(SharesUnReservedEvent) {
+        orgs: [organization],
+        bounties: [],
+        accounts: [who],
+    },
+    NewVoteStarted(NewVoteStartedEvent) {
+        orgs: [org],
+        bounties: [],
+        accounts: [caller],
+    },
+    Voted(VotedEvent) {
+        orgs: [],
+        bounties: [],
+        accounts: [voter],
+    },
+    DonationExecuted(DonationExecutedEvent) {
+        orgs: [org],
+        bounties: [],
+        accounts: [sender],
+    },
+    BankAccountOpened(BankAccountOpenedEvent) {
+        orgs: [hosting_org],
+        bounties: [],
+        accounts: [seeder],
+    },
+    SpendProposedByMember(SpendProposedByMemberEvent) {
+        orgs: [],
+        bounties: [],
+        accounts: [caller, dest],
+    },
+    VoteTriggeredOnSpendProposal(VoteTriggeredOnSpendProposalEvent) {
+        orgs: [],
+        bounties: [],
+        accounts: [caller],
+    },
+    SudoApprovedSpendProposal(SudoApprovedSpendProposalEvent) {
+        orgs: [],
+        bounties: [],
+        accounts: [caller],
+    },
+    SpendProposalPolled(SpendProposalPolledEvent) {
+        orgs: [],
+        bounties: [],
+        accounts: [caller],
+    },
+    BankAccountClosed(BankAccountClosedEvent) {
+        orgs: [org],
+        bounties: [],
+        accounts: [closer],
+    },
+    BountyPosted(BountyPostedEvent) {
+        orgs: [],
+        bounties: [new_bounty_id],
+        accounts: [poster],
+    },
+    BountyApplicationSubmitted(BountyApplicationSubmittedEvent) {
+        orgs: [],
+        bounties: [bounty_id],
+        accounts: [submitter],
+    },
+    ApplicationReviewTriggered(ApplicationReviewTriggeredEvent) {
+        orgs: [],
+        bounties: [bounty_id],
+        accounts: [trigger],
+    },
+    SudoApprovedApplication(SudoApprovedApplicationEvent) {
+        orgs: [],
+        bounties: [bounty_id],
+        accounts: [sudo],
+    },
+    ApplicationPolled(ApplicationPolledEvent) {
+        orgs: [],
+        bounties: [bounty_id],
+        accounts: [poller],
+    },
+    MilestoneSubmitted(MilestoneSubmittedEvent) {
+        orgs: [],
+        bounties: [bounty_id],
+        accounts: [submitter],
+    },
+    MilestoneReviewTriggered(MilestoneReviewTriggeredEvent) {
+        orgs: [],
+        bounties: [bounty_id],
+        accounts: [trigger],
+    },
+    MilestoneSudoApproved(MilestoneSudoApprovedEvent) {
+        orgs: [],
+        bounties: [bounty_id],
+        accounts: [sudo],
+    },
+    MilestonePolled(MilestonePolledEvent) {
+        orgs: [],
+        bounties: [bounty_id],
+        accounts: [poller],
+    },
+    MilestoneRejected(MilestoneRejectedEvent) {
+        orgs: [],
+        bounties: [bounty_id],
+        accounts: [grantee],
+    },
+}
+
+/// Selects the events yielded by an `EventSubscription`, every set field
+/// must be named by the event
+pub struct EventFilter<T: Bounty> {
+    pub org: Option<<T as Org>::OrgId>,
+    pub bounty: Option<<T as Bounty>::BountyId>,
+    pub account: Option<<T as System>::AccountId>,
+}
+
+impl<T: Bounty> Default for EventFilter<T> {
+    fn default() -> Self {
+        Self {
+            org: None,
+            bounty: None,
+            account: None,
+        }
+    }
+}
+
+impl<T: Bounty> EventFilter<T> {
+    pub fn matches(&self, event: &SunshineEvent<T>) -> bool {
+        self.org
+            .map(|org| event.orgs().contains(&org))
+            .unwrap_or(true)
+            && self
+                .bounty
+                .map(|bounty| event.bounties().contains(&bounty))
+                .unwrap_or(true)
+            && self
+                .account
+                .as_ref()
+                .map(|account| event.accounts().contains(&account))
+                .unwrap_or(true)
+    }
+}
+
+/// The scale encoded `Vec<EventRecord>` of a block, decoded by the
+/// `EventsDecoder` because the record types are only known at runtime
+struct EventRecords(Vec<u8>);
+
+impl Decode for EventRecords {
+    fn decode<I: Input>(
+        input: &mut I,
+    ) -> core::result::Result<Self, codec::Error> {
+        let len = input.remaining_len()?.unwrap_or_default();
+        let mut bytes = vec![0u8; len];
+        input.read(&mut bytes)?;
+        Ok(Self(bytes))
+    }
+}
+
+fn events_key() -> StorageKey {
+    let mut key = twox_128(b"System").to_vec();
+    key.extend_from_slice(&twox_128(b"Events"));
+    StorageKey(key)
+}
+
+/// Yields the sunshine events of finalized blocks with the block number
+/// they were emitted in, starting from `from` or the next finalized block
+pub struct EventSubscription<T: Runtime + Bounty> {
+    subxt: substrate_subxt::Client<T>,
+    decoder: EventsDecoder<T>,
+    heads: substrate_subxt::Subscription<<T as System>::Header>,
+    filter: EventFilter<T>,
+    finalized: Option<<T as System>::BlockNumber>,
+    next_block: Option<<T as System>::BlockNumber>,
+    pending: VecDeque<(<T as System>::BlockNumber, SunshineEvent<T>)>,
+}
+
+impl<T: Runtime + Bounty> EventSubscription<T> {
+    pub(crate) async fn new(
+        subxt: substrate_subxt::Client<T>,
+        filter: EventFilter<T>,
+        from: Option<<T as System>::BlockNumber>,
+    ) -> Result<Self> {
+        let mut decoder = EventsDecoder::<T>::new(subxt.metadata().clone());
+        decoder.with_bounty();
+        let heads = subxt.subscribe_finalized_blocks().await?;
+        Ok(Self {
+            subxt,
+            decoder,
+            heads,
+            filter,
+            finalized: None,
+            next_block: from,
+            pending: VecDeque::new(),
+        })
+    }
+    /// Returns the next matching event, `None` once the node closes the
+    /// subscription
+    pub async fn next(
+        &mut self,
+    ) -> Option<Result<(<T as System>::BlockNumber, SunshineEvent<T>)>> {
+        loop {
+            if let Some(event) = self.pending.pop_front() {
+                return Some(Ok(event))
+            }
+            let block = match (self.next_block, self.finalized) {
+                (Some(next), Some(finalized)) if next <= finalized => next,
+                _ => {
+                    let header = self.heads.next().await?;
+                    let number = *header.number();
+                    self.finalized = Some(number);
+                    self.next_block.get_or_insert(number);
+                    continue
+                }
+            };
+            self.next_block = Some(block + One::one());
+            if let Err(err) = self.fetch_block(block).await {
+                return Some(Err(err))
+            }
+        }
+    }
+    async fn fetch_block(
+        &mut self,
+        block: <T as System>::BlockNumber,
+    ) -> Result<()> {
+        let hash = self
+            .subxt
+            .block_hash(Some(block.into()))
+            .await?
+            .ok_or(Error::BlockNotFound)?;
+        let records: Option<EventRecords> =
+            self.subxt.fetch_unhashed(events_key(), Some(hash)).await?;
+        let records = match records {
+            Some(records) => records,
+            None => return Ok(()),
+        };
+        for (_, event) in self.decoder.decode_events(&mut &records.0[..])? {
+            if let RuntimeEvent::Raw(raw) = event {
+                if let Some(event) = SunshineEvent::<T>::decode(&raw)? {
+                    if self.filter.matches(&event) {
+                        self.pending.push_back((block, event));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
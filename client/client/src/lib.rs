@@ -7,21 +7,42 @@ extern crate substrate_subxt;
 
 mod r#abstract;
 mod client;
+mod document;
 mod error;
-#[cfg(feature = "light-client")]
-mod light_client;
+mod events;
+mod offline;
 mod runtime;
 mod srml;
 mod utils;
 
 pub use client::Client;
+pub use document::{
+    ApplicationDocument,
+    BountyDocument,
+    ConstitutionDocument,
+    JustificationDocument,
+    MilestoneDocument,
+};
 pub use error::Error;
-#[cfg(feature = "light-client")]
-pub use light_client::ChainType;
+pub use events::{
+    EventFilter,
+    EventSubscription,
+    SunshineEvent,
+};
+pub use offline::{
+    MortalExtra,
+    OfflineCall,
+    SignedTransaction,
+    UnsignedTransaction,
+    DEFAULT_ERA_PERIOD,
+};
 pub use r#abstract::AbstractClient;
 pub use runtime::Runtime;
 pub use srml::{
-    bank::Bank,
+    bank::{
+        Bank,
+        SpendProposalOf,
+    },
     bounty::Bounty,
     donate::Donate,
     org::Org,
@@ -32,6 +53,7 @@ pub use utils::{
     AccountShare,
     InvalidSs58,
     InvalidSuri,
+    Page,
     Ss58,
     Suri,
 };
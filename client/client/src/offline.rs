@@ -0,0 +1,353 @@
+use crate::{
+    error::{
+        Error,
+        Result,
+    },
+    srml::{
+        bank::{
+            BalanceOf,
+            Bank,
+        },
+        bounty::Bounty,
+        org::Org,
+    },
+};
+use codec::{
+    Decode,
+    Encode,
+};
+use core::{
+    fmt::Debug,
+    future::Future,
+    marker::PhantomData,
+    pin::Pin,
+};
+use std::sync::Mutex;
+use substrate_subxt::{
+    balances::Balances,
+    sp_core::{
+        blake2_256,
+        crypto::Pair,
+    },
+    sp_runtime::{
+        generic::{
+            Era,
+            SignedPayload,
+        },
+        traits::SignedExtension,
+    },
+    system::System,
+    ChargeTransactionPayment,
+    CheckEra,
+    CheckGenesis,
+    CheckNonce,
+    CheckSpecVersion,
+    CheckTxVersion,
+    CheckWeight,
+    Encoded,
+    Runtime,
+    SignedExtra,
+    Signer,
+    UncheckedExtrinsic,
+};
+use util::bank::OnChainTreasuryID;
+
+/// The calls of org supervisors that can be signed on another device
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum OfflineCall<T: Bounty> {
+    IssueShares {
+        org: <T as Org>::OrgId,
+        who: <T as System>::AccountId,
+        shares: <T as Org>::Shares,
+    },
+    BurnShares {
+        org: <T as Org>::OrgId,
+        who: <T as System>::AccountId,
+        shares: <T as Org>::Shares,
+    },
+    LockShares {
+        org: <T as Org>::OrgId,
+        who: <T as System>::AccountId,
+    },
+    UnlockShares {
+        org: <T as Org>::OrgId,
+        who: <T as System>::AccountId,
+    },
+    ProposeSpend {
+        bank_id: OnChainTreasuryID,
+        amount: BalanceOf<T>,
+        dest: <T as System>::AccountId,
+    },
+    SudoApproveSpend {
+        bank_id: OnChainTreasuryID,
+        spend_id: <T as Bank>::SpendId,
+    },
+    CloseBankAccount {
+        bank_id: OnChainTreasuryID,
+    },
+    SudoApproveApplication {
+        bounty_id: <T as Bounty>::BountyId,
+        application_id: <T as Bounty>::BountyId,
+    },
+    SudoApproveMilestone {
+        bounty_id: <T as Bounty>::BountyId,
+        milestone_id: <T as Bounty>::BountyId,
+    },
+}
+
+/// The number of blocks a prepared transaction stays valid for by default
+pub const DEFAULT_ERA_PERIOD: u64 = 64;
+
+/// A transaction built by a client connected to a node, it is signed on a
+/// device holding the key of `signer` which needs no connection.
+///
+/// `nonce`, `era`, `checkpoint` and `genesis_hash` are part of the signed
+/// `payload` and are kept to be reviewed before signing. The transaction
+/// is mortal, it is only valid for the `era` starting at the block
+/// `checkpoint`.
+#[derive(Clone, Debug, Eq, PartialEq, Encode, Decode)]
+pub struct UnsignedTransaction<T: Runtime> {
+    pub signer: <T as System>::AccountId,
+    pub nonce: <T as System>::Index,
+    pub era: Era,
+    pub checkpoint: <T as System>::Hash,
+    pub genesis_hash: <T as System>::Hash,
+    pub call: Vec<u8>,
+    pub extra: Vec<u8>,
+    pub payload: Vec<u8>,
+}
+
+impl<T: Runtime> UnsignedTransaction<T> {
+    /// Signs the payload, fails if the key is not the key of `signer`
+    pub fn sign<P: Pair>(&self, pair: &P) -> Result<SignedTransaction<T>>
+    where
+        T::Signature: From<P::Signature>,
+        <P as Pair>::Public: Into<<T as System>::AccountId>,
+    {
+        if pair.public().into() != self.signer {
+            return Err(Error::WrongSigner)
+        }
+        // payloads longer than 256 bytes are signed by their hash, like
+        // `SignedPayload::using_encoded`
+        let signature = if self.payload.len() > 256 {
+            pair.sign(&blake2_256(&self.payload))
+        } else {
+            pair.sign(&self.payload)
+        };
+        Ok(SignedTransaction {
+            signer: self.signer.clone(),
+            call: self.call.clone(),
+            extra: self.extra.clone(),
+            signature: signature.into(),
+        })
+    }
+}
+
+/// A signed transaction that can be submitted by any client
+#[derive(Clone, Debug, Eq, PartialEq, Encode, Decode)]
+pub struct SignedTransaction<T: Runtime> {
+    pub signer: <T as System>::AccountId,
+    pub call: Vec<u8>,
+    pub extra: Vec<u8>,
+    pub signature: T::Signature,
+}
+
+impl<T: Runtime> SignedTransaction<T> {
+    pub(crate) fn into_extrinsic(self) -> Result<UncheckedExtrinsic<T>>
+    where
+        <T as System>::AccountId: Into<<T as System>::Address>,
+    {
+        let extra = Decode::decode(&mut &self.extra[..])
+            .map_err(substrate_subxt::Error::Codec)?;
+        Ok(UncheckedExtrinsic::<T>::new_signed(
+            Encoded(self.call),
+            self.signer.into(),
+            self.signature,
+            extra,
+        ))
+    }
+}
+
+type Payload<T> =
+    SignedPayload<Encoded, <<T as Runtime>::Extra as SignedExtra<T>>::Extra>;
+
+/// A signed extra whose era can be replaced, the signed extra built by
+/// subxt is always immortal
+pub trait MortalExtra<T: System>: SignedExtension + Sized {
+    /// Replaces the era and the hash of the block it starts at
+    fn mortal(
+        self,
+        additional: Self::AdditionalSigned,
+        era: Era,
+        checkpoint: <T as System>::Hash,
+    ) -> (Self, Self::AdditionalSigned);
+}
+
+impl<T: System + Balances + Clone + Debug + Eq + Send + Sync> MortalExtra<T>
+    for (
+        CheckSpecVersion<T>,
+        CheckTxVersion<T>,
+        CheckGenesis<T>,
+        CheckEra<T>,
+        CheckNonce<T>,
+        CheckWeight<T>,
+        ChargeTransactionPayment<T>,
+    )
+{
+    fn mortal(
+        self,
+        additional: Self::AdditionalSigned,
+        era: Era,
+        checkpoint: <T as System>::Hash,
+    ) -> (Self, Self::AdditionalSigned) {
+        let (spec, tx, genesis, _, nonce, weight, payment) = self;
+        let (spec_version, tx_version, genesis_hash, _, n, w, p) = additional;
+        (
+            (
+                spec,
+                tx,
+                genesis,
+                CheckEra((era, PhantomData), checkpoint),
+                nonce,
+                weight,
+                payment,
+            ),
+            (spec_version, tx_version, genesis_hash, checkpoint, n, w, p),
+        )
+    }
+}
+
+/// A signer without a key which records the payload it is asked to sign,
+/// it lets subxt build the payload for an account whose key is elsewhere
+pub(crate) struct PayloadRecorder<T: Runtime> {
+    account_id: <T as System>::AccountId,
+    nonce: <T as System>::Index,
+    era: Era,
+    checkpoint: <T as System>::Hash,
+    recorded: Mutex<Option<(Vec<u8>, Vec<u8>, Vec<u8>)>>,
+}
+
+impl<T: Runtime> PayloadRecorder<T> {
+    pub(crate) fn new(
+        account_id: <T as System>::AccountId,
+        nonce: <T as System>::Index,
+        era: Era,
+        checkpoint: <T as System>::Hash,
+    ) -> Self {
+        Self {
+            account_id,
+            nonce,
+            era,
+            checkpoint,
+            recorded: Mutex::new(None),
+        }
+    }
+
+    pub(crate) fn into_transaction(
+        self,
+        genesis_hash: <T as System>::Hash,
+    ) -> Option<UnsignedTransaction<T>> {
+        let (call, extra, payload) = self.recorded.into_inner().ok()??;
+        Some(UnsignedTransaction {
+            signer: self.account_id,
+            nonce: self.nonce,
+            era: self.era,
+            checkpoint: self.checkpoint,
+            genesis_hash,
+            call,
+            extra,
+            payload,
+        })
+    }
+}
+
+impl<T: Runtime> Signer<T> for PayloadRecorder<T>
+where
+    <T::Extra as SignedExtra<T>>::Extra: MortalExtra<T>,
+{
+    fn account_id(&self) -> &<T as System>::AccountId {
+        &self.account_id
+    }
+
+    fn nonce(&self) -> Option<<T as System>::Index> {
+        Some(self.nonce)
+    }
+
+    fn sign(
+        &self,
+        payload: Payload<T>,
+    ) -> Pin<
+        Box<
+            dyn Future<
+                    Output = core::result::Result<
+                        UncheckedExtrinsic<T>,
+                        String,
+                    >,
+                > + Send
+                + Sync,
+        >,
+    > {
+        let (call, extra, additional) = payload.deconstruct();
+        let (extra, additional) =
+            extra.mortal(additional, self.era, self.checkpoint);
+        let payload = Payload::<T>::from_raw(call, extra, additional);
+        // the encoding of the payload is what gets signed, it is hashed
+        // when longer than 256 bytes
+        let signed = payload.using_encoded(|payload| payload.to_vec());
+        let (call, extra, _) = payload.deconstruct();
+        if let Ok(mut recorded) = self.recorded.lock() {
+            *recorded = Some((call.0, extra.encode(), signed));
+        }
+        Box::pin(async { Err("recorded for offline signing".to_string()) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runtime::Runtime;
+    use substrate_subxt::{
+        sp_core::sr25519,
+        sp_runtime::traits::Verify,
+    };
+
+    fn transaction(payload: Vec<u8>) -> UnsignedTransaction<Runtime> {
+        let pair = sr25519::Pair::from_seed(&[1u8; 32]);
+        UnsignedTransaction {
+            signer: pair.public().into(),
+            nonce: 0,
+            era: Era::mortal(DEFAULT_ERA_PERIOD, 100),
+            checkpoint: Default::default(),
+            genesis_hash: Default::default(),
+            call: vec![],
+            extra: vec![],
+            payload,
+        }
+    }
+
+    #[test]
+    fn long_payloads_are_signed_by_their_hash() {
+        let pair = sr25519::Pair::from_seed(&[1u8; 32]);
+        let tx = transaction(vec![7u8; 300]);
+        let signed = tx.sign(&pair).unwrap();
+        assert!(signed
+            .signature
+            .verify(&blake2_256(&tx.payload)[..], &tx.signer));
+        assert!(!signed.signature.verify(&tx.payload[..], &tx.signer));
+    }
+
+    #[test]
+    fn short_payloads_are_signed_as_is() {
+        let pair = sr25519::Pair::from_seed(&[1u8; 32]);
+        let tx = transaction(vec![7u8; 256]);
+        let signed = tx.sign(&pair).unwrap();
+        assert!(signed.signature.verify(&tx.payload[..], &tx.signer));
+    }
+
+    #[test]
+    fn sign_fails_for_other_keys() {
+        let pair = sr25519::Pair::from_seed(&[2u8; 32]);
+        let tx = transaction(vec![7u8; 32]);
+        assert!(matches!(tx.sign(&pair), Err(Error::WrongSigner)));
+    }
+}
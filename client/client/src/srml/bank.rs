@@ -32,11 +32,18 @@ use substrate_subxt::system::{
 use util::bank::{
     BankState,
     OnChainTreasuryID,
+    SpendProposal,
     SpendState,
 };
 
 pub type BalanceOf<T> = <T as Bank>::Currency; // as Currency<<T as System>::AccountId>>::Balance;
 
+pub type SpendProposalOf<T> = SpendProposal<
+    BalanceOf<T>,
+    <T as System>::AccountId,
+    SpendState<<T as Vote>::VoteId>,
+>;
+
 /// The subset of the bank trait and its inherited traits that the client must inherit
 #[module]
 pub trait Bank: System + Org + Vote + Donate {
@@ -77,26 +84,46 @@ pub struct MinimumTransferStore<T: Bank> {
     pub amount: BalanceOf<T>,
 }
 
+#[derive(Clone, Debug, Eq, PartialEq, Store, Encode)]
+pub struct TreasuryIDNonceStore<T: Bank> {
+    #[store(returns = OnChainTreasuryID)]
+    pub _runtime: std::marker::PhantomData<T>,
+}
+
 // ~~ Maps ~~
 
 #[derive(Clone, Debug, Eq, PartialEq, Store, Encode)]
 pub struct BankStoresStore<T: Bank> {
     #[store(returns = BankState<<T as System>::AccountId, <T as Org>::OrgId>)]
     pub id: OnChainTreasuryID,
-    phantom: std::marker::PhantomData<T>,
+    pub phantom: std::marker::PhantomData<T>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Store, Encode)]
+pub struct SpendNonceMapStore<T: Bank> {
+    #[store(returns = T::SpendId)]
+    pub bank_id: OnChainTreasuryID,
+    pub phantom: std::marker::PhantomData<T>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Store, Encode)]
+pub struct SpendProposalsStore<T: Bank> {
+    #[store(returns = SpendProposalOf<T>)]
+    pub bank_id: OnChainTreasuryID,
+    pub spend_id: T::SpendId,
 }
 
 // ~~ (Calls, Events) ~~
 
 #[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
 pub struct OpenOrgBankAccountCall<T: Bank> {
-    pub seed: BalanceOf<T>,
     pub hosting_org: <T as Org>::OrgId,
+    pub seed: BalanceOf<T>,
     pub bank_operator: Option<<T as System>::AccountId>,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
-pub struct OrgBankAccountOpenedEvent<T: Bank> {
+pub struct BankAccountOpenedEvent<T: Bank> {
     pub seeder: <T as System>::AccountId,
     pub new_bank_id: OnChainTreasuryID,
     pub seed: BalanceOf<T>,
@@ -104,8 +131,6 @@ pub struct OrgBankAccountOpenedEvent<T: Bank> {
     pub bank_operator: Option<<T as System>::AccountId>,
 }
 
-// -- unimplemented in client (TODO) --
-
 #[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
 pub struct MemberProposesSpendCall<T: Bank> {
     pub bank_id: OnChainTreasuryID,
@@ -164,9 +189,9 @@ pub struct SpendProposalPolledEvent<T: Bank> {
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
-pub struct CloseBankAccountCall<T: Bank> {
+pub struct CloseOrgBankAccountCall<T: Bank> {
     pub bank_id: OnChainTreasuryID,
-    p: core::marker::PhantomData<T>,
+    pub _runtime: std::marker::PhantomData<T>,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
@@ -25,7 +25,10 @@ use sp_runtime::traits::{
     Member,
     Zero,
 };
-use std::fmt::Debug;
+use std::{
+    fmt::Debug,
+    marker::PhantomData,
+};
 use substrate_subxt::system::{
     System,
     SystemEventsDecoder,
@@ -38,6 +41,7 @@ use util::{
     bounty::{
         ApplicationState,
         BountyInformation,
+        BountyMapID,
         GrantApplication,
         MilestoneStatus,
         MilestoneSubmission,
@@ -61,6 +65,33 @@ pub trait Bounty: System + Org + Vote + Bank {
         + Zero;
 }
 
+pub type BountyInformationOf<T> = BountyInformation<
+    BankOrAccount<OnChainTreasuryID, <T as System>::AccountId>,
+    <T as Org>::IpfsReference,
+    BalanceOf<T>,
+    ResolutionMetadata<
+        <T as Org>::OrgId,
+        <T as Vote>::Signal,
+        <T as System>::BlockNumber,
+    >,
+>;
+
+pub type GrantApplicationOf<T> = GrantApplication<
+    <T as System>::AccountId,
+    OnChainTreasuryID,
+    BalanceOf<T>,
+    <T as Org>::IpfsReference,
+    ApplicationState<<T as Vote>::VoteId>,
+>;
+
+pub type MilestoneSubmissionOf<T> = MilestoneSubmission<
+    <T as System>::AccountId,
+    <T as Bounty>::BountyId,
+    <T as Org>::IpfsReference,
+    BalanceOf<T>,
+    MilestoneStatus<<T as Vote>::VoteId>,
+>;
+
 // ~~ Constants ~~
 
 #[derive(Clone, Debug, Eq, PartialEq, Encode)]
@@ -68,48 +99,39 @@ pub struct BountyLowerBoundConstant<T: Bounty> {
     pub get: BalanceOf<T>,
 }
 
+// ~~ Values ~~
+
+#[derive(Clone, Debug, Eq, PartialEq, Store, Encode)]
+pub struct BountyNonceStore<T: Bounty> {
+    #[store(returns = T::BountyId)]
+    pub _runtime: PhantomData<T>,
+}
+
 // ~~ Maps ~~
 
+#[derive(Clone, Debug, Eq, PartialEq, Store, Encode)]
+pub struct BountyAssociatedNoncesStore<T: Bounty> {
+    #[store(returns = T::BountyId)]
+    pub bounty_id: T::BountyId,
+    pub map_id: BountyMapID,
+}
+
 #[derive(Clone, Debug, Eq, PartialEq, Store, Encode)]
 pub struct LiveBountiesStore<T: Bounty> {
-    #[store(returns = BountyInformation<
-        BankOrAccount<
-            OnChainTreasuryID,
-            T::AccountId
-        >,
-        T::IpfsReference,
-        BalanceOf<T>,
-        ResolutionMetadata<
-            T::OrgId,
-            T::Signal,
-            T::BlockNumber,
-        >,
-    >)]
+    #[store(returns = BountyInformationOf<T>)]
     pub id: T::BountyId,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Store, Encode)]
 pub struct BountyApplicationsStore<T: Bounty> {
-    #[store(returns = GrantApplication<
-        T::AccountId,
-        OnChainTreasuryID,
-        BalanceOf<T>,
-        T::IpfsReference,
-        ApplicationState<T::VoteId>,
-    >)]
+    #[store(returns = GrantApplicationOf<T>)]
     pub bounty_id: T::BountyId,
     pub application_id: T::BountyId,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Store, Encode)]
 pub struct MilestoneSubmissionsStore<T: Bounty> {
-    #[store(returns = MilestoneSubmission<
-        T::AccountId,
-        T::BountyId,
-        T::IpfsReference,
-        BalanceOf<T>,
-        MilestoneStatus<T::VoteId>
-    >)]
+    #[store(returns = MilestoneSubmissionOf<T>)]
     pub bounty_id: T::BountyId,
     pub milestone_id: T::BountyId,
 }
@@ -257,3 +279,11 @@ pub struct MilestonePolledEvent<T: Bounty> {
     pub milestone_id: T::BountyId,
     pub milestone_state: MilestoneStatus<T::VoteId>,
 }
+
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct MilestoneRejectedEvent<T: Bounty> {
+    pub grantee: <T as System>::AccountId,
+    pub bounty_id: T::BountyId,
+    pub milestone_id: T::BountyId,
+    pub reasons: Vec<<T as Org>::IpfsReference>,
+}
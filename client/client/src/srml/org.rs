@@ -10,14 +10,20 @@ use sp_runtime::traits::{
     Member,
     Zero,
 };
-use std::fmt::Debug;
+use std::{
+    fmt::Debug,
+    marker::PhantomData,
+};
 use substrate_subxt::system::{
     System,
     SystemEventsDecoder,
 };
 use util::{
     organization::Organization,
-    share::ShareProfile,
+    share::{
+        ShareProfile,
+        VestingTerms,
+    },
 };
 
 /// The subset of the org trait and its inherited traits that the client must inherit
@@ -56,9 +62,10 @@ pub struct SudoKeyStore<T: Org> {
     pub sudo_key: Option<<T as System>::AccountId>,
 }
 
-#[derive(Clone, Debug, Eq, PartialEq, Encode)]
-pub struct OrganizationIdNonceStore<T: Org> {
-    pub org_id_nonce: T::OrgId,
+#[derive(Clone, Debug, Eq, PartialEq, Store, Encode)]
+pub struct OrgIdNonceStore<T: Org> {
+    #[store(returns = T::OrgId)]
+    pub _runtime: PhantomData<T>,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Encode)]
@@ -74,6 +81,12 @@ pub struct OrganizationStatesStore<T: Org> {
     pub org: T::OrgId,
 }
 
+#[derive(Clone, Debug, Eq, PartialEq, Store, Encode)]
+pub struct SubOrganizationsStore<T: Org> {
+    #[store(returns = Vec<T::OrgId>)]
+    pub org: T::OrgId,
+}
+
 #[derive(Clone, Debug, Eq, PartialEq, Store, Encode)]
 pub struct TotalIssuanceStore<T: Org> {
     #[store(returns = T::Shares)]
@@ -132,6 +145,7 @@ pub struct IssueSharesCall<'a, T: Org> {
     pub organization: T::OrgId,
     pub who: &'a <T as System>::AccountId,
     pub shares: T::Shares,
+    pub vesting: Option<VestingTerms<<T as System>::BlockNumber>>,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
@@ -141,6 +155,14 @@ pub struct SharesIssuedEvent<T: Org> {
     pub shares: T::Shares,
 }
 
+#[derive(Clone, Debug, Eq, PartialEq, Event, Decode)]
+pub struct VestingSharesIssuedEvent<T: Org> {
+    pub organization: T::OrgId,
+    pub who: <T as System>::AccountId,
+    pub shares: T::Shares,
+    pub terms: VestingTerms<<T as System>::BlockNumber>,
+}
+
 #[derive(Clone, Debug, Eq, PartialEq, Call, Encode)]
 pub struct BurnSharesCall<'a, T: Org> {
     pub organization: T::OrgId,
@@ -12,9 +12,13 @@ use sp_core::crypto::{
     SecretStringError,
     Ss58Codec,
 };
-use std::str::FromStr;
+use std::{
+    future::Future,
+    str::FromStr,
+};
 use substrate_subxt::{
     sp_core,
+    sp_runtime::traits::AtLeast32Bit,
     system::System,
 };
 use thiserror::Error;
@@ -107,3 +111,57 @@ where
         }
     }
 }
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+/// A page of a listing, at most `limit` entries after skipping `offset` entries
+pub struct Page {
+    pub offset: u32,
+    pub limit: u32,
+}
+
+impl Default for Page {
+    fn default() -> Self {
+        Page {
+            offset: 0,
+            limit: 20,
+        }
+    }
+}
+
+impl Page {
+    /// Fetches the entries of the page from the ids in order, skipping ids without an entry
+    pub(crate) async fn fetch<K, V, F, Fut>(
+        self,
+        ids: impl Iterator<Item = K>,
+        fetch: F,
+    ) -> Result<Vec<(K, V)>, Error>
+    where
+        K: Copy,
+        F: Fn(K) -> Fut,
+        Fut: Future<Output = Result<Option<V>, Error>>,
+    {
+        let mut skipped = 0u32;
+        let mut entries = Vec::new();
+        for id in ids {
+            if entries.len() >= self.limit as usize {
+                break
+            }
+            if let Some(entry) = fetch(id).await? {
+                if skipped < self.offset {
+                    skipped += 1;
+                } else {
+                    entries.push((id, entry));
+                }
+            }
+        }
+        Ok(entries)
+    }
+}
+
+/// The identifiers from one up to the last generated identifier
+pub(crate) fn ids_up_to<I: AtLeast32Bit + Copy>(
+    last: I,
+) -> impl Iterator<Item = I> {
+    core::iter::successors(Some(I::one()), |id| Some(*id + I::one()))
+        .take_while(move |id| *id <= last)
+}
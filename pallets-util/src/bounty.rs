@@ -10,7 +10,10 @@ use codec::{
     Encode,
 };
 use sp_runtime::{
-    traits::Zero,
+    traits::{
+        Saturating,
+        Zero,
+    },
     RuntimeDebug,
 };
 use sp_std::prelude::*;
@@ -61,6 +64,26 @@ impl<Poster: Clone, Hash: Clone, Currency: Copy, ReviewBoard: Clone>
     }
 }
 
+impl<
+        Poster: Clone,
+        Hash: Clone,
+        Currency: Copy + Saturating + Zero,
+        ReviewBoard: Clone,
+    > BountyInformation<Poster, Hash, Currency, ReviewBoard>
+{
+    /// Deducts an executed payment from the funding reserved for the bounty
+    pub fn pay_out(&self, amount: Currency) -> Self {
+        BountyInformation {
+            funding_reserved: self.funding_reserved.saturating_sub(amount),
+            ..self.clone()
+        }
+    }
+    /// The bounty is finished once all of its funding is paid out
+    pub fn is_paid_out(&self) -> bool {
+        self.funding_reserved.is_zero()
+    }
+}
+
 #[derive(PartialEq, Eq, Copy, Clone, Encode, Decode, RuntimeDebug)]
 /// All variants hold identifiers which point to larger objects in runtime storage maps
 pub enum ApplicationState<VoteId> {
@@ -288,6 +311,8 @@ pub enum MilestoneStatus<VoteId> {
     SubmittedReviewStarted(VoteId),
     ApprovedButNotTransferred,
     ApprovedAndTransferExecuted,
+    /// Rejected by the review vote, reviewers' reasons are the vote's justifications
+    Rejected(VoteId),
 }
 
 impl<VoteId> Default for MilestoneStatus<VoteId> {
@@ -54,6 +54,7 @@ impl<
 }
 
 #[derive(new, PartialEq, Eq, Default, Clone, Encode, Decode, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
 pub struct ResolutionMetadata<OrgId, Threshold, BlockNumber> {
     org: OrgId,
     passage_threshold: Threshold,
@@ -30,6 +30,9 @@ impl<
         Hash: Clone,
     > Organization<AccountId, Id, Hash>
 {
+    pub fn sudo(&self) -> Option<AccountId> {
+        self.sudo.clone()
+    }
     pub fn parent(&self) -> Option<Id> {
         self.parent_id
     }
@@ -64,9 +67,168 @@ impl<
             constitution: self.constitution.clone(),
         }
     }
+    pub fn set_constitution(&self, new_constitution: Hash) -> Self {
+        Organization {
+            sudo: self.sudo.clone(),
+            parent_id: self.parent_id,
+            constitution: new_constitution,
+        }
+    }
+}
+
+#[derive(new, PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug)]
+/// A change of the organization's supervisor decided by a vote of its members
+pub struct SupervisorChange<AccountId, VoteId, BlockNumber> {
+    /// The elected supervisor, `None` recalls the current supervisor
+    candidate: Option<AccountId>,
+    /// The vote of the organization's members
+    vote_id: VoteId,
+    /// The change fails if the vote is still undecided after this block
+    deadline: BlockNumber,
+}
+
+impl<AccountId: Clone, VoteId: Copy, BlockNumber: Copy>
+    SupervisorChange<AccountId, VoteId, BlockNumber>
+{
+    pub fn candidate(&self) -> Option<AccountId> {
+        self.candidate.clone()
+    }
+    pub fn vote_id(&self) -> VoteId {
+        self.vote_id
+    }
+    pub fn deadline(&self) -> BlockNumber {
+        self.deadline
+    }
+}
+
+#[derive(new, PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug)]
+/// A new constitution proposed by a member and decided by a vote of the organization's members
+pub struct ConstitutionAmendment<Hash, VoteId, BlockNumber> {
+    constitution: Hash,
+    vote_id: VoteId,
+    /// The amendment fails if the vote is still undecided after this block
+    deadline: BlockNumber,
+}
+
+impl<Hash: Clone, VoteId: Copy, BlockNumber: Copy>
+    ConstitutionAmendment<Hash, VoteId, BlockNumber>
+{
+    pub fn constitution(&self) -> Hash {
+        self.constitution.clone()
+    }
+    pub fn vote_id(&self) -> VoteId {
+        self.vote_id
+    }
+    pub fn deadline(&self) -> BlockNumber {
+        self.deadline
+    }
+}
+
+#[derive(new, PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug)]
+/// A dissolution proposed by a supervisor and decided by a vote of the organization's members
+pub struct OrganizationDissolution<AccountId, VoteId, BlockNumber> {
+    proposer: AccountId,
+    /// Whether the sub-organizations are dissolved with the organization
+    recursive: bool,
+    vote_id: VoteId,
+    /// The dissolution fails if the vote is still undecided after this block
+    deadline: BlockNumber,
+}
+
+impl<AccountId: Clone, VoteId: Copy, BlockNumber: Copy>
+    OrganizationDissolution<AccountId, VoteId, BlockNumber>
+{
+    pub fn proposer(&self) -> AccountId {
+        self.proposer.clone()
+    }
+    pub fn recursive(&self) -> bool {
+        self.recursive
+    }
+    pub fn vote_id(&self) -> VoteId {
+        self.vote_id
+    }
+    pub fn deadline(&self) -> BlockNumber {
+        self.deadline
+    }
+}
+
+#[derive(new, PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug)]
+/// The term of an elected supervisor, supervisors set at registration have no term
+pub struct SupervisorTerm<AccountId, BlockNumber> {
+    supervisor: AccountId,
+    /// The number of consecutive terms served by the supervisor, including this one
+    consecutive_terms: u32,
+    /// The supervisor loses its permissions after this block
+    ends: BlockNumber,
+}
+
+impl<AccountId: Clone + PartialEq, BlockNumber: Copy>
+    SupervisorTerm<AccountId, BlockNumber>
+{
+    pub fn supervisor(&self) -> AccountId {
+        self.supervisor.clone()
+    }
+    pub fn consecutive_terms(&self) -> u32 {
+        self.consecutive_terms
+    }
+    pub fn ends(&self) -> BlockNumber {
+        self.ends
+    }
+    /// The next term for `supervisor`, counting consecutive terms of the same supervisor
+    pub fn next(&self, supervisor: AccountId, ends: BlockNumber) -> Self {
+        let consecutive_terms = if supervisor == self.supervisor {
+            self.consecutive_terms + 1
+        } else {
+            1
+        };
+        SupervisorTerm {
+            supervisor,
+            consecutive_terms,
+            ends,
+        }
+    }
+}
+
+#[derive(new, PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug)]
+/// An outsider's application to join an organization, decided by a vote of its members
+pub struct MembershipApplication<Hash, Shares, Balance, VoteId, BlockNumber> {
+    application: Hash,
+    /// The shares issued to the applicant if the application is accepted
+    shares: Shares,
+    /// Reserved from the applicant until the application is decided or withdrawn
+    deposit: Balance,
+    vote_id: VoteId,
+    /// The application fails if the vote is still undecided after this block
+    deadline: BlockNumber,
+}
+
+impl<
+        Hash: Clone,
+        Shares: Copy,
+        Balance: Copy,
+        VoteId: Copy,
+        BlockNumber: Copy,
+    > MembershipApplication<Hash, Shares, Balance, VoteId, BlockNumber>
+{
+    pub fn application(&self) -> Hash {
+        self.application.clone()
+    }
+    pub fn shares(&self) -> Shares {
+        self.shares
+    }
+    pub fn deposit(&self) -> Balance {
+        self.deposit
+    }
+    pub fn vote_id(&self) -> VoteId {
+        self.vote_id
+    }
+    pub fn deadline(&self) -> BlockNumber {
+        self.deadline
+    }
 }
 
 #[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
 /// The pieces of information used to register an organization in `org`
 pub enum OrganizationSource<AccountId, Shares> {
     /// Will be initialized as an organization with a single ShareId and equal governance strength from all members
@@ -91,6 +253,39 @@ impl<AccountId: PartialEq, Shares> Default
     }
 }
 
+#[derive(new, PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+/// An organization registered in the genesis block of `org`
+/// -> the parent must be listed before its sub-organizations
+pub struct OrganizationGenesis<AccountId, OrgId, Shares, Hash> {
+    /// If Some(account), the account is the supervisor of the organization
+    supervisor: Option<AccountId>,
+    /// If Some(org), the organization is registered as a sub-organization of org
+    parent: Option<OrgId>,
+    /// Value constitution
+    #[cfg_attr(feature = "std", serde(default))]
+    constitution: Hash,
+    /// Flat or weighted membership
+    source: OrganizationSource<AccountId, Shares>,
+}
+
+impl<AccountId: Clone, OrgId: Copy, Shares: Clone, Hash: Clone>
+    OrganizationGenesis<AccountId, OrgId, Shares, Hash>
+{
+    pub fn supervisor(&self) -> Option<AccountId> {
+        self.supervisor.clone()
+    }
+    pub fn parent(&self) -> Option<OrgId> {
+        self.parent
+    }
+    pub fn constitution(&self) -> Hash {
+        self.constitution.clone()
+    }
+    pub fn source(&self) -> OrganizationSource<AccountId, Shares> {
+        self.source.clone()
+    }
+}
+
 #[derive(new, PartialEq, Eq, Default, Clone, Encode, Decode, RuntimeDebug)]
 /// Static terms of agreement, define how the enforced payout structure for grants
 pub struct TermsOfAgreement<AccountId, Shares, Hash> {
@@ -9,11 +9,32 @@ use codec::{
 };
 use frame_support::Parameter;
 use sp_runtime::{
-    traits::Zero,
+    traits::{
+        AtLeast32BitUnsigned,
+        Zero,
+    },
+    Perbill,
     RuntimeDebug,
 };
 use sp_std::prelude::*;
 
+#[derive(PartialEq, Eq, Copy, Clone, Encode, Decode, RuntimeDebug)]
+/// The purpose shares are weighted for, common shares count towards every class
+pub enum ShareClass {
+    /// The shares held by members, weight for governance and payouts
+    Common,
+    /// Additional governance weight without payout weight
+    Voting,
+    /// Additional payout weight without governance weight or membership, i.e. for revenue sharing with contractors
+    Revenue,
+}
+
+impl Default for ShareClass {
+    fn default() -> ShareClass {
+        ShareClass::Common
+    }
+}
+
 #[derive(PartialEq, Eq, Copy, Clone, Encode, Decode, RuntimeDebug)]
 /// share profile reserves the total share amount every time but (might) have a limit on total reservations
 pub struct ShareProfile<Shares> {
@@ -117,6 +138,11 @@ impl<
     pub fn is_unlocked(&self) -> bool {
         !self.locked
     }
+
+    /// Locked or reserved shares cannot be burned by their owner or transferred
+    pub fn is_movable(&self) -> bool {
+        !self.locked && self.times_reserved == 0
+    }
 }
 
 impl<Shares: Copy + Parameter> AccessProfile<Shares> for ShareProfile<Shares> {
@@ -125,6 +151,66 @@ impl<Shares: Copy + Parameter> AccessProfile<Shares> for ShareProfile<Shares> {
     }
 }
 
+#[derive(new, PartialEq, Eq, Copy, Clone, Encode, Decode, RuntimeDebug)]
+/// The vesting requested when issuing shares, relative to the block of issuance
+pub struct VestingTerms<BlockNumber> {
+    /// Nothing vests before `cliff` blocks have passed
+    cliff: BlockNumber,
+    /// Shares vest linearly until everything is vested after `period` blocks
+    period: BlockNumber,
+}
+
+impl<BlockNumber: Copy + PartialOrd + Zero> VestingTerms<BlockNumber> {
+    pub fn cliff(&self) -> BlockNumber {
+        self.cliff
+    }
+    pub fn period(&self) -> BlockNumber {
+        self.period
+    }
+    pub fn is_valid(&self) -> bool {
+        !self.period.is_zero() && self.cliff <= self.period
+    }
+}
+
+#[derive(new, PartialEq, Eq, Copy, Clone, Encode, Decode, RuntimeDebug)]
+/// The vesting of shares issued to a member
+pub struct VestingSchedule<Shares, BlockNumber> {
+    total: Shares,
+    start: BlockNumber,
+    terms: VestingTerms<BlockNumber>,
+}
+
+impl<
+        Shares: AtLeast32BitUnsigned + Copy,
+        BlockNumber: AtLeast32BitUnsigned + Copy,
+    > VestingSchedule<Shares, BlockNumber>
+{
+    pub fn total(&self) -> Shares {
+        self.total
+    }
+    pub fn start(&self) -> BlockNumber {
+        self.start
+    }
+    pub fn terms(&self) -> VestingTerms<BlockNumber> {
+        self.terms
+    }
+    /// The shares that have not vested at `now`
+    pub fn unvested(&self, now: BlockNumber) -> Shares {
+        let elapsed = now.saturating_sub(self.start);
+        if elapsed < self.terms.cliff {
+            self.total
+        } else if elapsed >= self.terms.period {
+            Shares::zero()
+        } else {
+            let vested = Perbill::from_rational_approximation(
+                elapsed,
+                self.terms.period,
+            ) * self.total;
+            self.total - vested
+        }
+    }
+}
+
 #[derive(PartialEq, Eq, Default, Clone, Encode, Decode, RuntimeDebug)]
 /// The account ownership for the share genesis
 pub struct SimpleShareGenesis<AccountId, Shares> {
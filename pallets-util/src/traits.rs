@@ -60,7 +60,10 @@ pub trait AccessGenesis<AccountId, Shares> {
 pub trait AccessProfile<Shares> {
     fn total(&self) -> Shares;
 }
-use crate::share::SimpleShareGenesis;
+use crate::share::{
+    ShareClass,
+    SimpleShareGenesis,
+};
 pub trait ShareInformation<OrgId, AccountId, Shares> {
     type Profile: AccessProfile<Shares>;
     type Genesis: From<Vec<(AccountId, Shares)>>
@@ -77,6 +80,22 @@ pub trait ShareInformation<OrgId, AccountId, Shares> {
     /// Returns the entire membership group associated with a share identifier, fallible bc checks existence
     fn get_membership_with_shape(organization: OrgId) -> Option<Self::Genesis>;
 }
+/// Share weights by class, common shares count towards every class
+pub trait ShareClassInformation<OrgId, AccountId, Shares> {
+    /// Common shares plus the shares issued in `class`
+    fn class_issuance(organization: OrgId, class: ShareClass) -> Shares;
+    /// The common shares of `who` plus its shares in `class`
+    fn class_shares(
+        organization: OrgId,
+        class: ShareClass,
+        who: &AccountId,
+    ) -> Shares;
+    /// Every account weighted in `class`, fallible bc checks existence
+    fn class_ownership(
+        organization: OrgId,
+        class: ShareClass,
+    ) -> Option<Vec<(AccountId, Shares)>>;
+}
 pub trait ShareIssuance<OrgId, AccountId, Shares>:
     ShareInformation<OrgId, AccountId, Shares>
 {
@@ -101,6 +120,29 @@ pub trait ShareIssuance<OrgId, AccountId, Shares>:
         genesis: Self::Genesis,
     ) -> DispatchResult;
 }
+/// Lets dependent modules keep share-derived state consistent with share changes
+pub trait ShareChangeHandler<OrgId, AccountId, Shares> {
+    /// Fails if the share change is not permitted
+    fn can_change_shares(
+        organization: OrgId,
+        who: &AccountId,
+    ) -> DispatchResult;
+    fn on_shares_changed(
+        organization: OrgId,
+        who: &AccountId,
+        new_shares: Shares,
+    ) -> DispatchResult;
+}
+impl<OrgId, AccountId, Shares> ShareChangeHandler<OrgId, AccountId, Shares>
+    for ()
+{
+    fn can_change_shares(_: OrgId, _: &AccountId) -> DispatchResult {
+        Ok(())
+    }
+    fn on_shares_changed(_: OrgId, _: &AccountId, _: Shares) -> DispatchResult {
+        Ok(())
+    }
+}
 pub trait ReserveProfile<OrgId, AccountId, Shares>:
     ShareIssuance<OrgId, AccountId, Shares>
 {
@@ -145,7 +187,62 @@ pub trait RegisterOrganization<OrgId, AccountId, Hash> {
 pub trait RemoveOrganization<OrgId> {
     // returns Ok(Some(child_id)) or Ok(None) if leaf org
     fn remove_organization(id: OrgId) -> Result<Option<Vec<OrgId>>>;
-    fn recursive_remove_organization(id: OrgId) -> DispatchResult;
+    // returns all removed ids, the sub-organizations before their parents
+    fn recursive_remove_organization(id: OrgId) -> Result<Vec<OrgId>>;
+}
+/// Navigates the tree of organizations formed by sub-organizations and their parents
+pub trait OrganizationHierarchy<OrgId> {
+    fn parent_organization(org: OrgId) -> Option<OrgId>;
+    fn sub_organizations(org: OrgId) -> Vec<OrgId>;
+    /// Parent first up to the root organization
+    fn ancestors(org: OrgId) -> Vec<OrgId>;
+    /// Every organization in the sub-tree below `org` in breadth first order
+    fn descendants(org: OrgId) -> Vec<OrgId>;
+    fn is_ancestor(ancestor: OrgId, org: OrgId) -> bool;
+}
+/// Modules that keep state for organizations refuse their removal while that state is open
+pub trait OrganizationRemovalGuard<OrgId> {
+    /// Fails if the organization has open state in the module
+    fn can_remove_organization(organization: OrgId) -> DispatchResult;
+    /// Clears the module's remaining state for the removed organization
+    fn on_organization_removed(organization: OrgId);
+}
+impl<OrgId> OrganizationRemovalGuard<OrgId> for () {
+    fn can_remove_organization(_: OrgId) -> DispatchResult {
+        Ok(())
+    }
+    fn on_organization_removed(_: OrgId) {}
+}
+impl<OrgId: Copy, A, B> OrganizationRemovalGuard<OrgId> for (A, B)
+where
+    A: OrganizationRemovalGuard<OrgId>,
+    B: OrganizationRemovalGuard<OrgId>,
+{
+    fn can_remove_organization(organization: OrgId) -> DispatchResult {
+        A::can_remove_organization(organization)?;
+        B::can_remove_organization(organization)
+    }
+    fn on_organization_removed(organization: OrgId) {
+        A::on_organization_removed(organization);
+        B::on_organization_removed(organization);
+    }
+}
+impl<OrgId: Copy, A, B, C> OrganizationRemovalGuard<OrgId> for (A, B, C)
+where
+    A: OrganizationRemovalGuard<OrgId>,
+    B: OrganizationRemovalGuard<OrgId>,
+    C: OrganizationRemovalGuard<OrgId>,
+{
+    fn can_remove_organization(organization: OrgId) -> DispatchResult {
+        A::can_remove_organization(organization)?;
+        B::can_remove_organization(organization)?;
+        C::can_remove_organization(organization)
+    }
+    fn on_organization_removed(organization: OrgId) {
+        A::on_organization_removed(organization);
+        B::on_organization_removed(organization);
+        C::on_organization_removed(organization);
+    }
 }
 
 // ====== Vote Logic ======
@@ -174,6 +271,35 @@ pub trait OpenVote<OrgId, Threshold, BlockNumber, Hash> {
     ) -> Result<Self::VoteIdentifier>;
 }
 
+/// No votes are opened, i.e. for runtimes without a vote module
+impl<OrgId, Threshold, BlockNumber, Hash>
+    OpenVote<OrgId, Threshold, BlockNumber, Hash> for ()
+{
+    type VoteIdentifier = ();
+    fn open_vote(
+        _: Option<Hash>,
+        _: OrgId,
+        _: Threshold,
+        _: Option<Threshold>,
+        _: Option<BlockNumber>,
+    ) -> Result<()> {
+        Err(DispatchError::Other("no vote module to open votes"))
+    }
+    fn open_unanimous_consent(
+        _: Option<Hash>,
+        _: OrgId,
+        _: Option<BlockNumber>,
+    ) -> Result<()> {
+        Err(DispatchError::Other("no vote module to open votes"))
+    }
+}
+impl GetVoteOutcome<()> for () {
+    type Outcome = crate::vote::VoteOutcome;
+    fn get_vote_outcome(_: ()) -> Result<Self::Outcome> {
+        Err(DispatchError::Other("no vote module to poll votes"))
+    }
+}
+
 pub trait UpdateVoteTopic<VoteId, Hash> {
     fn update_vote_topic(
         vote_id: VoteId,
@@ -262,6 +388,84 @@ pub trait VoteOnProposal<AccountId, OrgId, Threshold, BlockNumber, VoteId, Hash>
     ) -> DispatchResult;
 }
 
+/// Open a secret ballot vote with a commit phase followed by a reveal phase
+pub trait OpenCommitRevealVote<OrgId, Threshold, BlockNumber, Hash>:
+    OpenVote<OrgId, Threshold, BlockNumber, Hash>
+{
+    fn open_commit_reveal_vote(
+        topic: Option<Hash>,
+        organization: OrgId,
+        passage_threshold: Threshold,
+        rejection_threshold: Option<Threshold>,
+        commit_duration: BlockNumber,
+        reveal_duration: BlockNumber,
+    ) -> Result<Self::VoteIdentifier>;
+}
+
+/// Commit `hash(voter, vote_id, direction, salt)` in the commit phase, reveal the direction and salt in the reveal phase
+pub trait CommitRevealVoteOnProposal<
+    AccountId,
+    OrgId,
+    Threshold,
+    BlockNumber,
+    VoteId,
+    Hash,
+    Commitment,
+>:
+    VoteOnProposal<AccountId, OrgId, Threshold, BlockNumber, VoteId, Hash>
+{
+    fn commit_vote(
+        vote_id: VoteId,
+        voter: AccountId,
+        commitment: Commitment,
+    ) -> DispatchResult;
+    fn reveal_vote(
+        vote_id: VoteId,
+        voter: AccountId,
+        direction: Self::Direction,
+        salt: Commitment,
+        justification: Option<Hash>,
+    ) -> DispatchResult;
+}
+
+/// Permissioned changes to open votes, closed votes keep their tally for audit
+pub trait ControlOpenVote<VoteId, BlockNumber>: GetVoteOutcome<VoteId> {
+    fn extend_vote(vote_id: VoteId, blocks: BlockNumber)
+        -> Result<BlockNumber>;
+    fn close_vote_early(vote_id: VoteId) -> Result<Self::Outcome>;
+    fn cancel_vote(vote_id: VoteId) -> DispatchResult;
+}
+impl<BlockNumber> ControlOpenVote<(), BlockNumber> for () {
+    fn extend_vote(_: (), _: BlockNumber) -> Result<BlockNumber> {
+        Err(DispatchError::Other("no vote module to control votes"))
+    }
+    fn close_vote_early(_: ()) -> Result<Self::Outcome> {
+        Err(DispatchError::Other("no vote module to control votes"))
+    }
+    fn cancel_vote(_: ()) -> DispatchResult {
+        Err(DispatchError::Other("no vote module to control votes"))
+    }
+}
+
+/// Votes against may be required to reference a justification, i.e. for reviews that must explain rejections
+/// -> the requirement is set when the vote opens so it applies to every vote cast
+pub trait JustifiedVote<OrgId, Threshold, BlockNumber, AccountId, Direction, Hash>:
+    OpenVote<OrgId, Threshold, BlockNumber, Hash>
+{
+    fn open_justified_vote(
+        topic: Option<Hash>,
+        organization: OrgId,
+        passage_threshold: Threshold,
+        rejection_threshold: Option<Threshold>,
+        duration: Option<BlockNumber>,
+    ) -> Result<Self::VoteIdentifier>;
+    fn justification_required_against(vote_id: Self::VoteIdentifier) -> bool;
+    /// All justifications submitted with votes, with the voter and the direction of the vote
+    fn justifications(
+        vote_id: Self::VoteIdentifier,
+    ) -> Vec<(AccountId, Direction, Hash)>;
+}
+
 // ====== Court Logic ======
 
 pub trait RegisterDisputeType<AccountId, Currency, VoteMetadata, BlockNumber> {
@@ -14,6 +14,7 @@ use sp_std::prelude::*;
 #[derive(
     Clone, Copy, PartialEq, Eq, Encode, Decode, sp_runtime::RuntimeDebug,
 )]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
 /// The voter options (direction)
 pub enum VoterView {
     /// Not yet voted
@@ -38,6 +39,13 @@ pub struct Vote<Signal, Hash> {
 }
 
 impl<Signal: Copy, Hash: Clone> Vote<Signal, Hash> {
+    pub fn set_magnitude(&self, new_magnitude: Signal) -> Self {
+        Vote {
+            magnitude: new_magnitude,
+            direction: self.direction,
+            justification: self.justification.clone(),
+        }
+    }
     pub fn set_new_view(
         &self,
         new_direction: VoterView,
@@ -91,10 +99,53 @@ pub struct VoteState<Signal, BlockNumber, Hash> {
     initialized: BlockNumber,
     /// The time at which this vote state expires
     expires: Option<BlockNumber>,
+    /// The time at which the commit phase ends and the reveal phase starts, only set for commit-reveal votes
+    reveal_starts: Option<BlockNumber>,
+    /// The time at which this vote state was closed early or cancelled
+    closed: Option<BlockNumber>,
     /// The vote outcome
     outcome: VoteOutcome,
 }
 
+#[derive(PartialEq, Eq, Clone, Encode, Decode, sp_runtime::RuntimeDebug)]
+/// The layout of `VoteState` before commit-reveal votes and vote controls,
+/// only decoded to migrate the votes stored in that layout
+pub struct VoteStateV1<Signal, BlockNumber, Hash> {
+    topic: Option<Hash>,
+    in_favor: Signal,
+    against: Signal,
+    turnout: Signal,
+    all_possible_turnout: Signal,
+    passage_threshold: Signal,
+    rejection_threshold: Option<Signal>,
+    initialized: BlockNumber,
+    expires: Option<BlockNumber>,
+    outcome: VoteOutcome,
+}
+
+impl<Signal, BlockNumber, Hash> From<VoteStateV1<Signal, BlockNumber, Hash>>
+    for VoteState<Signal, BlockNumber, Hash>
+{
+    fn from(
+        old: VoteStateV1<Signal, BlockNumber, Hash>,
+    ) -> VoteState<Signal, BlockNumber, Hash> {
+        VoteState {
+            topic: old.topic,
+            in_favor: old.in_favor,
+            against: old.against,
+            turnout: old.turnout,
+            all_possible_turnout: old.all_possible_turnout,
+            passage_threshold: old.passage_threshold,
+            rejection_threshold: old.rejection_threshold,
+            initialized: old.initialized,
+            expires: old.expires,
+            reveal_starts: None,
+            closed: None,
+            outcome: old.outcome,
+        }
+    }
+}
+
 impl<
         Signal: Parameter
             + Copy
@@ -117,6 +168,8 @@ impl<
             rejection_threshold: None,
             initialized: BlockNumber::default(),
             expires: None,
+            reveal_starts: None,
+            closed: None,
             outcome: VoteOutcome::default(),
         }
     }
@@ -169,6 +222,27 @@ impl<
             ..Default::default()
         }
     }
+    pub fn new_commit_reveal(
+        topic: Option<Hash>,
+        all_possible_turnout: Signal,
+        passage_threshold: Signal,
+        rejection_threshold: Option<Signal>,
+        initialized: BlockNumber,
+        reveal_starts: BlockNumber,
+        expires: BlockNumber,
+    ) -> VoteState<Signal, BlockNumber, Hash> {
+        VoteState {
+            topic,
+            all_possible_turnout,
+            passage_threshold,
+            rejection_threshold,
+            initialized,
+            expires: Some(expires),
+            reveal_starts: Some(reveal_starts),
+            outcome: VoteOutcome::Voting,
+            ..Default::default()
+        }
+    }
     pub fn topic(&self) -> Option<Hash> {
         self.topic.clone()
     }
@@ -187,6 +261,18 @@ impl<
     pub fn expires(&self) -> Option<BlockNumber> {
         self.expires
     }
+    pub fn reveal_starts(&self) -> Option<BlockNumber> {
+        self.reveal_starts
+    }
+    pub fn is_commit_reveal(&self) -> bool {
+        self.reveal_starts.is_some()
+    }
+    pub fn closed(&self) -> Option<BlockNumber> {
+        self.closed
+    }
+    pub fn is_closed(&self) -> bool {
+        self.closed.is_some()
+    }
     pub fn passage_threshold(&self) -> Signal {
         self.passage_threshold
     }
@@ -196,6 +282,51 @@ impl<
     pub fn outcome(&self) -> VoteOutcome {
         self.outcome
     }
+    /// Replaces a voter's `old` signal with `new` signal, the tally follows the voter's direction
+    /// -> a passage threshold equal to all possible turnout (unanimity) follows the new total
+    pub fn update_signal(
+        &self,
+        old: Signal,
+        new: Signal,
+        direction: VoterView,
+    ) -> Self {
+        let all_possible_turnout = self.all_possible_turnout - old + new;
+        let passage_threshold =
+            if self.passage_threshold == self.all_possible_turnout {
+                all_possible_turnout
+            } else {
+                self.passage_threshold
+            };
+        let (turnout, in_favor, against) = match direction {
+            VoterView::NoVote => (self.turnout, self.in_favor, self.against),
+            VoterView::InFavor => {
+                (
+                    self.turnout - old + new,
+                    self.in_favor - old + new,
+                    self.against,
+                )
+            }
+            VoterView::Against => {
+                (
+                    self.turnout - old + new,
+                    self.in_favor,
+                    self.against - old + new,
+                )
+            }
+            VoterView::Abstain => {
+                (self.turnout - old + new, self.in_favor, self.against)
+            }
+        };
+        VoteState {
+            in_favor,
+            against,
+            turnout,
+            all_possible_turnout,
+            passage_threshold,
+            ..self.clone()
+        }
+        .set_outcome()
+    }
     pub fn update_topic_and_clear_state(&self, new_topic: Hash) -> Self {
         VoteState {
             in_favor: 0u32.into(),
@@ -233,6 +364,62 @@ impl<
     }
 }
 
+impl<
+        Signal: Parameter
+            + Copy
+            + From<u32>
+            + Default
+            + sp_std::ops::Add<Output = Signal>
+            + sp_std::ops::Sub<Output = Signal>
+            + PartialOrd,
+        BlockNumber: Parameter + Copy + Default + sp_std::ops::Add<Output = BlockNumber>,
+        Hash: Clone,
+    > VoteState<Signal, BlockNumber, Hash>
+{
+    /// Adds `blocks` to the expiry, returns None if the vote never expires
+    pub fn extend(&self, blocks: BlockNumber) -> Option<Self> {
+        let expires = self.expires?;
+        Some(VoteState {
+            expires: Some(expires + blocks),
+            ..self.clone()
+        })
+    }
+    /// Returns true if no remaining votes can change whether the vote passes
+    pub fn outcome_is_decided(&self) -> bool {
+        self.approved()
+            || self.rejected().unwrap_or(false)
+            || !self.can_still_pass()
+    }
+    /// Closes the vote with its decided outcome, the tally is kept for audit
+    pub fn close_early(&self, now: BlockNumber) -> Option<Self> {
+        if !self.outcome_is_decided() {
+            return None
+        }
+        let outcome = if self.approved() {
+            VoteOutcome::Approved
+        } else {
+            VoteOutcome::Rejected
+        };
+        Some(VoteState {
+            closed: Some(now),
+            outcome,
+            ..self.clone()
+        })
+    }
+    /// Cancels the vote, the tally is kept for audit
+    pub fn cancel(&self, now: BlockNumber) -> Self {
+        VoteState {
+            closed: Some(now),
+            outcome: VoteOutcome::Cancelled,
+            ..self.clone()
+        }
+    }
+    // every signal that is not against may still vote in favor
+    fn can_still_pass(&self) -> bool {
+        self.all_possible_turnout - self.against >= self.passage_threshold
+    }
+}
+
 impl<
         Signal: Parameter
             + Copy
@@ -380,6 +567,7 @@ impl<
 #[derive(
     PartialEq, Eq, Copy, Clone, Encode, Decode, sp_runtime::RuntimeDebug,
 )]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 /// The vote's state and outcome
 pub enum VoteOutcome {
@@ -391,6 +579,52 @@ pub enum VoteOutcome {
     Approved,
     /// The VoteState is rejected
     Rejected,
+    /// The VoteState was cancelled before an outcome was reached
+    Cancelled,
+}
+
+#[derive(PartialEq, Eq, Clone, Encode, Decode, sp_runtime::RuntimeDebug)]
+/// Everything a voter needs to know about a vote at the current block
+pub struct VoteSummary<Signal, BlockNumber, Hash> {
+    /// The tally, thresholds and outcome
+    pub state: VoteState<Signal, BlockNumber, Hash>,
+    /// Blocks left to vote including the current block, None if the vote
+    /// never expires
+    pub remaining_blocks: Option<BlockNumber>,
+    /// The queried account's vote
+    pub vote: Option<Vote<Signal, Hash>>,
+}
+
+#[derive(
+    PartialEq, Eq, Copy, Clone, Encode, Decode, sp_runtime::RuntimeDebug,
+)]
+/// How minted signal follows share changes while an organization has open votes
+pub enum SignalPolicy {
+    /// Signal is fixed when the vote opens
+    Snapshot,
+    /// Signal is minted and burned as shares are issued and burned
+    AutoSync,
+    /// Shares cannot be issued or burned
+    ForbidShareChanges,
+}
+
+impl Default for SignalPolicy {
+    fn default() -> Self {
+        SignalPolicy::Snapshot
+    }
+}
+
+#[derive(
+    PartialEq, Eq, Copy, Clone, Encode, Decode, sp_runtime::RuntimeDebug,
+)]
+/// Changes to an open vote that require supervisor or governed approval
+pub enum VoteControl<BlockNumber> {
+    /// Add the blocks to the vote's expiry
+    Extend(BlockNumber),
+    /// Close the vote once the outcome can no longer change
+    CloseEarly,
+    /// Cancel the vote
+    Cancel,
 }
 
 impl Default for VoteOutcome {
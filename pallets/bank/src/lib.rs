@@ -59,6 +59,7 @@ use util::{
         Increment,
         OpenBankAccount,
         OpenVote,
+        OrganizationRemovalGuard,
         OrganizationSupervisorPermissions,
         SeededGenerateUniqueID,
         SpendGovernance,
@@ -144,6 +145,7 @@ decl_error! {
         CannotPollSpendProposalIfSpendProposalDNE,
         // for getting banks for org
         NoBanksForOrg,
+        CannotRemoveOrganizationWithOpenBanks,
     }
 }
 
@@ -181,6 +183,21 @@ decl_storage! {
                 >
             >;
     }
+    add_extra_genesis {
+        /// Bank accounts opened at genesis as (opener, org, deposit, controller)
+        config(banks): Vec<(T::AccountId, T::OrgId, BalanceOf<T>, Option<T::AccountId>)>;
+
+        build(|config: &GenesisConfig<T>| {
+            for (opener, org, deposit, controller) in config.banks.iter() {
+                <Module<T>>::open_bank_account(
+                    opener.clone(),
+                    *org,
+                    *deposit,
+                    controller.clone(),
+                ).expect("genesis bank config set up failed");
+            }
+        })
+    }
 }
 
 decl_module! {
@@ -574,3 +591,16 @@ impl<T: Trait> SpendGovernance<OnChainTreasuryID, BalanceOf<T>, T::AccountId>
         }
     }
 }
+
+impl<T: Trait> OrganizationRemovalGuard<T::OrgId> for Module<T> {
+    fn can_remove_organization(organization: T::OrgId) -> DispatchResult {
+        ensure!(
+            <OrgTreasuryCount<T>>::get(organization) == 0,
+            Error::<T>::CannotRemoveOrganizationWithOpenBanks
+        );
+        Ok(())
+    }
+    fn on_organization_removed(organization: T::OrgId) {
+        <OrgTreasuryCount<T>>::remove(organization);
+    }
+}
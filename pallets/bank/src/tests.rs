@@ -16,6 +16,7 @@ use sp_runtime::{
 };
 use util::{
     organization::Organization,
+    share::ShareClass,
     traits::GroupMembership,
     vote::VoterView,
 };
@@ -51,6 +52,14 @@ parameter_types! {
     pub const MaximumBlockLength: u32 = 2 * 1024;
     pub const AvailableBlockRatio: Perbill = Perbill::one();
     pub const ReservationLimit: u32 = 10000;
+    pub const MaxMembers: u32 = 10000;
+    pub const MaxOrgDepth: u32 = 2;
+    pub const OrgVoteDuration: u64 = 10;
+    pub const SignalShareClass: ShareClass = ShareClass::Voting;
+    pub const SupervisorTermLength: u64 = 100;
+    pub const MaxConsecutiveSupervisorTerms: u32 = 2;
+    pub const MembershipDeposit: u64 = 10;
+    pub const MaxMembershipApplications: u32 = 2;
 }
 impl frame_system::Trait for Test {
     type Origin = Origin;
@@ -94,21 +103,42 @@ impl org::Trait for Test {
     type OrgId = u64;
     type Shares = u64;
     type ReservationLimit = ReservationLimit;
+    type MaxMembers = MaxMembers;
+    type MaxOrgDepth = MaxOrgDepth;
+    type RemovalGuard = Bank;
+    type OrgVote = vote::Module<Test>;
+    type OrgVoteId = u64;
+    type OrgVoteDuration = OrgVoteDuration;
+    type SignalShareClass = SignalShareClass;
+    type SupervisorTermLength = SupervisorTermLength;
+    type MaxConsecutiveSupervisorTerms = MaxConsecutiveSupervisorTerms;
+    type MembershipCurrency = Balances;
+    type MembershipDeposit = MembershipDeposit;
+    type MaxMembershipApplications = MaxMembershipApplications;
+    type ShareChangeHandler = ();
+}
+parameter_types! {
+    pub const MaxClosedVoteHistory: u32 = 100;
+    pub const MaxVoteControlDuration: u64 = 20;
 }
 impl vote::Trait for Test {
     type Event = TestEvent;
     type VoteId = u64;
     type Signal = u64;
+    type MaxClosedVoteHistory = MaxClosedVoteHistory;
+    type MaxVoteControlDuration = MaxVoteControlDuration;
 }
 parameter_types! {
     pub const TransactionFee: u64 = 3;
     pub const TreasuryModuleId: ModuleId = ModuleId(*b"py/trsry");
+    pub const PayoutShareClass: ShareClass = ShareClass::Revenue;
 }
 impl donate::Trait for Test {
     type Event = TestEvent;
     type Currency = Balances;
     type TransactionFee = TransactionFee;
     type Treasury = TreasuryModuleId;
+    type PayoutShareClass = PayoutShareClass;
 }
 parameter_types! {
     pub const MaxTreasuryPerOrg: u32 = 50;
@@ -155,6 +185,7 @@ fn new_test_ext() -> sp_io::TestExternalities {
         first_organization_supervisor: 1,
         first_organization_value_constitution: 1738,
         first_organization_flat_membership: vec![1, 2, 3, 4, 5, 6],
+        organizations: vec![],
     }
     .assimilate_storage(&mut t)
     .unwrap();
@@ -179,6 +210,43 @@ fn genesis_config_works() {
     });
 }
 
+#[test]
+fn bank_genesis_config_works() {
+    let mut t = frame_system::GenesisConfig::default()
+        .build_storage::<Test>()
+        .unwrap();
+    pallet_balances::GenesisConfig::<Test> {
+        balances: vec![(1, 100), (2, 98)],
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+    org::GenesisConfig::<Test> {
+        first_organization_supervisor: 1,
+        first_organization_value_constitution: 1738,
+        first_organization_flat_membership: vec![1, 2],
+        organizations: vec![],
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+    GenesisConfig::<Test> {
+        banks: vec![(1, 1, 20, None), (2, 1, 30, Some(2))],
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+    let mut ext: sp_io::TestExternalities = t.into();
+    ext.execute_with(|| {
+        assert_eq!(Bank::total_bank_count(), 2);
+        assert_eq!(Bank::org_treasury_count(1), 2);
+        let second_bank = OnChainTreasuryID([0, 0, 0, 0, 0, 0, 0, 2]);
+        assert_eq!(
+            Bank::bank_stores(second_bank).unwrap(),
+            BankState::new(1, Some(2))
+        );
+        assert_eq!(Balances::total_balance(&Bank::account_id(second_bank)), 30);
+        assert_eq!(Balances::total_balance(&2), 68);
+    });
+}
+
 #[test]
 fn opening_bank_account_works() {
     new_test_ext().execute_with(|| {
@@ -258,3 +326,40 @@ fn spend_governance_works() {
         assert_eq!(Balances::total_balance(&4), 80);
     });
 }
+
+#[test]
+fn open_banks_block_organization_removal() {
+    new_test_ext().execute_with(|| {
+        let one = Origin::signed(1);
+        assert_ok!(Org::register_flat_org(
+            one.clone(),
+            Some(1),
+            Some(1),
+            1738,
+            vec![1, 2, 3]
+        ));
+        assert_ok!(Bank::open_org_bank_account(one.clone(), 2, 31, None));
+        assert_eq!(Bank::org_treasury_count(2), 1);
+        // the members approve the dissolution but the open bank blocks it
+        assert_ok!(Org::dissolve_organization(one.clone(), 2, false));
+        for i in 1u64..3u64 {
+            assert_ok!(Vote::submit_vote(
+                Origin::signed(i),
+                1,
+                VoterView::InFavor,
+                None
+            ));
+        }
+        assert_eq!(
+            Org::poll_dissolution(one.clone(), 2),
+            Err(Error::<Test>::CannotRemoveOrganizationWithOpenBanks.into())
+        );
+        assert_ok!(Bank::close_org_bank_account(
+            one.clone(),
+            OnChainTreasuryID([0, 0, 0, 0, 0, 0, 0, 1])
+        ));
+        assert_eq!(Bank::org_treasury_count(2), 0);
+        assert_ok!(Org::poll_dissolution(one, 2));
+        assert!(Org::organization_states(2).is_none());
+    });
+}
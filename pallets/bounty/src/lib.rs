@@ -14,19 +14,28 @@
 #[cfg(test)]
 mod tests;
 
-use codec::Codec;
+use codec::{
+    Codec,
+    Decode,
+    Encode,
+};
 use frame_support::{
     decl_error,
     decl_event,
     decl_module,
     decl_storage,
     ensure,
+    storage::{
+        IterableStorageMap,
+        StorageDoubleMap,
+    },
     traits::{
         Currency,
         ExistenceRequirement,
         Get,
         ReservableCurrency,
     },
+    weights::Weight,
     Parameter,
 };
 use frame_system::{
@@ -42,6 +51,7 @@ use sp_runtime::{
     },
     DispatchError,
     DispatchResult,
+    RuntimeDebug,
 };
 use sp_std::{
     fmt::Debug,
@@ -68,7 +78,9 @@ use util::{
         GetVoteOutcome,
         GroupMembership,
         IDIsAvailable,
+        JustifiedVote,
         OpenVote,
+        OrganizationRemovalGuard,
         OrganizationSupervisorPermissions,
         PostBounty,
         ReturnsBountyIdentifier,
@@ -78,7 +90,10 @@ use util::{
         SubmitMilestone,
         SuperviseGrantApplication,
     },
-    vote::VoteOutcome,
+    vote::{
+        VoteOutcome,
+        VoterView,
+    },
 };
 
 /// The balances type for this module is inherited from bank
@@ -113,6 +128,7 @@ decl_event!(
     pub enum Event<T>
     where
         <T as frame_system::Trait>::AccountId,
+        <T as org::Trait>::IpfsReference,
         <T as vote::Trait>::VoteId,
         <T as Trait>::BountyId,
         Balance = BalanceOf<T>,
@@ -126,6 +142,10 @@ decl_event!(
         MilestoneReviewTriggered(AccountId, BountyId, BountyId, MilestoneStatus<VoteId>),
         SudoApprovedMilestone(AccountId, BountyId, BountyId, MilestoneStatus<VoteId>),
         MilestonePolled(AccountId, BountyId, BountyId, MilestoneStatus<VoteId>),
+        /// Grantee, BountyId, MilestoneId, justifications of the votes against the milestone
+        MilestoneRejected(AccountId, BountyId, BountyId, Vec<IpfsReference>),
+        /// BountyId, closer, funding refunded to the poster
+        BountyClosed(BountyId, AccountId, Balance),
     }
 );
 
@@ -165,11 +185,33 @@ decl_error! {
         CannotPollMilestoneThatDNE,
         CannotPollMilestoneIfBaseAppDNE,
         CannotPollMilestoneSubmissionIfBaseBountyDNE,
+        CannotRemoveOrganizationThatReviewsLiveBounties,
+        CannotCloseBountyThatDNE,
+        NotAuthorizedToCloseBounty,
+        CannotCloseBountyWithApprovedApplication,
+    }
+}
+
+/// Storage layout versions of this module, used for runtime upgrade migrations
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub enum Releases {
+    /// `OrgBountyCount` counts every bounty ever posted
+    V1,
+    /// `OrgBountyCount` counts the bounties which are not paid out
+    V2,
+}
+
+impl Default for Releases {
+    fn default() -> Self {
+        Releases::V1
     }
 }
 
 decl_storage! {
     trait Store for Module<T: Trait> as Bounty {
+        /// The storage layout version, for runtime upgrade migrations
+        StorageVersion build(|_| Releases::V2): Releases;
+
         /// Uid generation helper for main BountyId
         BountyNonce get(fn bounty_nonce): T::BountyId;
 
@@ -196,6 +238,10 @@ decl_storage! {
                 >
             >;
 
+        /// The number of bounties not yet paid out reviewed by each organization's acceptance or supervision committee
+        pub OrgBountyCount get(fn org_bounty_count): map
+            hasher(blake2_128_concat) T::OrgId => u32;
+
         /// All bounty applications
         pub BountyApplications get(fn bounty_applications): double_map
             hasher(opaque_blake2_256) T::BountyId,
@@ -222,6 +268,29 @@ decl_storage! {
                 >
             >;
     }
+    add_extra_genesis {
+        /// Bounties posted at genesis from the poster's free balance
+        config(bounties): Vec<(
+            T::AccountId,
+            T::IpfsReference,
+            BalanceOf<T>,
+            ResolutionMetadata<T::OrgId, T::Signal, T::BlockNumber>,
+            Option<ResolutionMetadata<T::OrgId, T::Signal, T::BlockNumber>>,
+        )>;
+
+        build(|config: &GenesisConfig<T>| {
+            for (poster, description, amount, acceptance, supervision) in config.bounties.iter() {
+                <Module<T>>::post_bounty(
+                    poster.clone(),
+                    None,
+                    description.clone(),
+                    *amount,
+                    acceptance.clone(),
+                    supervision.clone(),
+                ).expect("genesis bounty config set up failed");
+            }
+        })
+    }
 }
 
 decl_module! {
@@ -229,6 +298,10 @@ decl_module! {
         type Error = Error<T>;
         fn deposit_event() = default;
 
+        fn on_runtime_upgrade() -> Weight {
+            Self::migrate_org_bounty_count()
+        }
+
         #[weight = 0]
         fn account_posts_bounty(
             origin,
@@ -420,6 +493,17 @@ decl_module! {
             Self::deposit_event(RawEvent::MilestonePolled(poller, bounty_id, milestone_id, milestone_status));
             Ok(())
         }
+        /// Closes a bounty without an approved application and refunds its remaining funding to the poster
+        #[weight = 0]
+        fn account_closes_bounty(
+            origin,
+            bounty_id: T::BountyId,
+        ) -> DispatchResult {
+            let closer = ensure_signed(origin)?;
+            let refund = Self::close_bounty(&closer, bounty_id)?;
+            Self::deposit_event(RawEvent::BountyClosed(bounty_id, closer, refund));
+            Ok(())
+        }
     }
 }
 
@@ -427,7 +511,155 @@ impl<T: Trait> Module<T> {
     pub fn is_bounty(id: T::BountyId) -> bool {
         !Self::id_is_available(BIdWrapper::new(id))
     }
+    /// The justifications of the votes against the milestone if its review rejected it
+    pub fn milestone_rejection_reasons(
+        bounty_id: T::BountyId,
+        milestone_id: T::BountyId,
+    ) -> Vec<T::IpfsReference> {
+        match <MilestoneSubmissions<T>>::get(bounty_id, milestone_id)
+            .map(|m| m.state())
+        {
+            Some(MilestoneStatus::Rejected(vote_id)) => {
+                Self::rejection_reasons(vote_id)
+            }
+            _ => Vec::new(),
+        }
+    }
+    fn rejection_reasons(vote_id: T::VoteId) -> Vec<T::IpfsReference> {
+        <vote::Module<T>>::justifications(vote_id)
+            .into_iter()
+            .filter(|(_, direction, _)| *direction == VoterView::Against)
+            .map(|(_, _, reason)| reason)
+            .collect()
+    }
 
+    /// The organizations of the acceptance and supervision committees of the bounty
+    fn review_orgs(
+        bounty: &BountyInformation<
+            BankOrAccount<OnChainTreasuryID, T::AccountId>,
+            T::IpfsReference,
+            BalanceOf<T>,
+            ResolutionMetadata<T::OrgId, T::Signal, T::BlockNumber>,
+        >,
+    ) -> Vec<T::OrgId> {
+        let acceptance_org = bounty.acceptance_committee().org();
+        let mut orgs = vec![acceptance_org];
+        if let Some(supervision_org) = bounty
+            .supervision_committee()
+            .map(|committee| committee.org())
+            .filter(|org| *org != acceptance_org)
+        {
+            orgs.push(supervision_org);
+        }
+        orgs
+    }
+    /// Deducts an executed milestone payment from the bounty's funding, the
+    /// committees stop reviewing the bounty once its funding is paid out
+    fn record_milestone_payment(bounty_id: T::BountyId, amount: BalanceOf<T>) {
+        if let Some(bounty) = <LiveBounties<T>>::get(bounty_id) {
+            if bounty.is_paid_out() {
+                return
+            }
+            let bounty = bounty.pay_out(amount);
+            if bounty.is_paid_out() {
+                Self::review_orgs(&bounty).into_iter().for_each(|org| {
+                    <OrgBountyCount<T>>::mutate(org, |count| {
+                        *count = count.saturating_sub(1)
+                    })
+                });
+            }
+            <LiveBounties<T>>::insert(bounty_id, bounty);
+        }
+    }
+    /// The poster may close the bounty, or for bounties posted from a bank
+    /// -> the bank controller or the supervisor of the bank's organization
+    fn can_close_bounty(
+        poster: &BankOrAccount<OnChainTreasuryID, T::AccountId>,
+        who: &T::AccountId,
+    ) -> bool {
+        match poster {
+            BankOrAccount::Account(account) => account == who,
+            BankOrAccount::Bank(bank_id) => {
+                <bank::Module<T>>::bank_stores(*bank_id).map_or(false, |bank| {
+                    bank.is_controller(who)
+                        || <org::Module<T>>::is_organization_supervisor(
+                            bank.org(),
+                            who,
+                        )
+                })
+            }
+        }
+    }
+    /// Removes the bounty with its applications and milestones, unreserves
+    /// -> the funding not yet paid out and returns the refunded amount
+    fn close_bounty(
+        closer: &T::AccountId,
+        bounty_id: T::BountyId,
+    ) -> Result<BalanceOf<T>, DispatchError> {
+        let bounty = <LiveBounties<T>>::get(bounty_id)
+            .ok_or(Error::<T>::CannotCloseBountyThatDNE)?;
+        ensure!(
+            Self::can_close_bounty(&bounty.poster(), closer),
+            Error::<T>::NotAuthorizedToCloseBounty
+        );
+        // grantees working on the bounty keep their funding
+        ensure!(
+            bounty.is_paid_out()
+                || <BountyApplications<T>>::iter_prefix_values(bounty_id)
+                    .all(|app| !app.state().approved_and_live()),
+            Error::<T>::CannotCloseBountyWithApprovedApplication
+        );
+        let refund = bounty.funding_reserved();
+        if !bounty.is_paid_out() {
+            let poster_account = match bounty.poster() {
+                BankOrAccount::Bank(bank_id) => {
+                    <bank::Module<T>>::account_id(bank_id)
+                }
+                BankOrAccount::Account(account) => account,
+            };
+            <T as bank::Trait>::Currency::unreserve(&poster_account, refund);
+            Self::review_orgs(&bounty).into_iter().for_each(|org| {
+                <OrgBountyCount<T>>::mutate(org, |count| {
+                    *count = count.saturating_sub(1)
+                })
+            });
+        }
+        <LiveBounties<T>>::remove(bounty_id);
+        <BountyApplications<T>>::remove_prefix(bounty_id);
+        <MilestoneSubmissions<T>>::remove_prefix(bounty_id);
+        <BountyAssociatedNonces<T>>::remove_prefix(bounty_id);
+        Ok(refund)
+    }
+    /// Recounts `OrgBountyCount` from the bounties not yet paid out, chains
+    /// upgraded from `Releases::V1` counted every bounty ever posted
+    pub fn migrate_org_bounty_count() -> Weight {
+        if <StorageVersion>::get() != Releases::V1 {
+            return 0
+        }
+        let mut reads: Weight = 2;
+        let mut writes: Weight = 1;
+        let stale = <OrgBountyCount<T>>::iter()
+            .map(|(org, _)| org)
+            .collect::<Vec<_>>();
+        reads += stale.len() as Weight;
+        writes += stale.len() as Weight;
+        stale.into_iter().for_each(<OrgBountyCount<T>>::remove);
+        let mut bounty_id: T::BountyId = 1u32.into();
+        while bounty_id <= <BountyNonce<T>>::get() {
+            reads += 1;
+            if let Some(bounty) = <LiveBounties<T>>::get(bounty_id)
+                .filter(|bounty| !bounty.is_paid_out())
+            {
+                Self::review_orgs(&bounty).into_iter().for_each(|org| {
+                    writes += 1;
+                    <OrgBountyCount<T>>::mutate(org, |count| *count += 1)
+                });
+            }
+            bounty_id += 1u32.into();
+        }
+        <StorageVersion>::put(Releases::V2);
+        T::DbWeight::get().reads_writes(reads, writes)
+    }
     pub fn transfer_milestone_payment(
         sender: BankOrAccount<OnChainTreasuryID, T::AccountId>,
         recipient: BankOrAccount<OnChainTreasuryID, T::AccountId>,
@@ -613,6 +845,12 @@ impl<T: Trait>
             acceptance_committee,
             supervision_committee,
         );
+        // index the committees' organizations
+        Self::review_orgs(&new_bounty_post)
+            .into_iter()
+            .for_each(|org| {
+                <OrgBountyCount<T>>::mutate(org, |count| *count += 1)
+            });
         // generate unique bounty identifier
         let new_bounty_id = Self::generate_unique_id();
         // insert new bounty
@@ -900,7 +1138,8 @@ impl<T: Trait>
                 Error::<T>::CannotTriggerMilestoneReviewIfSubmissionDNE,
             )?;
         // dispatch vote by acceptance committee
-        let new_vote_id = <vote::Module<T>>::open_vote(
+        // -> reviewers must explain why they reject a milestone
+        let new_vote_id = <vote::Module<T>>::open_justified_vote(
             Some(milestone_submission.submission()),
             review_board.org(),
             review_board.passage_threshold(),
@@ -962,6 +1201,10 @@ impl<T: Trait>
             milestone_submission.amount(),
         );
         let new_milestone_submission = if let Ok(()) = payment_receipt {
+            Self::record_milestone_payment(
+                bounty_id,
+                milestone_submission.amount(),
+            );
             milestone_submission
                 .set_state(MilestoneStatus::ApprovedAndTransferExecuted)
         } else {
@@ -1016,6 +1259,10 @@ impl<T: Trait>
                     );
                     let new_milestone_submission =
                         if let Ok(()) = payment_receipt {
+                            Self::record_milestone_payment(
+                                bounty_id,
+                                milestone_submission.amount(),
+                            );
                             milestone_submission.set_state(
                                 MilestoneStatus::ApprovedAndTransferExecuted,
                             )
@@ -1030,8 +1277,24 @@ impl<T: Trait>
                         new_milestone_submission,
                     );
                     Ok(ret_state)
+                } else if vote_outcome == VoteOutcome::Rejected {
+                    let new_milestone_submission = milestone_submission
+                        .set_state(MilestoneStatus::Rejected(live_vote_id));
+                    let ret_state = new_milestone_submission.state();
+                    <MilestoneSubmissions<T>>::insert(
+                        bounty_id,
+                        milestone_id,
+                        new_milestone_submission,
+                    );
+                    Self::deposit_event(RawEvent::MilestoneRejected(
+                        grant_app.submitter(),
+                        bounty_id,
+                        milestone_id,
+                        Self::rejection_reasons(live_vote_id),
+                    ));
+                    Ok(ret_state)
                 } else {
-                    // TODO: change this `if else` to a `match` statement and remove the milestone submission if the vote rejects it? Or need to build a path for updating the milestone and triggering a new vote based on new submission
+                    // TODO: build a path for updating a rejected milestone and triggering a new vote based on new submission
                     Ok(milestone_submission.state())
                 }
             }
@@ -1040,3 +1303,16 @@ impl<T: Trait>
         }
     }
 }
+
+impl<T: Trait> OrganizationRemovalGuard<T::OrgId> for Module<T> {
+    fn can_remove_organization(organization: T::OrgId) -> DispatchResult {
+        ensure!(
+            <OrgBountyCount<T>>::get(organization) == 0,
+            Error::<T>::CannotRemoveOrganizationThatReviewsLiveBounties
+        );
+        Ok(())
+    }
+    fn on_organization_removed(organization: T::OrgId) {
+        <OrgBountyCount<T>>::remove(organization);
+    }
+}
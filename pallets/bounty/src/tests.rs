@@ -47,6 +47,14 @@ parameter_types! {
     pub const MaximumBlockLength: u32 = 2 * 1024;
     pub const AvailableBlockRatio: Perbill = Perbill::one();
     pub const ReservationLimit: u32 = 10000;
+    pub const MaxMembers: u32 = 10000;
+    pub const MaxOrgDepth: u32 = 2;
+    pub const OrgVoteDuration: u64 = 10;
+    pub const SignalShareClass: ShareClass = ShareClass::Voting;
+    pub const SupervisorTermLength: u64 = 100;
+    pub const MaxConsecutiveSupervisorTerms: u32 = 2;
+    pub const MembershipDeposit: u64 = 10;
+    pub const MaxMembershipApplications: u32 = 2;
 }
 impl frame_system::Trait for Test {
     type Origin = Origin;
@@ -90,21 +98,42 @@ impl org::Trait for Test {
     type OrgId = u64;
     type Shares = u64;
     type ReservationLimit = ReservationLimit;
+    type MaxMembers = MaxMembers;
+    type MaxOrgDepth = MaxOrgDepth;
+    type RemovalGuard = Bounty;
+    type OrgVote = vote::Module<Test>;
+    type OrgVoteId = u64;
+    type OrgVoteDuration = OrgVoteDuration;
+    type SignalShareClass = SignalShareClass;
+    type SupervisorTermLength = SupervisorTermLength;
+    type MaxConsecutiveSupervisorTerms = MaxConsecutiveSupervisorTerms;
+    type MembershipCurrency = Balances;
+    type MembershipDeposit = MembershipDeposit;
+    type MaxMembershipApplications = MaxMembershipApplications;
+    type ShareChangeHandler = ();
+}
+parameter_types! {
+    pub const MaxClosedVoteHistory: u32 = 100;
+    pub const MaxVoteControlDuration: u64 = 20;
 }
 impl vote::Trait for Test {
     type Event = TestEvent;
     type VoteId = u64;
     type Signal = u64;
+    type MaxClosedVoteHistory = MaxClosedVoteHistory;
+    type MaxVoteControlDuration = MaxVoteControlDuration;
 }
 parameter_types! {
     pub const TransactionFee: u64 = 3;
     pub const TreasuryModuleId: ModuleId = ModuleId(*b"py/trsry");
+    pub const PayoutShareClass: ShareClass = ShareClass::Revenue;
 }
 impl donate::Trait for Test {
     type Event = TestEvent;
     type Currency = Balances;
     type TransactionFee = TransactionFee;
     type Treasury = TreasuryModuleId;
+    type PayoutShareClass = PayoutShareClass;
 }
 parameter_types! {
     pub const MaxTreasuryPerOrg: u32 = 50;
@@ -129,9 +158,10 @@ impl Trait for Test {
 pub type System = system::Module<Test>;
 pub type Balances = pallet_balances::Module<Test>;
 pub type Org = org::Module<Test>;
+pub type Vote = vote::Module<Test>;
 pub type Bounty = Module<Test>;
 
-fn get_last_event() -> RawEvent<u64, u64, u64, u64> {
+fn get_last_event() -> RawEvent<u64, u32, u64, u64, u64> {
     System::events()
         .into_iter()
         .map(|r| r.event)
@@ -159,6 +189,7 @@ fn new_test_ext() -> sp_io::TestExternalities {
         first_organization_supervisor: 1,
         first_organization_value_constitution: 1738,
         first_organization_flat_membership: vec![1, 2, 3, 4, 5, 6],
+        organizations: vec![],
     }
     .assimilate_storage(&mut t)
     .unwrap();
@@ -167,7 +198,10 @@ fn new_test_ext() -> sp_io::TestExternalities {
     ext
 }
 
-use util::organization::Organization;
+use util::{
+    organization::Organization,
+    share::ShareClass,
+};
 
 #[test]
 fn genesis_config_works() {
@@ -545,3 +579,232 @@ fn account_sudo_approves_milestone_works() {
         );
     });
 }
+
+#[test]
+fn milestone_rejection_exposes_reasons() {
+    new_test_ext().execute_with(|| {
+        let one = Origin::signed(1);
+        let two = Origin::signed(2);
+        let new_resolution_metadata =
+            ResolutionMetadata::new(1, 4, Some(2), None);
+        assert_ok!(Bounty::account_posts_bounty(
+            one.clone(),
+            10u32, // constitution
+            10,    // amount reserved for bounty
+            new_resolution_metadata,
+            None,
+        ));
+        assert_ok!(Bounty::account_applies_for_bounty(
+            two.clone(),
+            1,
+            15u32, // application description
+            10
+        ));
+        assert_ok!(Bounty::account_sudo_approves_application(
+            one.clone(),
+            1,
+            1,
+        ));
+        assert_ok!(Bounty::grantee_submits_milestone(
+            two.clone(),
+            1,
+            1,
+            10u32, // milestone reference
+            10
+        ));
+        assert_ok!(Bounty::account_triggers_milestone_review(
+            one.clone(),
+            1,
+            1,
+        ));
+        // reviewers must justify votes against the milestone
+        assert_noop!(
+            Vote::submit_vote(Origin::signed(3), 1, VoterView::Against, None),
+            vote::Error::<Test>::AgainstVoteRequiresJustification
+        );
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(3),
+            1,
+            VoterView::Against,
+            Some(31)
+        ));
+        assert_ok!(Vote::submit_vote(
+            Origin::signed(4),
+            1,
+            VoterView::Against,
+            Some(41)
+        ));
+        assert_ok!(Bounty::account_polls_milestone(one, 1, 1));
+        let mut reasons = Bounty::milestone_rejection_reasons(1, 1);
+        reasons.sort();
+        assert_eq!(reasons, vec![31, 41]);
+        assert_eq!(
+            Bounty::milestone_submissions(1, 1).unwrap().state(),
+            MilestoneStatus::Rejected(1)
+        );
+    });
+}
+
+#[test]
+fn bounties_block_organization_removal_until_paid_out() {
+    new_test_ext().execute_with(|| {
+        let one = Origin::signed(1);
+        let two = Origin::signed(2);
+        let new_resolution_metadata = ResolutionMetadata::new(1, 1, None, None);
+        assert_ok!(Bounty::account_posts_bounty(
+            one.clone(),
+            10u32, // constitution
+            10,    // amount reserved for bounty
+            new_resolution_metadata,
+            None,
+        ));
+        assert_eq!(Bounty::org_bounty_count(1), 1);
+        // the members approve the dissolution but the live bounty blocks it
+        assert_ok!(Org::dissolve_organization(one.clone(), 1, false));
+        for i in 1u64..5u64 {
+            assert_ok!(Vote::submit_vote(
+                Origin::signed(i),
+                1,
+                VoterView::InFavor,
+                None
+            ));
+        }
+        assert_eq!(
+            Org::poll_dissolution(one.clone(), 1),
+            Err(Error::<Test>::CannotRemoveOrganizationThatReviewsLiveBounties
+                .into())
+        );
+        assert_ok!(Bounty::account_applies_for_bounty(
+            two.clone(),
+            1,
+            15u32, // application description
+            10
+        ));
+        assert_ok!(Bounty::account_sudo_approves_application(
+            one.clone(),
+            1,
+            1,
+        ));
+        // a partial payout keeps the bounty under review
+        assert_ok!(Bounty::grantee_submits_milestone(
+            two.clone(),
+            1,
+            1,
+            10u32, // milestone reference
+            4
+        ));
+        assert_ok!(Bounty::account_approved_milestone(one.clone(), 1, 1));
+        assert_eq!(
+            Bounty::foundation_sponsored_bounties(1)
+                .unwrap()
+                .funding_reserved(),
+            6
+        );
+        assert_eq!(Bounty::org_bounty_count(1), 1);
+        assert_ok!(Bounty::grantee_submits_milestone(
+            two, 1, 1, 11u32, // milestone reference
+            6
+        ));
+        assert_ok!(Bounty::account_approved_milestone(one.clone(), 1, 2));
+        assert_eq!(Bounty::org_bounty_count(1), 0);
+        assert_ok!(Org::poll_dissolution(one, 1));
+        assert!(Org::organization_states(1).is_none());
+    });
+}
+
+#[test]
+fn account_closes_bounty_works() {
+    new_test_ext().execute_with(|| {
+        let one = Origin::signed(1);
+        let two = Origin::signed(2);
+        let new_resolution_metadata = ResolutionMetadata::new(1, 1, None, None);
+        for _ in 0..2 {
+            assert_ok!(Bounty::account_posts_bounty(
+                one.clone(),
+                10u32, // constitution
+                10,    // amount reserved for bounty
+                new_resolution_metadata.clone(),
+                None,
+            ));
+        }
+        assert_eq!(Balances::reserved_balance(&1), 20);
+        assert_eq!(Bounty::org_bounty_count(1), 2);
+        assert_noop!(
+            Bounty::account_closes_bounty(two.clone(), 1),
+            Error::<Test>::NotAuthorizedToCloseBounty
+        );
+        // grantees working on a bounty keep its funding
+        assert_ok!(Bounty::account_applies_for_bounty(
+            two.clone(),
+            1,
+            15u32, // application description
+            10
+        ));
+        assert_ok!(Bounty::account_sudo_approves_application(
+            one.clone(),
+            1,
+            1,
+        ));
+        assert_noop!(
+            Bounty::account_closes_bounty(one.clone(), 1),
+            Error::<Test>::CannotCloseBountyWithApprovedApplication
+        );
+        // applications which were not approved do not block closing
+        assert_ok!(Bounty::account_applies_for_bounty(
+            two,
+            2,
+            15u32, // application description
+            10
+        ));
+        assert_ok!(Bounty::account_closes_bounty(one.clone(), 2));
+        assert_eq!(get_last_event(), RawEvent::BountyClosed(2, 1, 10));
+        assert_eq!(Balances::reserved_balance(&1), 10);
+        assert_eq!(Bounty::org_bounty_count(1), 1);
+        assert!(Bounty::foundation_sponsored_bounties(2).is_none());
+        assert!(Bounty::bounty_applications(2, 1).is_none());
+        assert_noop!(
+            Bounty::account_closes_bounty(one, 2),
+            Error::<Test>::CannotCloseBountyThatDNE
+        );
+    });
+}
+
+#[test]
+fn org_bounty_count_migration_works() {
+    new_test_ext().execute_with(|| {
+        let one = Origin::signed(1);
+        let two = Origin::signed(2);
+        let new_resolution_metadata = ResolutionMetadata::new(1, 1, None, None);
+        for _ in 0..2 {
+            assert_ok!(Bounty::account_posts_bounty(
+                one.clone(),
+                10u32, // constitution
+                10,    // amount reserved for bounty
+                new_resolution_metadata.clone(),
+                None,
+            ));
+        }
+        assert_ok!(Bounty::account_applies_for_bounty(
+            two.clone(),
+            1,
+            15u32, // application description
+            10
+        ));
+        assert_ok!(Bounty::account_sudo_approves_application(
+            one.clone(),
+            1,
+            1,
+        ));
+        assert_ok!(Bounty::grantee_submits_milestone(
+            two, 1, 1, 10u32, // milestone reference
+            10
+        ));
+        assert_ok!(Bounty::account_approved_milestone(one, 1, 1));
+        // chains which counted every bounty ever posted
+        <OrgBountyCount<Test>>::insert(1, 2);
+        <StorageVersion>::put(Releases::V1);
+        Bounty::migrate_org_bounty_count();
+        assert_eq!(Bounty::org_bounty_count(1), 1);
+        assert_eq!(<StorageVersion>::get(), Releases::V2);
+    });
+}
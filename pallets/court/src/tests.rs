@@ -15,6 +15,7 @@ use sp_runtime::{
 };
 use util::{
     organization::Organization,
+    share::ShareClass,
     traits::GroupMembership,
     vote::VoterView,
 };
@@ -49,6 +50,14 @@ parameter_types! {
     pub const MaximumBlockLength: u32 = 2 * 1024;
     pub const AvailableBlockRatio: Perbill = Perbill::one();
     pub const ReservationLimit: u32 = 10000;
+    pub const MaxMembers: u32 = 10000;
+    pub const MaxOrgDepth: u32 = 2;
+    pub const OrgVoteDuration: u64 = 10;
+    pub const SignalShareClass: ShareClass = ShareClass::Voting;
+    pub const SupervisorTermLength: u64 = 100;
+    pub const MaxConsecutiveSupervisorTerms: u32 = 2;
+    pub const MembershipDeposit: u64 = 10;
+    pub const MaxMembershipApplications: u32 = 2;
 }
 impl frame_system::Trait for Test {
     type Origin = Origin;
@@ -92,11 +101,30 @@ impl org::Trait for Test {
     type OrgId = u64;
     type Shares = u64;
     type ReservationLimit = ReservationLimit;
+    type MaxMembers = MaxMembers;
+    type MaxOrgDepth = MaxOrgDepth;
+    type RemovalGuard = ();
+    type OrgVote = vote::Module<Test>;
+    type OrgVoteId = u64;
+    type OrgVoteDuration = OrgVoteDuration;
+    type SignalShareClass = SignalShareClass;
+    type SupervisorTermLength = SupervisorTermLength;
+    type MaxConsecutiveSupervisorTerms = MaxConsecutiveSupervisorTerms;
+    type MembershipCurrency = Balances;
+    type MembershipDeposit = MembershipDeposit;
+    type MaxMembershipApplications = MaxMembershipApplications;
+    type ShareChangeHandler = ();
+}
+parameter_types! {
+    pub const MaxClosedVoteHistory: u32 = 100;
+    pub const MaxVoteControlDuration: u64 = 20;
 }
 impl vote::Trait for Test {
     type Event = TestEvent;
     type VoteId = u64;
     type Signal = u64;
+    type MaxClosedVoteHistory = MaxClosedVoteHistory;
+    type MaxVoteControlDuration = MaxVoteControlDuration;
 }
 parameter_types! {
     pub const MinimumDisputeAmount: u64 = 10;
@@ -141,6 +169,7 @@ fn new_test_ext() -> sp_io::TestExternalities {
         first_organization_supervisor: 1,
         first_organization_value_constitution: 1738,
         first_organization_flat_membership: vec![1, 2, 3, 4, 5, 6],
+        organizations: vec![],
     }
     .assimilate_storage(&mut t)
     .unwrap();
@@ -7,6 +7,7 @@ use frame_support::{
     decl_error,
     decl_event,
     decl_module,
+    ensure,
     traits::{
         Currency,
         ExistenceRequirement,
@@ -21,13 +22,17 @@ use sp_runtime::{
     traits::{
         AccountIdConversion,
         CheckedSub,
+        Zero,
     },
     DispatchError,
     DispatchResult,
     ModuleId,
     Permill,
 };
-use util::traits::GetGroup;
+use util::{
+    share::ShareClass,
+    traits::ShareClassInformation,
+};
 
 type BalanceOf<T> = <<T as Trait>::Currency as Currency<
     <T as system::Trait>::AccountId,
@@ -42,6 +47,8 @@ pub trait Trait: system::Trait + org::Trait {
     type TransactionFee: Get<BalanceOf<Self>>;
     /// Where the conditional taxes go
     type Treasury: Get<ModuleId>;
+    /// The share class that weights payouts to an organization's shareholders
+    type PayoutShareClass: Get<ShareClass>;
 }
 
 decl_event!(
@@ -114,14 +121,16 @@ impl<T: Trait> Module<T> {
         let _ = free
             .checked_sub(&total_transfer)
             .ok_or(Error::<T>::NotEnoughFundsInFreeToMakeTransfer)?;
-        // Get the membership set of the Org
-        let group = <org::Module<T>>::get_group(recipient)
-            .ok_or(Error::<T>::CannotDonateToOrgThatDNE)?;
+        // Get the shareholders of the Org in the payout class
+        let group = <org::Module<T>>::class_ownership(
+            recipient,
+            T::PayoutShareClass::get(),
+        )
+        .ok_or(Error::<T>::CannotDonateToOrgThatDNE)?;
         // iterate through and pay the transfer out
         group
-            .0
             .into_iter()
-            .map(|acc: T::AccountId| -> DispatchResult {
+            .map(|(acc, _)| -> DispatchResult {
                 let amt_due = Self::calculate_proportional_amount_for_account(
                     amt,
                     acc.clone(),
@@ -153,13 +162,16 @@ impl<T: Trait> Module<T> {
         account: T::AccountId,
         group: T::OrgId,
     ) -> Result<BalanceOf<T>, DispatchError> {
-        let issuance = <org::Module<T>>::total_issuance(group);
-        let acc_ownership = <org::Module<T>>::members(group, &account)
-            .ok_or(Error::<T>::AccountHasNoOwnershipInOrg)?;
-        let ownership = Permill::from_rational_approximation(
-            acc_ownership.total(),
-            issuance,
+        let class = T::PayoutShareClass::get();
+        let issuance = <org::Module<T>>::class_issuance(group, class);
+        let acc_ownership =
+            <org::Module<T>>::class_shares(group, class, &account);
+        ensure!(
+            !acc_ownership.is_zero(),
+            Error::<T>::AccountHasNoOwnershipInOrg
         );
+        let ownership =
+            Permill::from_rational_approximation(acc_ownership, issuance);
         Ok(ownership * amount)
     }
 }
@@ -47,6 +47,14 @@ parameter_types! {
     pub const MaximumBlockLength: u32 = 2 * 1024;
     pub const AvailableBlockRatio: Perbill = Perbill::one();
     pub const ReservationLimit: u32 = 10000;
+    pub const MaxMembers: u32 = 10000;
+    pub const MaxOrgDepth: u32 = 2;
+    pub const OrgVoteDuration: u64 = 10;
+    pub const SignalShareClass: ShareClass = ShareClass::Voting;
+    pub const SupervisorTermLength: u64 = 100;
+    pub const MaxConsecutiveSupervisorTerms: u32 = 2;
+    pub const MembershipDeposit: u64 = 10;
+    pub const MaxMembershipApplications: u32 = 2;
 }
 impl frame_system::Trait for Test {
     type Origin = Origin;
@@ -90,16 +98,31 @@ impl org::Trait for Test {
     type OrgId = u64;
     type Shares = u64;
     type ReservationLimit = ReservationLimit;
+    type MaxMembers = MaxMembers;
+    type MaxOrgDepth = MaxOrgDepth;
+    type RemovalGuard = ();
+    type OrgVote = ();
+    type OrgVoteId = ();
+    type OrgVoteDuration = OrgVoteDuration;
+    type SignalShareClass = SignalShareClass;
+    type SupervisorTermLength = SupervisorTermLength;
+    type MaxConsecutiveSupervisorTerms = MaxConsecutiveSupervisorTerms;
+    type MembershipCurrency = Balances;
+    type MembershipDeposit = MembershipDeposit;
+    type MaxMembershipApplications = MaxMembershipApplications;
+    type ShareChangeHandler = ();
 }
 parameter_types! {
     pub const TransactionFee: u64 = 3;
     pub const TreasuryModuleId: ModuleId = ModuleId(*b"py/trsry");
+    pub const PayoutShareClass: ShareClass = ShareClass::Revenue;
 }
 impl Trait for Test {
     type Event = TestEvent;
     type Currency = Balances;
     type TransactionFee = TransactionFee;
     type Treasury = TreasuryModuleId;
+    type PayoutShareClass = PayoutShareClass;
 }
 pub type System = system::Module<Test>;
 pub type Balances = pallet_balances::Module<Test>;
@@ -134,6 +157,7 @@ fn new_test_ext() -> sp_io::TestExternalities {
         first_organization_supervisor: 1,
         first_organization_value_constitution: 1738,
         first_organization_flat_membership: vec![1, 2, 3, 4, 5, 6],
+        organizations: vec![],
     }
     .assimilate_storage(&mut t)
     .unwrap();
@@ -177,3 +201,27 @@ fn check_donation_accuracy() {
         assert_eq!(Balances::total_balance(&2), 93);
     });
 }
+
+#[test]
+fn donations_are_weighted_by_payout_share_class() {
+    new_test_ext().execute_with(|| {
+        // a contractor with revenue shares but no membership
+        assert_ok!(Org::issue_class_shares(
+            Origin::signed(1),
+            1,
+            ShareClass::Revenue,
+            7,
+            6
+        ));
+        assert!(!Org::is_member_of_group(1, &7));
+        assert_ok!(Donate::make_prop_donation_without_fee(
+            Origin::signed(3),
+            1,
+            120
+        ));
+        // 120 * 6 / 12 = 60
+        assert_eq!(Balances::total_balance(&7), 60);
+        // 98 + 120 / 12 = 108
+        assert_eq!(Balances::total_balance(&2), 108);
+    });
+}
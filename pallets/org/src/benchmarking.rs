@@ -0,0 +1,118 @@
+//! Benchmarks for iterating the members of one organization while other organizations hold members
+
+use super::*;
+use frame_benchmarking::{
+    account,
+    benchmarks,
+};
+use frame_system::RawOrigin;
+
+const SEED: u32 = 0;
+/// Members in each of the other organizations registered alongside the benchmarked one
+const OTHER_ORG_MEMBERS: u32 = 20;
+
+fn register_org<T: Trait>(
+    name: &'static str,
+    members: u32,
+    supervisor: Option<T::AccountId>,
+) -> T::OrgId {
+    let accounts = (0..members)
+        .map(|i| account(name, i, SEED))
+        .collect::<Vec<T::AccountId>>();
+    <Module<T>>::register_organization(
+        OrganizationSource::Accounts(accounts),
+        supervisor,
+        T::IpfsReference::default(),
+    )
+    .expect("benchmark organization registration failed")
+}
+
+/// Registers `o` organizations with `OTHER_ORG_MEMBERS` members each and one organization with `m` members supervised by `supervisor()`
+fn setup<T: Trait>(o: u32, m: u32) -> T::OrgId {
+    (0..o).for_each(|_| {
+        register_org::<T>("other", OTHER_ORG_MEMBERS, None);
+    });
+    register_org::<T>("member", m, Some(supervisor::<T>()))
+}
+
+fn supervisor<T: Trait>() -> T::AccountId {
+    account("supervisor", 0, SEED)
+}
+
+benchmarks! {
+    _ {
+        let o in 0 .. 50 => ();
+        let m in 1 .. 100 => ();
+    }
+
+    // the lookup used before the `MemberCount` index, scanning the members of every organization
+    get_group_full_scan {
+        let o in ...;
+        let m in ...;
+        let org = setup::<T>(o, m);
+    }: {
+        let group = <Members<T>>::iter()
+            .filter(|(id, _, _)| *id == org)
+            .map(|(_, account, _)| account)
+            .collect::<Vec<_>>();
+        assert_eq!(group.len() as u32, m);
+    }
+
+    // the current lookup, iterating only the organization's prefix
+    get_group_prefix {
+        let o in ...;
+        let m in ...;
+        let org = setup::<T>(o, m);
+    }: {
+        let group = <Module<T>>::get_group(org).expect("org registered in setup");
+        assert_eq!(group.0.len() as u32, m);
+    }
+
+    migrate_member_count {
+        let o in ...;
+        let m in ...;
+        let org = setup::<T>(o, m);
+        <StorageVersion>::put(Releases::V1);
+        <MemberCount<T>>::iter()
+            .map(|(id, _)| id)
+            .collect::<Vec<_>>()
+            .into_iter()
+            .for_each(<MemberCount<T>>::remove);
+    }: {
+        <Module<T>>::migrate_member_count();
+    }
+    verify {
+        assert_eq!(<MemberCount<T>>::get(org), m);
+    }
+
+    issue_shares {
+        let o in ...;
+        let m in ...;
+        let org = setup::<T>(o, m);
+        let new_member: T::AccountId = account("new", 0, SEED);
+        let shares: T::Shares = 10u32.into();
+    }: _(RawOrigin::Signed(supervisor::<T>()), org, new_member, shares, None)
+    verify {
+        assert_eq!(<MemberCount<T>>::get(org), m + 1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{
+        new_test_ext,
+        TestRuntime,
+    };
+    use frame_support::assert_ok;
+
+    #[test]
+    fn benchmarks_work() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(test_benchmark_get_group_full_scan::<TestRuntime>());
+            assert_ok!(test_benchmark_get_group_prefix::<TestRuntime>());
+            assert_ok!(test_benchmark_migrate_member_count::<TestRuntime>());
+            assert_ok!(test_benchmark_issue_shares::<TestRuntime>());
+        });
+    }
+}
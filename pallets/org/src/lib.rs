@@ -8,33 +8,57 @@
 #[cfg(test)]
 mod tests;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
 use util::{
     organization::{
+        ConstitutionAmendment,
+        MembershipApplication,
         Organization,
+        OrganizationDissolution,
+        OrganizationGenesis,
         OrganizationSource,
+        SupervisorChange,
+        SupervisorTerm,
     },
     share::{
+        ShareClass,
         ShareProfile,
         SimpleShareGenesis,
+        VestingSchedule,
+        VestingTerms,
     },
     traits::{
         AccessGenesis,
+        ControlOpenVote,
         GenerateUniqueID,
         GetGroup,
+        GetVoteOutcome,
         GroupMembership,
         IDIsAvailable,
         LockProfile,
+        OpenVote,
+        OrganizationHierarchy,
+        OrganizationRemovalGuard,
         OrganizationSupervisorPermissions,
         RegisterOrganization,
         RemoveOrganization,
         ReserveProfile,
+        ShareChangeHandler,
+        ShareClassInformation,
         ShareInformation,
         ShareIssuance,
         VerifyShape,
     },
+    vote::VoteOutcome,
 };
 
-use codec::Codec;
+use codec::{
+    Codec,
+    Decode,
+    Encode,
+};
 use frame_support::{
     decl_error,
     decl_event,
@@ -45,7 +69,12 @@ use frame_support::{
         IterableStorageDoubleMap,
         IterableStorageMap,
     },
-    traits::Get,
+    traits::{
+        Currency,
+        Get,
+        ReservableCurrency,
+    },
+    weights::Weight,
     Parameter,
 };
 use frame_system::{
@@ -61,16 +90,22 @@ use sp_runtime::{
         CheckedSub,
         MaybeSerializeDeserialize,
         Member,
+        Saturating,
         Zero,
     },
     DispatchError,
     DispatchResult,
+    RuntimeDebug,
 };
 use sp_std::{
     fmt::Debug,
     prelude::*,
 };
 
+type BalanceOf<T> = <<T as Trait>::MembershipCurrency as Currency<
+    <T as frame_system::Trait>::AccountId,
+>>::Balance;
+
 pub trait Trait: system::Trait {
     /// Overarching event type
     type Event: From<Event<Self>> + Into<<Self as frame_system::Trait>::Event>;
@@ -108,6 +143,56 @@ pub trait Trait: system::Trait {
     /// The hard limit on the number of times shares can be reserved
     /// - why? we need to track how much the group check is called and limit it somehow and this is the best I've come up with for now...TODO: make issue and get feedback
     type ReservationLimit: Get<u32>;
+
+    /// The maximum number of members in an organization, bounds the cost of iterating its members
+    type MaxMembers: Get<u32>;
+
+    /// The maximum number of ancestors of a sub-organization, bounds the cost of walking the hierarchy
+    type MaxOrgDepth: Get<u32>;
+
+    /// Notified of share changes, i.e. the vote module keeps minted signal consistent with shares
+    type ShareChangeHandler: ShareChangeHandler<
+        Self::OrgId,
+        Self::AccountId,
+        Self::Shares,
+    >;
+
+    /// Modules with organization state that must be settled before an organization is removed
+    type RemovalGuard: OrganizationRemovalGuard<Self::OrgId>;
+
+    /// Opens, polls and closes the member votes that elect and recall supervisors, amend constitutions and dissolve organizations
+    type OrgVote: OpenVote<
+            Self::OrgId,
+            Self::Shares,
+            Self::BlockNumber,
+            Self::IpfsReference,
+            VoteIdentifier = Self::OrgVoteId,
+        > + GetVoteOutcome<Self::OrgVoteId, Outcome = VoteOutcome>
+        + ControlOpenVote<Self::OrgVoteId, Self::BlockNumber>;
+
+    /// The vote identifier of `OrgVote`
+    type OrgVoteId: Parameter + Member + Copy;
+
+    /// The number of blocks members have to vote on a supervisor change, constitution amendment or dissolution
+    type OrgVoteDuration: Get<Self::BlockNumber>;
+
+    /// The share class that weights signal in votes of weighted membership, read by the vote module and the member votes of `OrgVote`
+    type SignalShareClass: Get<ShareClass>;
+
+    /// The number of blocks an elected supervisor serves before losing its permissions
+    type SupervisorTermLength: Get<Self::BlockNumber>;
+
+    /// The maximum number of consecutive terms served by one elected supervisor
+    type MaxConsecutiveSupervisorTerms: Get<u32>;
+
+    /// The currency reserved for the membership application deposits, slashed if the application is rejected
+    type MembershipCurrency: ReservableCurrency<Self::AccountId>;
+
+    /// The minimum deposit reserved with every membership application, deters applications that open votes for free
+    type MembershipDeposit: Get<BalanceOf<Self>>;
+
+    /// The maximum number of pending membership applications to one organization
+    type MaxMembershipApplications: Get<u32>;
 }
 
 decl_event!(
@@ -117,6 +202,8 @@ decl_event!(
         OrgId = <T as Trait>::OrgId,
         <T as Trait>::Shares,
         <T as Trait>::IpfsReference,
+        <T as Trait>::OrgVoteId,
+        <T as frame_system::Trait>::BlockNumber,
     {
         /// No shares issued but an organization was registered with flat membership with the last `u32` as the number of members
         NewFlatOrganizationRegistered(AccountId, OrgId, IpfsReference, u32),
@@ -146,6 +233,60 @@ decl_event!(
         SharesBatchIssued(OrgId, Shares),
         /// Organization ID, Total Shares Burned
         SharesBatchBurned(OrgId, Shares),
+        /// Proposer, Organization ID, whether sub-organizations are dissolved, Vote ID
+        DissolutionProposed(AccountId, OrgId, bool, OrgVoteId),
+        /// Proposer, Organization ID, all removed Organization IDs
+        OrganizationDissolved(AccountId, OrgId, Vec<OrgId>),
+        /// Organization ID, Vote ID of the rejected or undecided dissolution
+        DissolutionFailed(OrgId, OrgVoteId),
+        /// Proposer, Organization ID, elected candidate or None for recall, Vote ID
+        SupervisorChangeProposed(AccountId, OrgId, Option<AccountId>, OrgVoteId),
+        /// Organization ID, elected supervisor, block at which the term ends
+        SupervisorElected(OrgId, AccountId, BlockNumber),
+        /// Organization ID, recalled supervisor
+        SupervisorRemoved(OrgId, Option<AccountId>),
+        /// Organization ID, Vote ID of the rejected or undecided change
+        SupervisorChangeFailed(OrgId, OrgVoteId),
+        /// Proposer, Organization ID, proposed constitution, Vote ID
+        ConstitutionAmendmentProposed(AccountId, OrgId, IpfsReference, OrgVoteId),
+        /// Organization ID, new constitution
+        ConstitutionAmended(OrgId, IpfsReference),
+        /// Organization ID, Vote ID of the rejected or undecided amendment
+        ConstitutionAmendmentFailed(OrgId, OrgVoteId),
+        /// Organization ID, whether members may transfer shares
+        ShareTransferabilitySet(OrgId, bool),
+        /// Organization ID, Account ID of the member that left, Amount Burned
+        MemberLeftOrg(OrgId, AccountId, Shares),
+        /// Organization ID, Sender, Recipient, Amount Transferred
+        SharesTransferred(OrgId, AccountId, AccountId, Shares),
+        /// Organization ID, shares issued to accepted applicants
+        MembershipSharesSet(OrgId, Shares),
+        /// Applicant, Organization ID, application, Vote ID
+        MembershipApplied(AccountId, OrgId, IpfsReference, OrgVoteId),
+        /// Organization ID, accepted applicant, Amount Issued
+        MembershipApplicationAccepted(OrgId, AccountId, Shares),
+        /// Organization ID, applicant, Vote ID of the rejected or undecided application
+        MembershipApplicationRejected(OrgId, AccountId, OrgVoteId),
+        /// Organization ID, applicant
+        MembershipApplicationWithdrawn(OrgId, AccountId),
+        /// Organization ID, invitee, Amount issued on acceptance
+        MemberInvited(OrgId, AccountId, Shares),
+        /// Organization ID, invitee, Amount Issued
+        InvitationAccepted(OrgId, AccountId, Shares),
+        /// Organization ID, invitee
+        InvitationDeclined(OrgId, AccountId),
+        /// Organization ID, Share Class, Recipient AccountId, Issued Amount
+        ClassSharesIssued(OrgId, ShareClass, AccountId, Shares),
+        /// Organization ID, Share Class, Burned AccountId, Burned Amount
+        ClassSharesBurned(OrgId, ShareClass, AccountId, Shares),
+        /// Organization ID, Recipient AccountId, Issued Amount, vesting terms
+        VestingSharesIssued(OrgId, AccountId, Shares, VestingTerms<BlockNumber>),
+        /// Proposer, Organization ID, vesting member, Vote ID
+        VestingRevocationProposed(AccountId, OrgId, AccountId, OrgVoteId),
+        /// Organization ID, vesting member, Amount Burned
+        VestingRevoked(OrgId, AccountId, Shares),
+        /// Organization ID, vesting member, Vote ID of the rejected or undecided revocation
+        VestingRevocationFailed(OrgId, AccountId, OrgVoteId),
     }
 );
 
@@ -169,6 +310,7 @@ decl_error! {
         CannotUnLockProfileThatDNE,
         CannotLockProfileThatDNE,
         CannotReserveIfMemberProfileDNE,
+        CannotReserveLockedShares,
         CannotUnReserveIfMemberProfileDNE,
         OrganizationMustBeRegisteredToIssueShares,
         OrganizationMustBeRegisteredToBurnShares,
@@ -184,6 +326,76 @@ decl_error! {
         NotAuthorizedToBurnShares,
         OrganizationCannotBeRemovedIfInputIdIsAvailable,
         AccountHasNoOwnershipInOrg,
+        MemberCountWouldExceedMaxMembers,
+        OrganizationMustExistToDissolve,
+        NotAuthorizedToDissolveOrganization,
+        CannotDissolveOrganizationWithSubOrganizationsUnlessRecursive,
+        DissolutionAlreadyProposed,
+        NoDissolutionProposed,
+        DissolutionVoteUndecided,
+        OrganizationMustExistToChangeSupervisor,
+        NotAuthorizedToProposeSupervisorChange,
+        SupervisorCandidateMustBeMember,
+        SupervisorChangeAlreadyProposed,
+        NoSupervisorChangeProposed,
+        SupervisorChangeVoteUndecided,
+        SupervisorExceedsMaxConsecutiveTerms,
+        OrganizationMustExistToAmendConstitution,
+        NotAuthorizedToProposeConstitutionAmendment,
+        ConstitutionAmendmentAlreadyProposed,
+        NoConstitutionAmendmentProposed,
+        ConstitutionAmendmentVoteUndecided,
+        OrganizationMustBeRegisteredToSetShareTransferability,
+        NotAuthorizedToSetShareTransferability,
+        CannotLeaveOrganizationIfNotMember,
+        CannotTransferSharesIfNotMember,
+        CannotMoveLockedOrReservedShares,
+        SharesNotTransferableInOrganization,
+        CannotTransferSharesToNonMember,
+        CannotTransferSharesToSelf,
+        OrganizationMustBeRegisteredToSetMembershipShares,
+        NotAuthorizedToSetMembershipShares,
+        OrganizationMustExistToApplyForMembership,
+        CannotApplyForMembershipIfAlreadyMember,
+        MembershipApplicationAlreadyExists,
+        MembershipDepositBelowMinimum,
+        TooManyMembershipApplications,
+        NoMembershipApplication,
+        MembershipApplicationVoteUndecided,
+        CannotWithdrawDecidedMembershipApplication,
+        OrganizationMustExistToInviteMember,
+        NotAuthorizedToInviteMember,
+        CannotInviteExistingMember,
+        NoInvitationToAccept,
+        ParentOrganizationMustExistToRegisterSubOrganization,
+        SubOrganizationWouldExceedMaxOrgDepth,
+        ClassHolderCountWouldExceedMaxMembers,
+        NotEnoughClassSharesToSatisfyBurnRequest,
+        InvalidVestingTerms,
+        VestingScheduleAlreadyExists,
+        CannotMoveUnvestedShares,
+        NoVestingScheduleToRevoke,
+        NotAuthorizedToProposeVestingRevocation,
+        VestingRevocationAlreadyProposed,
+        NoVestingRevocationProposed,
+        VestingRevocationVoteUndecided,
+    }
+}
+
+/// Storage layout versions of this module, used for runtime upgrade migrations
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub enum Releases {
+    /// Members without the `MemberCount` index
+    V1,
+    /// Members with the `MemberCount` index
+    V2,
+    /// Organizations with the `SubOrganizations` index
+    V3,
+}
+
+impl Default for Releases {
+    fn default() -> Self {
+        Releases::V1
     }
 }
 
@@ -199,28 +411,127 @@ decl_storage! {
         pub OrganizationStates get(fn organization_states): map
             hasher(blake2_128_concat) T::OrgId => Option<Organization<T::AccountId, T::OrgId, T::IpfsReference>>;
 
+        /// The sub-organizations registered with each organization as their parent
+        pub SubOrganizations get(fn sub_organizations): map
+            hasher(blake2_128_concat) T::OrgId => Vec<T::OrgId>;
+
         /// The map to track organizational membership
         pub Members get(fn members): double_map
             hasher(blake2_128_concat) T::OrgId,
             hasher(blake2_128_concat) T::AccountId => Option<ShareProfile<T::Shares>>;
 
+        /// The shares of each account in the share classes other than `ShareClass::Common`, which are held in `Members`
+        pub ClassShares get(fn class_shares_of): double_map
+            hasher(blake2_128_concat) (T::OrgId, ShareClass),
+            hasher(blake2_128_concat) T::AccountId => T::Shares;
+
+        /// Total shares issued in the share classes other than `ShareClass::Common`
+        pub ClassIssuance get(fn class_issuance_of): map
+            hasher(blake2_128_concat) (T::OrgId, ShareClass) => T::Shares;
+
+        /// The number of holders of each share class, bounded by `MaxMembers`
+        pub ClassHolderCount get(fn class_holder_count): map
+            hasher(blake2_128_concat) (T::OrgId, ShareClass) => u32;
+
+        /// The vesting of shares issued with vesting terms, unvested shares carry no voting or payout weight
+        pub VestingSchedules get(fn vesting_schedules): double_map
+            hasher(blake2_128_concat) T::OrgId,
+            hasher(blake2_128_concat) T::AccountId => Option<VestingSchedule<T::Shares, T::BlockNumber>>;
+
+        /// Pending vote-driven revocations of unvested shares with the vote and its deadline
+        pub VestingRevocations get(fn vesting_revocations): double_map
+            hasher(blake2_128_concat) T::OrgId,
+            hasher(blake2_128_concat) T::AccountId => Option<(T::OrgVoteId, T::BlockNumber)>;
+
+        /// The number of members in each organization, bounded by `MaxMembers`
+        pub MemberCount get(fn member_count): map
+            hasher(blake2_128_concat) T::OrgId => u32;
+
+        /// Organizations whose members may transfer shares to other members
+        pub TransferableShares get(fn transferable_shares): map
+            hasher(blake2_128_concat) T::OrgId => bool;
+
+        /// The shares issued to accepted membership applicants, one share if unset
+        pub MembershipShares get(fn membership_shares): map
+            hasher(blake2_128_concat) T::OrgId => Option<T::Shares>;
+
+        /// Pending membership applications by outsiders
+        pub MembershipApplications get(fn membership_applications): double_map
+            hasher(blake2_128_concat) T::OrgId,
+            hasher(blake2_128_concat) T::AccountId => Option<MembershipApplication<T::IpfsReference, T::Shares, BalanceOf<T>, T::OrgVoteId, T::BlockNumber>>;
+
+        /// The number of pending membership applications, bounded by `MaxMembershipApplications`
+        pub MembershipApplicationCount get(fn membership_application_count): map
+            hasher(blake2_128_concat) T::OrgId => u32;
+
+        /// Invitations by the supervisor with the shares issued when the invitee accepts
+        pub Invitations get(fn invitations): double_map
+            hasher(blake2_128_concat) T::OrgId,
+            hasher(blake2_128_concat) T::AccountId => Option<T::Shares>;
+
         /// Total number of outstanding shares that express relative ownership in group
         pub TotalIssuance get(fn total_issuance): map
             hasher(opaque_blake2_256) T::OrgId => T::Shares;
+
+        /// Pending vote-driven supervisor changes
+        pub SupervisorChanges get(fn supervisor_changes): map
+            hasher(blake2_128_concat) T::OrgId => Option<SupervisorChange<T::AccountId, T::OrgVoteId, T::BlockNumber>>;
+
+        /// The terms of elected supervisors
+        pub SupervisorTerms get(fn supervisor_terms): map
+            hasher(blake2_128_concat) T::OrgId => Option<SupervisorTerm<T::AccountId, T::BlockNumber>>;
+
+        /// Pending vote-driven constitution amendments
+        pub ConstitutionAmendments get(fn constitution_amendments): map
+            hasher(blake2_128_concat) T::OrgId => Option<ConstitutionAmendment<T::IpfsReference, T::OrgVoteId, T::BlockNumber>>;
+
+        /// Pending vote-driven dissolutions
+        pub OrganizationDissolutions get(fn organization_dissolutions): map
+            hasher(blake2_128_concat) T::OrgId => Option<OrganizationDissolution<T::AccountId, T::OrgVoteId, T::BlockNumber>>;
+
+        /// Append-only history of replaced constitutions with the block at which each was replaced, oldest first
+        pub ConstitutionHistory get(fn constitution_history): map
+            hasher(blake2_128_concat) T::OrgId => Vec<(T::IpfsReference, T::BlockNumber)>;
+
+        /// The storage layout version, new chains start with the latest layout
+        StorageVersion build(|_| Releases::V3): Releases;
     }
     add_extra_genesis {
         config(first_organization_supervisor): T::AccountId;
         config(first_organization_value_constitution): T::IpfsReference;
         config(first_organization_flat_membership): Vec<T::AccountId>;
+        /// Organizations registered after the first organization, in order
+        config(organizations): Vec<
+            OrganizationGenesis<T::AccountId, T::OrgId, T::Shares, T::IpfsReference>
+        >;
 
         build(|config: &GenesisConfig<T>| {
-            <Module<T>>::register_flat_org(
-                T::Origin::from(Some(config.first_organization_supervisor.clone()).into()),
-                Some(config.first_organization_supervisor.clone()),
-                None,
-                config.first_organization_value_constitution.clone(),
-                config.first_organization_flat_membership.clone(),
-            ).expect("first organization config set up failed");
+            // the first organization is skipped if it has no members
+            if !config.first_organization_flat_membership.is_empty() {
+                <Module<T>>::register_flat_org(
+                    T::Origin::from(Some(config.first_organization_supervisor.clone()).into()),
+                    Some(config.first_organization_supervisor.clone()),
+                    None,
+                    config.first_organization_value_constitution.clone(),
+                    config.first_organization_flat_membership.clone(),
+                ).expect("first organization config set up failed");
+            }
+            for org in config.organizations.iter() {
+                if let Some(parent) = org.parent() {
+                    <Module<T>>::register_sub_organization(
+                        parent,
+                        org.source(),
+                        org.supervisor(),
+                        org.constitution(),
+                    )
+                } else {
+                    <Module<T>>::register_organization(
+                        org.source(),
+                        org.supervisor(),
+                        org.constitution(),
+                    )
+                }.expect("genesis organization config set up failed");
+            }
         })
     }
 }
@@ -230,6 +541,10 @@ decl_module! {
         type Error = Error<T>;
         fn deposit_event() = default;
 
+        fn on_runtime_upgrade() -> Weight {
+            Self::migrate_member_count() + Self::migrate_sub_organizations()
+        }
+
         #[weight = 0]
         fn register_flat_org(
             origin,
@@ -269,7 +584,7 @@ decl_module! {
         }
         /// Share Issuance Runtime Methods
         #[weight = 0]
-        fn issue_shares(origin, organization: T::OrgId, who: T::AccountId, shares: T::Shares) -> DispatchResult {
+        fn issue_shares(origin, organization: T::OrgId, who: T::AccountId, shares: T::Shares, vesting: Option<VestingTerms<T::BlockNumber>>) -> DispatchResult {
             let issuer = ensure_signed(origin)?;
             // first check is that the organization exists
             ensure!(!Self::id_is_available(organization), Error::<T>::OrganizationMustBeRegisteredToIssueShares);
@@ -277,8 +592,20 @@ decl_module! {
             let authentication: bool = Self::is_organization_supervisor(organization, &issuer);
             ensure!(authentication, Error::<T>::NotAuthorizedToIssueShares);
 
-            Self::issue(organization, who.clone(), shares, false)?;
-            Self::deposit_event(RawEvent::SharesIssued(organization, who, shares));
+            if let Some(terms) = vesting {
+                ensure!(terms.is_valid(), Error::<T>::InvalidVestingTerms);
+                let now = system::Module::<T>::block_number();
+                // a fully vested schedule is replaced by the new one
+                if let Some(schedule) = <VestingSchedules<T>>::get(organization, &who) {
+                    ensure!(schedule.unvested(now).is_zero(), Error::<T>::VestingScheduleAlreadyExists);
+                }
+                Self::issue(organization, who.clone(), shares, false)?;
+                <VestingSchedules<T>>::insert(organization, &who, VestingSchedule::new(shares, now, terms));
+                Self::deposit_event(RawEvent::VestingSharesIssued(organization, who, shares, terms));
+            } else {
+                Self::issue(organization, who.clone(), shares, false)?;
+                Self::deposit_event(RawEvent::SharesIssued(organization, who, shares));
+            }
             Ok(())
         }
         #[weight = 0]
@@ -295,6 +622,36 @@ decl_module! {
             Ok(())
         }
         #[weight = 0]
+        fn issue_class_shares(origin, organization: T::OrgId, class: ShareClass, who: T::AccountId, shares: T::Shares) -> DispatchResult {
+            let issuer = ensure_signed(origin)?;
+            ensure!(!Self::id_is_available(organization), Error::<T>::OrganizationMustBeRegisteredToIssueShares);
+            let authentication: bool = Self::is_organization_supervisor(organization, &issuer);
+            ensure!(authentication, Error::<T>::NotAuthorizedToIssueShares);
+
+            if class == ShareClass::Common {
+                Self::issue(organization, who.clone(), shares, false)?;
+            } else {
+                Self::class_issue(organization, class, &who, shares)?;
+            }
+            Self::deposit_event(RawEvent::ClassSharesIssued(organization, class, who, shares));
+            Ok(())
+        }
+        #[weight = 0]
+        fn burn_class_shares(origin, organization: T::OrgId, class: ShareClass, who: T::AccountId, shares: T::Shares) -> DispatchResult {
+            let burner = ensure_signed(origin)?;
+            ensure!(!Self::id_is_available(organization), Error::<T>::OrganizationMustBeRegisteredToBurnShares);
+            let authentication: bool = Self::is_organization_supervisor(organization, &burner);
+            ensure!(authentication, Error::<T>::NotAuthorizedToBurnShares);
+
+            if class == ShareClass::Common {
+                Self::burn(organization, who.clone(), Some(shares), false)?;
+            } else {
+                Self::class_burn(organization, class, &who, shares)?;
+            }
+            Self::deposit_event(RawEvent::ClassSharesBurned(organization, class, who, shares));
+            Ok(())
+        }
+        #[weight = 0]
         fn batch_issue_shares(origin, organization: T::OrgId, new_accounts: Vec<(T::AccountId, T::Shares)>) -> DispatchResult {
             let issuer = ensure_signed(origin)?;
             // first check is that the organization exists
@@ -379,6 +736,614 @@ decl_module! {
             Self::deposit_event(RawEvent::SharesUnReserved(organization, who, amount_unreserved));
             Ok(())
         }
+        #[weight = 0]
+        fn set_share_transferability(origin, organization: T::OrgId, transferable: bool) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            ensure!(!Self::id_is_available(organization), Error::<T>::OrganizationMustBeRegisteredToSetShareTransferability);
+            let authentication: bool = Self::is_organization_supervisor(organization, &caller);
+            ensure!(authentication, Error::<T>::NotAuthorizedToSetShareTransferability);
+            <TransferableShares<T>>::insert(organization, transferable);
+            Self::deposit_event(RawEvent::ShareTransferabilitySet(organization, transferable));
+            Ok(())
+        }
+        #[weight = 0]
+        fn leave_organization(origin, organization: T::OrgId) -> DispatchResult {
+            let leaver = ensure_signed(origin)?;
+            let profile = <Members<T>>::get(organization, &leaver).ok_or(Error::<T>::CannotLeaveOrganizationIfNotMember)?;
+            ensure!(profile.is_movable(), Error::<T>::CannotMoveLockedOrReservedShares);
+            Self::burn(organization, leaver.clone(), None, false)?;
+            Self::deposit_event(RawEvent::MemberLeftOrg(organization, leaver, profile.total()));
+            Ok(())
+        }
+        #[weight = 0]
+        fn transfer_shares(origin, organization: T::OrgId, dest: T::AccountId, shares: T::Shares) -> DispatchResult {
+            let sender = ensure_signed(origin)?;
+            ensure!(<TransferableShares<T>>::get(organization), Error::<T>::SharesNotTransferableInOrganization);
+            ensure!(sender != dest, Error::<T>::CannotTransferSharesToSelf);
+            let profile = <Members<T>>::get(organization, &sender).ok_or(Error::<T>::CannotTransferSharesIfNotMember)?;
+            ensure!(profile.is_movable(), Error::<T>::CannotMoveLockedOrReservedShares);
+            ensure!(profile.total() - Self::unvested_shares(organization, &sender) >= shares, Error::<T>::CannotMoveUnvestedShares);
+            ensure!(Self::is_member_of_group(organization, &dest), Error::<T>::CannotTransferSharesToNonMember);
+            // check both sides before moving shares, total issuance is unchanged
+            T::ShareChangeHandler::can_change_shares(organization, &dest)?;
+            Self::burn(organization, sender.clone(), Some(shares), true)?;
+            Self::issue(organization, dest.clone(), shares, true)?;
+            Self::deposit_event(RawEvent::SharesTransferred(organization, sender, dest, shares));
+            Ok(())
+        }
+        #[weight = 0]
+        fn set_membership_shares(origin, organization: T::OrgId, shares: T::Shares) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            ensure!(!Self::id_is_available(organization), Error::<T>::OrganizationMustBeRegisteredToSetMembershipShares);
+            let authentication: bool = Self::is_organization_supervisor(organization, &caller);
+            ensure!(authentication, Error::<T>::NotAuthorizedToSetMembershipShares);
+            <MembershipShares<T>>::insert(organization, shares);
+            Self::deposit_event(RawEvent::MembershipSharesSet(organization, shares));
+            Ok(())
+        }
+        #[weight = 0]
+        fn apply_for_membership(origin, organization: T::OrgId, application: T::IpfsReference, deposit: Option<BalanceOf<T>>) -> DispatchResult {
+            let applicant = ensure_signed(origin)?;
+            ensure!(!Self::id_is_available(organization), Error::<T>::OrganizationMustExistToApplyForMembership);
+            ensure!(!Self::is_member_of_group(organization, &applicant), Error::<T>::CannotApplyForMembershipIfAlreadyMember);
+            ensure!(<MembershipApplications<T>>::get(organization, &applicant).is_none(), Error::<T>::MembershipApplicationAlreadyExists);
+            let application_count = <MembershipApplicationCount<T>>::get(organization);
+            ensure!(application_count < T::MaxMembershipApplications::get(), Error::<T>::TooManyMembershipApplications);
+            let deposit = deposit.unwrap_or_else(T::MembershipDeposit::get);
+            ensure!(deposit >= T::MembershipDeposit::get(), Error::<T>::MembershipDepositBelowMinimum);
+            T::MembershipCurrency::reserve(&applicant, deposit)?;
+            let shares = <MembershipShares<T>>::get(organization).unwrap_or_else(|| 1u32.into());
+            let (vote_id, deadline) = Self::open_member_vote(organization, Some(application.clone()))?;
+            <MembershipApplications<T>>::insert(organization, &applicant, MembershipApplication::new(application.clone(), shares, deposit, vote_id, deadline));
+            <MembershipApplicationCount<T>>::insert(organization, application_count + 1u32);
+            Self::deposit_event(RawEvent::MembershipApplied(applicant, organization, application, vote_id));
+            Ok(())
+        }
+        #[weight = 0]
+        fn poll_membership_application(origin, organization: T::OrgId, applicant: T::AccountId) -> DispatchResult {
+            let _ = ensure_signed(origin)?;
+            let application = <MembershipApplications<T>>::get(organization, &applicant).ok_or(Error::<T>::NoMembershipApplication)?;
+            let outcome = T::OrgVote::get_vote_outcome(application.vote_id())?;
+            let now = system::Module::<T>::block_number();
+            match outcome {
+                VoteOutcome::Approved => {
+                    Self::issue(organization, applicant.clone(), application.shares(), false)?;
+                    Self::remove_membership_application(organization, &applicant);
+                    T::MembershipCurrency::unreserve(&applicant, application.deposit());
+                    Self::deposit_event(RawEvent::MembershipApplicationAccepted(organization, applicant, application.shares()));
+                }
+                VoteOutcome::NotStarted | VoteOutcome::Voting if now <= application.deadline() => {
+                    return Err(Error::<T>::MembershipApplicationVoteUndecided.into())
+                }
+                _ => {
+                    Self::remove_membership_application(organization, &applicant);
+                    if outcome == VoteOutcome::Rejected {
+                        // the deposit of rejected applications is burned to deter spam
+                        let _ = T::MembershipCurrency::slash_reserved(&applicant, application.deposit());
+                    } else {
+                        // expired or cancelled votes did not reject the applicant
+                        T::MembershipCurrency::unreserve(&applicant, application.deposit());
+                    }
+                    Self::deposit_event(RawEvent::MembershipApplicationRejected(organization, applicant, application.vote_id()));
+                }
+            }
+            Ok(())
+        }
+        #[weight = 0]
+        fn withdraw_membership_application(origin, organization: T::OrgId) -> DispatchResult {
+            let applicant = ensure_signed(origin)?;
+            let application = <MembershipApplications<T>>::get(organization, &applicant).ok_or(Error::<T>::NoMembershipApplication)?;
+            let outcome = T::OrgVote::get_vote_outcome(application.vote_id())?;
+            let now = system::Module::<T>::block_number();
+            // only undecided applications may be withdrawn, their vote is cancelled with them
+            match outcome {
+                VoteOutcome::NotStarted | VoteOutcome::Voting if now <= application.deadline() => {
+                    T::OrgVote::cancel_vote(application.vote_id())?;
+                }
+                _ => return Err(Error::<T>::CannotWithdrawDecidedMembershipApplication.into()),
+            }
+            Self::remove_membership_application(organization, &applicant);
+            T::MembershipCurrency::unreserve(&applicant, application.deposit());
+            Self::deposit_event(RawEvent::MembershipApplicationWithdrawn(organization, applicant));
+            Ok(())
+        }
+        #[weight = 0]
+        fn invite_member(origin, organization: T::OrgId, invitee: T::AccountId, shares: T::Shares) -> DispatchResult {
+            let inviter = ensure_signed(origin)?;
+            ensure!(!Self::id_is_available(organization), Error::<T>::OrganizationMustExistToInviteMember);
+            let authentication: bool = Self::is_organization_supervisor(organization, &inviter);
+            ensure!(authentication, Error::<T>::NotAuthorizedToInviteMember);
+            ensure!(!Self::is_member_of_group(organization, &invitee), Error::<T>::CannotInviteExistingMember);
+            // a new invitation replaces any pending invitation
+            <Invitations<T>>::insert(organization, &invitee, shares);
+            Self::deposit_event(RawEvent::MemberInvited(organization, invitee, shares));
+            Ok(())
+        }
+        #[weight = 0]
+        fn accept_invitation(origin, organization: T::OrgId) -> DispatchResult {
+            let invitee = ensure_signed(origin)?;
+            let shares = <Invitations<T>>::get(organization, &invitee).ok_or(Error::<T>::NoInvitationToAccept)?;
+            Self::issue(organization, invitee.clone(), shares, false)?;
+            <Invitations<T>>::remove(organization, &invitee);
+            Self::deposit_event(RawEvent::InvitationAccepted(organization, invitee, shares));
+            Ok(())
+        }
+        #[weight = 0]
+        fn decline_invitation(origin, organization: T::OrgId) -> DispatchResult {
+            let invitee = ensure_signed(origin)?;
+            <Invitations<T>>::take(organization, &invitee).ok_or(Error::<T>::NoInvitationToAccept)?;
+            Self::deposit_event(RawEvent::InvitationDeclined(organization, invitee));
+            Ok(())
+        }
+        #[weight = 0]
+        fn propose_vesting_revocation(origin, organization: T::OrgId, who: T::AccountId) -> DispatchResult {
+            let proposer = ensure_signed(origin)?;
+            let authentication: bool = Self::is_organization_supervisor(organization, &proposer);
+            ensure!(authentication, Error::<T>::NotAuthorizedToProposeVestingRevocation);
+            ensure!(<VestingSchedules<T>>::get(organization, &who).is_some(), Error::<T>::NoVestingScheduleToRevoke);
+            ensure!(<VestingRevocations<T>>::get(organization, &who).is_none(), Error::<T>::VestingRevocationAlreadyProposed);
+            let (vote_id, deadline) = Self::open_member_vote(organization, None)?;
+            <VestingRevocations<T>>::insert(organization, &who, (vote_id, deadline));
+            Self::deposit_event(RawEvent::VestingRevocationProposed(proposer, organization, who, vote_id));
+            Ok(())
+        }
+        #[weight = 0]
+        fn poll_vesting_revocation(origin, organization: T::OrgId, who: T::AccountId) -> DispatchResult {
+            let _ = ensure_signed(origin)?;
+            let (vote_id, deadline) = <VestingRevocations<T>>::get(organization, &who).ok_or(Error::<T>::NoVestingRevocationProposed)?;
+            let outcome = T::OrgVote::get_vote_outcome(vote_id)?;
+            let now = system::Module::<T>::block_number();
+            match outcome {
+                VoteOutcome::Approved => {
+                    // the vested shares are kept, the unvested remainder is burned even if the member locked or reserved its shares
+                    let unvested = Self::unvested_shares(organization, &who);
+                    if !unvested.is_zero() {
+                        Self::force_burn(organization, who.clone(), Some(unvested), false)?;
+                    }
+                    <VestingRevocations<T>>::remove(organization, &who);
+                    <VestingSchedules<T>>::remove(organization, &who);
+                    Self::deposit_event(RawEvent::VestingRevoked(organization, who, unvested));
+                }
+                VoteOutcome::NotStarted | VoteOutcome::Voting if now <= deadline => {
+                    return Err(Error::<T>::VestingRevocationVoteUndecided.into())
+                }
+                _ => {
+                    <VestingRevocations<T>>::remove(organization, &who);
+                    Self::deposit_event(RawEvent::VestingRevocationFailed(organization, who, vote_id));
+                }
+            }
+            Ok(())
+        }
+        #[weight = 0]
+        fn dissolve_organization(origin, organization: T::OrgId, recursive: bool) -> DispatchResult {
+            let proposer = ensure_signed(origin)?;
+            let org_state = <OrganizationStates<T>>::get(organization).ok_or(Error::<T>::OrganizationMustExistToDissolve)?;
+            // the supervisor of the organization or of its parent organization may propose its dissolution
+            let authentication: bool = Self::is_organization_supervisor(organization, &proposer)
+                || org_state.parent().map(|parent| Self::is_organization_supervisor(parent, &proposer)).unwrap_or(false);
+            ensure!(authentication, Error::<T>::NotAuthorizedToDissolveOrganization);
+            ensure!(recursive || Self::sub_organizations(organization).is_empty(), Error::<T>::CannotDissolveOrganizationWithSubOrganizationsUnlessRecursive);
+            ensure!(<OrganizationDissolutions<T>>::get(organization).is_none(), Error::<T>::DissolutionAlreadyProposed);
+            let (vote_id, deadline) = Self::open_member_vote(organization, None)?;
+            <OrganizationDissolutions<T>>::insert(organization, OrganizationDissolution::new(proposer.clone(), recursive, vote_id, deadline));
+            Self::deposit_event(RawEvent::DissolutionProposed(proposer, organization, recursive, vote_id));
+            Ok(())
+        }
+        #[weight = 0]
+        fn poll_dissolution(origin, organization: T::OrgId) -> DispatchResult {
+            let _ = ensure_signed(origin)?;
+            let dissolution = <OrganizationDissolutions<T>>::get(organization).ok_or(Error::<T>::NoDissolutionProposed)?;
+            let outcome = T::OrgVote::get_vote_outcome(dissolution.vote_id())?;
+            let now = system::Module::<T>::block_number();
+            match outcome {
+                VoteOutcome::Approved => {
+                    ensure!(dissolution.recursive() || Self::sub_organizations(organization).is_empty(), Error::<T>::CannotDissolveOrganizationWithSubOrganizationsUnlessRecursive);
+                    // the approved vote is closed so that it does not block the removal, it is already closed if a previous poll failed to remove the organization
+                    let _ = T::OrgVote::close_vote_early(dissolution.vote_id());
+                    // so are the votes of pending membership applications
+                    Self::cancel_membership_applications(organization);
+                    if dissolution.recursive() {
+                        Self::descendants(organization).into_iter().for_each(Self::cancel_membership_applications);
+                    }
+                    let removed = if dissolution.recursive() {
+                        Self::recursive_remove_organization(organization)?
+                    } else {
+                        Self::remove_organization(organization)?;
+                        vec![organization]
+                    };
+                    Self::deposit_event(RawEvent::OrganizationDissolved(dissolution.proposer(), organization, removed));
+                }
+                VoteOutcome::NotStarted | VoteOutcome::Voting if now <= dissolution.deadline() => {
+                    return Err(Error::<T>::DissolutionVoteUndecided.into())
+                }
+                _ => {
+                    <OrganizationDissolutions<T>>::remove(organization);
+                    Self::deposit_event(RawEvent::DissolutionFailed(organization, dissolution.vote_id()));
+                }
+            }
+            Ok(())
+        }
+        #[weight = 0]
+        fn propose_supervisor_change(origin, organization: T::OrgId, candidate: T::AccountId) -> DispatchResult {
+            let proposer = ensure_signed(origin)?;
+            ensure!(Self::is_member_of_group(organization, &candidate), Error::<T>::SupervisorCandidateMustBeMember);
+            if let Some(term) = <SupervisorTerms<T>>::get(organization) {
+                // term limits apply to consecutive terms of the same supervisor
+                ensure!(
+                    term.supervisor() != candidate || term.consecutive_terms() < T::MaxConsecutiveSupervisorTerms::get(),
+                    Error::<T>::SupervisorExceedsMaxConsecutiveTerms
+                );
+            }
+            let vote_id = Self::open_supervisor_vote(&proposer, organization, Some(candidate.clone()))?;
+            Self::deposit_event(RawEvent::SupervisorChangeProposed(proposer, organization, Some(candidate), vote_id));
+            Ok(())
+        }
+        #[weight = 0]
+        fn remove_supervisor(origin, organization: T::OrgId) -> DispatchResult {
+            let proposer = ensure_signed(origin)?;
+            let vote_id = Self::open_supervisor_vote(&proposer, organization, None)?;
+            Self::deposit_event(RawEvent::SupervisorChangeProposed(proposer, organization, None, vote_id));
+            Ok(())
+        }
+        #[weight = 0]
+        fn poll_supervisor_change(origin, organization: T::OrgId) -> DispatchResult {
+            let _ = ensure_signed(origin)?;
+            let change = <SupervisorChanges<T>>::get(organization).ok_or(Error::<T>::NoSupervisorChangeProposed)?;
+            let outcome = T::OrgVote::get_vote_outcome(change.vote_id())?;
+            let now = system::Module::<T>::block_number();
+            match outcome {
+                VoteOutcome::Approved => {
+                    <SupervisorChanges<T>>::remove(organization);
+                    if let Some(supervisor) = change.candidate() {
+                        let ends = now + T::SupervisorTermLength::get();
+                        let term = if let Some(old_term) = <SupervisorTerms<T>>::get(organization) {
+                            old_term.next(supervisor.clone(), ends)
+                        } else {
+                            SupervisorTerm::new(supervisor.clone(), 1u32, ends)
+                        };
+                        Self::put_organization_supervisor(organization, supervisor.clone())?;
+                        <SupervisorTerms<T>>::insert(organization, term);
+                        Self::deposit_event(RawEvent::SupervisorElected(organization, supervisor, ends));
+                    } else {
+                        let old_supervisor = <OrganizationStates<T>>::get(organization).and_then(|org_state| org_state.sudo());
+                        Self::clear_organization_supervisor(organization)?;
+                        <SupervisorTerms<T>>::remove(organization);
+                        Self::deposit_event(RawEvent::SupervisorRemoved(organization, old_supervisor));
+                    }
+                }
+                VoteOutcome::NotStarted | VoteOutcome::Voting if now <= change.deadline() => {
+                    return Err(Error::<T>::SupervisorChangeVoteUndecided.into())
+                }
+                _ => {
+                    <SupervisorChanges<T>>::remove(organization);
+                    Self::deposit_event(RawEvent::SupervisorChangeFailed(organization, change.vote_id()));
+                }
+            }
+            Ok(())
+        }
+        #[weight = 0]
+        fn propose_constitution_amendment(origin, organization: T::OrgId, constitution: T::IpfsReference) -> DispatchResult {
+            let proposer = ensure_signed(origin)?;
+            ensure!(!Self::id_is_available(organization), Error::<T>::OrganizationMustExistToAmendConstitution);
+            ensure!(Self::is_member_of_group(organization, &proposer), Error::<T>::NotAuthorizedToProposeConstitutionAmendment);
+            ensure!(<ConstitutionAmendments<T>>::get(organization).is_none(), Error::<T>::ConstitutionAmendmentAlreadyProposed);
+            let (vote_id, deadline) = Self::open_member_vote(organization, Some(constitution.clone()))?;
+            <ConstitutionAmendments<T>>::insert(organization, ConstitutionAmendment::new(constitution.clone(), vote_id, deadline));
+            Self::deposit_event(RawEvent::ConstitutionAmendmentProposed(proposer, organization, constitution, vote_id));
+            Ok(())
+        }
+        #[weight = 0]
+        fn poll_constitution_amendment(origin, organization: T::OrgId) -> DispatchResult {
+            let _ = ensure_signed(origin)?;
+            let amendment = <ConstitutionAmendments<T>>::get(organization).ok_or(Error::<T>::NoConstitutionAmendmentProposed)?;
+            let outcome = T::OrgVote::get_vote_outcome(amendment.vote_id())?;
+            let now = system::Module::<T>::block_number();
+            match outcome {
+                VoteOutcome::Approved => {
+                    let org_state = <OrganizationStates<T>>::get(organization).ok_or(Error::<T>::OrganizationMustExistToAmendConstitution)?;
+                    <ConstitutionAmendments<T>>::remove(organization);
+                    <ConstitutionHistory<T>>::mutate(organization, |history| history.push((org_state.constitution(), now)));
+                    <OrganizationStates<T>>::insert(organization, org_state.set_constitution(amendment.constitution()));
+                    Self::deposit_event(RawEvent::ConstitutionAmended(organization, amendment.constitution()));
+                }
+                VoteOutcome::NotStarted | VoteOutcome::Voting if now <= amendment.deadline() => {
+                    return Err(Error::<T>::ConstitutionAmendmentVoteUndecided.into())
+                }
+                _ => {
+                    <ConstitutionAmendments<T>>::remove(organization);
+                    Self::deposit_event(RawEvent::ConstitutionAmendmentFailed(organization, amendment.vote_id()));
+                }
+            }
+            Ok(())
+        }
+    }
+}
+
+impl<T: Trait> Module<T> {
+    /// Opens a member vote on a change of the organization's supervisor
+    fn open_supervisor_vote(
+        proposer: &T::AccountId,
+        organization: T::OrgId,
+        candidate: Option<T::AccountId>,
+    ) -> Result<T::OrgVoteId, DispatchError> {
+        ensure!(
+            !Self::id_is_available(organization),
+            Error::<T>::OrganizationMustExistToChangeSupervisor
+        );
+        ensure!(
+            Self::is_member_of_group(organization, proposer),
+            Error::<T>::NotAuthorizedToProposeSupervisorChange
+        );
+        ensure!(
+            <SupervisorChanges<T>>::get(organization).is_none(),
+            Error::<T>::SupervisorChangeAlreadyProposed
+        );
+        let (vote_id, deadline) = Self::open_member_vote(organization, None)?;
+        <SupervisorChanges<T>>::insert(
+            organization,
+            SupervisorChange::new(candidate, vote_id, deadline),
+        );
+        Ok(vote_id)
+    }
+    /// Opens a vote of all members which passes or fails with a majority of shares, returns the vote and its deadline
+    fn open_member_vote(
+        organization: T::OrgId,
+        topic: Option<T::IpfsReference>,
+    ) -> Result<(T::OrgVoteId, T::BlockNumber), DispatchError> {
+        let majority: T::Shares =
+            Self::class_issuance(organization, T::SignalShareClass::get())
+                / 2u32.into()
+                + 1u32.into();
+        let duration = T::OrgVoteDuration::get();
+        let vote_id = T::OrgVote::open_vote(
+            topic,
+            organization,
+            majority,
+            Some(majority),
+            Some(duration),
+        )?;
+        let deadline = system::Module::<T>::block_number() + duration;
+        Ok((vote_id, deadline))
+    }
+    /// Removes a decided or withdrawn membership application
+    fn remove_membership_application(
+        organization: T::OrgId,
+        applicant: &T::AccountId,
+    ) {
+        <MembershipApplications<T>>::remove(organization, applicant);
+        <MembershipApplicationCount<T>>::mutate(organization, |count| {
+            *count = count.saturating_sub(1u32)
+        });
+    }
+    /// Cancels the votes of the pending membership applications to the
+    /// organization and returns their deposits, votes that already closed
+    /// cannot be cancelled but do not block the removal of the organization
+    fn cancel_membership_applications(organization: T::OrgId) {
+        for (applicant, application) in
+            <MembershipApplications<T>>::iter_prefix(organization)
+        {
+            let _ = T::OrgVote::cancel_vote(application.vote_id());
+            T::MembershipCurrency::unreserve(&applicant, application.deposit());
+        }
+        <MembershipApplications<T>>::remove_prefix(organization);
+        <MembershipApplicationCount<T>>::remove(organization);
+    }
+    /// Burns the shares without checking that they are movable, only used
+    /// to burn shares that the member never controlled i.e. unvested shares
+    fn force_burn(
+        organization: T::OrgId,
+        old_owner: T::AccountId,
+        amount: Option<T::Shares>,
+        batch: bool,
+    ) -> DispatchResult {
+        T::ShareChangeHandler::can_change_shares(organization, &old_owner)?;
+        let old_profile = <Members<T>>::get(organization, &old_owner)
+            .ok_or(Error::<T>::NotEnoughSharesToSatisfyBurnRequest)?;
+        let old_issuance = <TotalIssuance<T>>::get(organization);
+        let amt_to_burn = if let Some(specific_amt) = amount {
+            ensure!(
+                old_profile.total() >= specific_amt,
+                Error::<T>::NotEnoughSharesToSatisfyBurnRequest
+            );
+            specific_amt
+        } else {
+            old_profile.total()
+        };
+        ensure!(
+            old_issuance >= amt_to_burn,
+            Error::<T>::CannotBurnMoreThanTotalIssuance
+        );
+        if !batch {
+            let new_issuance = old_issuance - amt_to_burn;
+            <TotalIssuance<T>>::insert(organization, new_issuance);
+        }
+        let new_profile = old_profile.subtract_shares(amt_to_burn);
+        let new_shares = new_profile.total();
+        if new_profile.is_zero() {
+            // leave the group
+            <Members<T>>::remove(organization, &old_owner);
+            <VestingSchedules<T>>::remove(organization, &old_owner);
+            <MemberCount<T>>::mutate(organization, |count| {
+                *count = count.saturating_sub(1u32)
+            });
+        } else {
+            <Members<T>>::insert(organization, &old_owner, new_profile);
+        }
+        T::ShareChangeHandler::on_shares_changed(
+            organization,
+            &old_owner,
+            new_shares,
+        )
+    }
+    /// The unvested shares of `who`, never more than the shares it holds
+    pub fn unvested_shares(
+        organization: T::OrgId,
+        who: &T::AccountId,
+    ) -> T::Shares {
+        if let Some(schedule) = <VestingSchedules<T>>::get(organization, who) {
+            let held = <Members<T>>::get(organization, who)
+                .map(|profile| profile.total())
+                .unwrap_or_else(T::Shares::zero);
+            let unvested =
+                schedule.unvested(system::Module::<T>::block_number());
+            if unvested < held {
+                unvested
+            } else {
+                held
+            }
+        } else {
+            T::Shares::zero()
+        }
+    }
+    /// The unvested shares of all members of the organization
+    pub fn total_unvested_shares(organization: T::OrgId) -> T::Shares {
+        <VestingSchedules<T>>::iter_prefix(organization)
+            .fold(T::Shares::zero(), |total, (who, _)| {
+                total + Self::unvested_shares(organization, &who)
+            })
+    }
+    /// Issues shares of a class other than `ShareClass::Common`, holders need not be members
+    fn class_issue(
+        organization: T::OrgId,
+        class: ShareClass,
+        who: &T::AccountId,
+        amount: T::Shares,
+    ) -> DispatchResult {
+        T::ShareChangeHandler::can_change_shares(organization, who)?;
+        let key = (organization, class);
+        if !<ClassShares<T>>::contains_key(key, who) {
+            let new_count = <ClassHolderCount<T>>::get(key) + 1u32;
+            ensure!(
+                new_count <= T::MaxMembers::get(),
+                Error::<T>::ClassHolderCountWouldExceedMaxMembers
+            );
+            <ClassHolderCount<T>>::insert(key, new_count);
+        }
+        let new_issuance = <ClassIssuance<T>>::get(key)
+            .checked_add(&amount)
+            .ok_or(Error::<T>::IssuanceWouldOverflowShares)?;
+        <ClassIssuance<T>>::insert(key, new_issuance);
+        <ClassShares<T>>::mutate(key, who, |shares| *shares += amount);
+        T::ShareChangeHandler::on_shares_changed(
+            organization,
+            who,
+            Self::class_shares(organization, class, who),
+        )
+    }
+    /// Burns shares of a class other than `ShareClass::Common`
+    fn class_burn(
+        organization: T::OrgId,
+        class: ShareClass,
+        who: &T::AccountId,
+        amount: T::Shares,
+    ) -> DispatchResult {
+        T::ShareChangeHandler::can_change_shares(organization, who)?;
+        let key = (organization, class);
+        let new_shares =
+            <ClassShares<T>>::get(key, who)
+                .checked_sub(&amount)
+                .ok_or(Error::<T>::NotEnoughClassSharesToSatisfyBurnRequest)?;
+        <ClassIssuance<T>>::mutate(key, |issuance| *issuance -= amount);
+        if new_shares.is_zero() {
+            <ClassShares<T>>::remove(key, who);
+            <ClassHolderCount<T>>::mutate(key, |count| {
+                *count = count.saturating_sub(1u32)
+            });
+        } else {
+            <ClassShares<T>>::insert(key, who, new_shares);
+        }
+        T::ShareChangeHandler::on_shares_changed(
+            organization,
+            who,
+            Self::class_shares(organization, class, who),
+        )
+    }
+    /// Elected supervisors lose their permissions when their term ends
+    fn supervisor_term_ended(organization: T::OrgId) -> bool {
+        <SupervisorTerms<T>>::get(organization)
+            .map(|term| term.ends() < system::Module::<T>::block_number())
+            .unwrap_or(false)
+    }
+    /// Builds the `MemberCount` index for chains with `Members` written before the index existed
+    pub fn migrate_member_count() -> Weight {
+        if <StorageVersion>::get() != Releases::V1 {
+            return 0
+        }
+        let mut reads: Weight = 1;
+        let mut counts: Vec<(T::OrgId, u32)> = Vec::new();
+        for (org, _, _) in <Members<T>>::iter() {
+            reads += 1;
+            if let Some(count) = counts.iter_mut().find(|(o, _)| *o == org) {
+                count.1 += 1;
+            } else {
+                counts.push((org, 1u32));
+            }
+        }
+        let writes = counts.len() as Weight + 1;
+        counts
+            .into_iter()
+            .for_each(|(org, count)| <MemberCount<T>>::insert(org, count));
+        <StorageVersion>::put(Releases::V2);
+        T::DbWeight::get().reads_writes(reads, writes)
+    }
+    /// Builds the `SubOrganizations` index for chains with organizations registered before the index existed
+    pub fn migrate_sub_organizations() -> Weight {
+        if <StorageVersion>::get() != Releases::V2 {
+            return 0
+        }
+        let mut reads: Weight = 1;
+        let mut children: Vec<(T::OrgId, Vec<T::OrgId>)> = Vec::new();
+        for (org, org_state) in <OrganizationStates<T>>::iter() {
+            reads += 1;
+            if let Some(parent) = org_state.parent() {
+                if let Some(siblings) =
+                    children.iter_mut().find(|(p, _)| *p == parent)
+                {
+                    siblings.1.push(org);
+                } else {
+                    children.push((parent, vec![org]));
+                }
+            }
+        }
+        let writes = children.len() as Weight + 1;
+        children.into_iter().for_each(|(parent, mut sub_orgs)| {
+            sub_orgs.sort();
+            <SubOrganizations<T>>::insert(parent, sub_orgs)
+        });
+        <StorageVersion>::put(Releases::V3);
+        T::DbWeight::get().reads_writes(reads, writes)
+    }
+}
+
+impl<T: Trait> OrganizationHierarchy<T::OrgId> for Module<T> {
+    fn parent_organization(org: T::OrgId) -> Option<T::OrgId> {
+        <OrganizationStates<T>>::get(org)
+            .and_then(|org_state| org_state.parent())
+    }
+    fn sub_organizations(org: T::OrgId) -> Vec<T::OrgId> {
+        <SubOrganizations<T>>::get(org)
+    }
+    fn ancestors(org: T::OrgId) -> Vec<T::OrgId> {
+        let mut ancestors = Vec::new();
+        let mut next = Self::parent_organization(org);
+        while let Some(parent) = next {
+            ancestors.push(parent);
+            next = Self::parent_organization(parent);
+        }
+        ancestors
+    }
+    fn descendants(org: T::OrgId) -> Vec<T::OrgId> {
+        let mut descendants = <SubOrganizations<T>>::get(org);
+        let mut next = 0usize;
+        while next < descendants.len() {
+            let children = <SubOrganizations<T>>::get(descendants[next]);
+            descendants.extend(children);
+            next += 1;
+        }
+        descendants
+    }
+    fn is_ancestor(ancestor: T::OrgId, org: T::OrgId) -> bool {
+        Self::ancestors(org).contains(&ancestor)
     }
 }
 
@@ -411,7 +1376,7 @@ impl<T: Trait> OrganizationSupervisorPermissions<T::OrgId, T::AccountId>
 {
     fn is_organization_supervisor(org: T::OrgId, who: &T::AccountId) -> bool {
         if let Some(state) = <OrganizationStates<T>>::get(org) {
-            return state.is_sudo(who)
+            return state.is_sudo(who) && !Self::supervisor_term_ended(org)
         }
         false
     }
@@ -490,8 +1455,16 @@ impl<T: Trait> RegisterOrganization<T::OrgId, T::AccountId, T::IpfsReference>
         supervisor: Option<T::AccountId>,
         value_constitution: T::IpfsReference,
     ) -> Result<T::OrgId, DispatchError> {
+        ensure!(
+            !Self::id_is_available(parent_id),
+            Error::<T>::ParentOrganizationMustExistToRegisterSubOrganization
+        );
+        // the parent's ancestors and the parent itself are ancestors of the new organization
+        ensure!(
+            (Self::ancestors(parent_id).len() as u32) < T::MaxOrgDepth::get(),
+            Error::<T>::SubOrganizationWouldExceedMaxOrgDepth
+        );
         let new_org_id = Self::generate_unique_id();
-        // TODO: could check if parent has a child_id and limit depth with a module constant; seems like a good idea, unbounded size not good in runtime context
         let new_organization = Self::organization_from_src(
             source,
             new_org_id,
@@ -501,6 +1474,9 @@ impl<T: Trait> RegisterOrganization<T::OrgId, T::AccountId, T::IpfsReference>
         )?;
         let new_org_count = <OrganizationCounter>::get() + 1u32;
         <OrganizationStates<T>>::insert(new_org_id, new_organization);
+        <SubOrganizations<T>>::mutate(parent_id, |sub_orgs| {
+            sub_orgs.push(new_org_id)
+        });
         <OrganizationCounter>::put(new_org_count);
         Ok(new_org_id)
     }
@@ -513,20 +1489,63 @@ impl<T: Trait> RemoveOrganization<T::OrgId> for Module<T> {
             !Self::id_is_available(id),
             Error::<T>::OrganizationCannotBeRemovedIfInputIdIsAvailable
         );
+        T::RemovalGuard::can_remove_organization(id)?;
+        if let Some(parent) = Self::parent_organization(id) {
+            <SubOrganizations<T>>::mutate(parent, |sub_orgs| {
+                sub_orgs.retain(|sub_org| *sub_org != id)
+            });
+        }
+        let ret: Vec<T::OrgId> = <SubOrganizations<T>>::take(id);
+        <OrganizationStates<T>>::remove(id);
+        <Members<T>>::remove_prefix(id);
+        <MemberCount<T>>::remove(id);
+        <TotalIssuance<T>>::remove(id);
+        <SupervisorChanges<T>>::remove(id);
+        <SupervisorTerms<T>>::remove(id);
+        <ConstitutionAmendments<T>>::remove(id);
+        <OrganizationDissolutions<T>>::remove(id);
+        <TransferableShares<T>>::remove(id);
+        <MembershipShares<T>>::remove(id);
+        for class in [ShareClass::Voting, ShareClass::Revenue].iter() {
+            <ClassShares<T>>::remove_prefix((id, *class));
+            <ClassIssuance<T>>::remove((id, *class));
+            <ClassHolderCount<T>>::remove((id, *class));
+        }
+        // pending applicants get their deposits back
+        Self::cancel_membership_applications(id);
+        <VestingSchedules<T>>::remove_prefix(id);
+        <VestingRevocations<T>>::remove_prefix(id);
+        <Invitations<T>>::remove_prefix(id);
         let new_org_count = <OrganizationCounter>::get().saturating_sub(1u32);
         <OrganizationCounter>::put(new_org_count);
-        let ret: Vec<T::OrgId> = <OrganizationStates<T>>::iter()
-            .filter(|(_, org_state)| (*org_state).parent() == Some(id))
-            .map(|(child_id, _)| child_id)
-            .collect::<Vec<_>>();
+        T::RemovalGuard::on_organization_removed(id);
         if !ret.is_empty() {
             Ok(Some(ret))
         } else {
             Ok(None)
         }
     }
-    fn recursive_remove_organization(_id: T::OrgId) -> DispatchResult {
-        todo!()
+    fn recursive_remove_organization(
+        id: T::OrgId,
+    ) -> Result<Vec<T::OrgId>, DispatchError> {
+        ensure!(
+            !Self::id_is_available(id),
+            Error::<T>::OrganizationCannotBeRemovedIfInputIdIsAvailable
+        );
+        let mut sub_tree = vec![id];
+        sub_tree.extend(Self::descendants(id));
+        // nothing is removed unless every organization in the sub-tree can be removed
+        sub_tree
+            .iter()
+            .map(|org| T::RemovalGuard::can_remove_organization(*org))
+            .collect::<DispatchResult>()?;
+        // remove sub-organizations before their parents
+        sub_tree.reverse();
+        sub_tree
+            .iter()
+            .map(|org| Self::remove_organization(*org).map(|_| ()))
+            .collect::<DispatchResult>()?;
+        Ok(sub_tree)
     }
 }
 
@@ -534,10 +1553,8 @@ impl<T: Trait> GetGroup<T::OrgId, T::AccountId> for Module<T> {
     fn get_group(organization: T::OrgId) -> Option<OrderedSet<T::AccountId>> {
         if !Self::id_is_available(organization) {
             Some(
-                // is this the same performance as a get() with Key=Vec<AccountId>
-                <Members<T>>::iter()
-                    .filter(|(org, _, _)| *org == organization)
-                    .map(|(_, account, _)| account)
+                <Members<T>>::iter_prefix(organization)
+                    .map(|(account, _)| account)
                     .collect::<Vec<_>>()
                     .into(),
             )
@@ -569,10 +1586,8 @@ impl<T: Trait> ShareInformation<T::OrgId, T::AccountId, T::Shares>
     ) -> Option<Self::Genesis> {
         if !Self::id_is_available(organization) {
             Some(
-                // is this the same performance as a get() with Key=Vec<AccountId>
-                <Members<T>>::iter()
-                    .filter(|(org, _, _)| *org == organization)
-                    .map(|(_, account, profile)| (account, profile.total()))
+                <Members<T>>::iter_prefix(organization)
+                    .map(|(account, profile)| (account, profile.total()))
                     .collect::<Vec<(T::AccountId, T::Shares)>>()
                     .into(),
             )
@@ -581,6 +1596,65 @@ impl<T: Trait> ShareInformation<T::OrgId, T::AccountId, T::Shares>
         }
     }
 }
+impl<T: Trait> ShareClassInformation<T::OrgId, T::AccountId, T::Shares>
+    for Module<T>
+{
+    fn class_issuance(organization: T::OrgId, class: ShareClass) -> T::Shares {
+        // unvested shares carry no weight
+        let common = <TotalIssuance<T>>::get(organization)
+            .saturating_sub(Self::total_unvested_shares(organization));
+        if class == ShareClass::Common {
+            common
+        } else {
+            common + <ClassIssuance<T>>::get((organization, class))
+        }
+    }
+    fn class_shares(
+        organization: T::OrgId,
+        class: ShareClass,
+        who: &T::AccountId,
+    ) -> T::Shares {
+        let common = <Members<T>>::get(organization, who)
+            .map(|profile| profile.total())
+            .unwrap_or_else(T::Shares::zero)
+            - Self::unvested_shares(organization, who);
+        if class == ShareClass::Common {
+            common
+        } else {
+            common + <ClassShares<T>>::get((organization, class), who)
+        }
+    }
+    fn class_ownership(
+        organization: T::OrgId,
+        class: ShareClass,
+    ) -> Option<Vec<(T::AccountId, T::Shares)>> {
+        if Self::id_is_available(organization) {
+            return None
+        }
+        let mut ownership = <Members<T>>::iter_prefix(organization)
+            .map(|(who, profile)| {
+                let vested =
+                    profile.total() - Self::unvested_shares(organization, &who);
+                (who, vested)
+            })
+            .collect::<Vec<(T::AccountId, T::Shares)>>();
+        if class != ShareClass::Common {
+            for (who, shares) in
+                <ClassShares<T>>::iter_prefix((organization, class))
+            {
+                if let Some(owner) =
+                    ownership.iter_mut().find(|(o, _)| *o == who)
+                {
+                    owner.1 += shares;
+                } else {
+                    ownership.push((who, shares));
+                }
+            }
+        }
+        ownership.retain(|(_, shares)| !shares.is_zero());
+        Some(ownership)
+    }
+}
 impl<T: Trait> ShareIssuance<T::OrgId, T::AccountId, T::Shares> for Module<T> {
     fn issue(
         organization: T::OrgId,
@@ -588,19 +1662,31 @@ impl<T: Trait> ShareIssuance<T::OrgId, T::AccountId, T::Shares> for Module<T> {
         amount: T::Shares,
         batch: bool,
     ) -> DispatchResult {
+        T::ShareChangeHandler::can_change_shares(organization, &new_owner)?;
         let new_profile = if let Some(existing_profile) =
             <Members<T>>::get(organization, &new_owner)
         {
             existing_profile.add_shares(amount)
         } else {
+            let new_count = <MemberCount<T>>::get(organization) + 1u32;
+            ensure!(
+                new_count <= T::MaxMembers::get(),
+                Error::<T>::MemberCountWouldExceedMaxMembers
+            );
+            <MemberCount<T>>::insert(organization, new_count);
             ShareProfile::new_shares(amount)
         };
         if !batch {
             let new_issuance = <TotalIssuance<T>>::get(organization) + amount;
             <TotalIssuance<T>>::insert(organization, new_issuance);
         }
-        <Members<T>>::insert(organization, new_owner, new_profile);
-        Ok(())
+        let new_shares = new_profile.total();
+        <Members<T>>::insert(organization, new_owner.clone(), new_profile);
+        T::ShareChangeHandler::on_shares_changed(
+            organization,
+            &new_owner,
+            new_shares,
+        )
     }
     fn burn(
         organization: T::OrgId,
@@ -608,34 +1694,11 @@ impl<T: Trait> ShareIssuance<T::OrgId, T::AccountId, T::Shares> for Module<T> {
         amount: Option<T::Shares>,
         batch: bool,
     ) -> DispatchResult {
-        let old_profile = <Members<T>>::get(organization, &old_owner)
-            .ok_or(Error::<T>::NotEnoughSharesToSatisfyBurnRequest)?;
-        let old_issuance = <TotalIssuance<T>>::get(organization);
-        let amt_to_burn = if let Some(specific_amt) = amount {
-            ensure!(
-                old_profile.total() >= specific_amt,
-                Error::<T>::NotEnoughSharesToSatisfyBurnRequest
-            );
-            specific_amt
-        } else {
-            old_profile.total()
-        };
-        ensure!(
-            old_issuance >= amt_to_burn,
-            Error::<T>::CannotBurnMoreThanTotalIssuance
-        );
-        if !batch {
-            let new_issuance = old_issuance - amt_to_burn;
-            <TotalIssuance<T>>::insert(organization, new_issuance);
-        }
-        let new_profile = old_profile.subtract_shares(amt_to_burn);
-        if new_profile.is_zero() {
-            // leave the group
-            <Members<T>>::remove(organization, old_owner);
-        } else {
-            <Members<T>>::insert(organization, old_owner, new_profile);
-        }
-        Ok(())
+        let movable = <Members<T>>::get(organization, &old_owner)
+            .map(|profile| profile.is_movable())
+            .unwrap_or(true);
+        ensure!(movable, Error::<T>::CannotMoveLockedOrReservedShares);
+        Self::force_burn(organization, old_owner, amount, batch)
     }
     fn batch_issue(
         organization: T::OrgId,
@@ -649,8 +1712,24 @@ impl<T: Trait> ShareIssuance<T::OrgId, T::AccountId, T::Shares> for Module<T> {
         let new_issuance = old_issuance
             .checked_add(&genesis.total())
             .ok_or(Error::<T>::IssuanceWouldOverflowShares)?;
-        genesis
-            .account_ownership()
+        let ownership = genesis.account_ownership();
+        // every issuance is checked before the first write so a failure leaves no partial batch
+        let mut new_members: Vec<&T::AccountId> = Vec::new();
+        for (member, _) in ownership.iter() {
+            T::ShareChangeHandler::can_change_shares(organization, member)?;
+            if !<Members<T>>::contains_key(organization, member)
+                && !new_members.contains(&member)
+            {
+                new_members.push(member);
+            }
+        }
+        ensure!(
+            <MemberCount<T>>::get(organization)
+                .saturating_add(new_members.len() as u32)
+                <= T::MaxMembers::get(),
+            Error::<T>::MemberCountWouldExceedMaxMembers
+        );
+        ownership
             .into_iter()
             .map(|(member, shares)| -> DispatchResult {
                 Self::issue(organization, member, shares, true)
@@ -671,8 +1750,31 @@ impl<T: Trait> ShareIssuance<T::OrgId, T::AccountId, T::Shares> for Module<T> {
         let new_issuance = old_issuance
             .checked_sub(&genesis.total())
             .ok_or(Error::<T>::IssuanceCannotGoNegative)?;
-        genesis
-            .account_ownership()
+        let ownership = genesis.account_ownership();
+        // every burn is checked before the first write so a failure leaves no partial batch
+        let mut burned: Vec<(&T::AccountId, T::Shares)> = Vec::new();
+        for (member, shares) in ownership.iter() {
+            T::ShareChangeHandler::can_change_shares(organization, member)?;
+            if let Some(total) = burned.iter_mut().find(|(m, _)| *m == member)
+            {
+                total.1 += *shares;
+            } else {
+                burned.push((member, *shares));
+            }
+        }
+        for (member, shares) in burned {
+            let profile = <Members<T>>::get(organization, member)
+                .ok_or(Error::<T>::NotEnoughSharesToSatisfyBurnRequest)?;
+            ensure!(
+                profile.total() >= shares,
+                Error::<T>::NotEnoughSharesToSatisfyBurnRequest
+            );
+            ensure!(
+                profile.is_movable(),
+                Error::<T>::CannotMoveLockedOrReservedShares
+            );
+        }
+        ownership
             .into_iter()
             .map(|(member, shares)| -> DispatchResult {
                 Self::burn(organization, member, Some(shares), true)
@@ -690,6 +1792,10 @@ impl<T: Trait> ReserveProfile<T::OrgId, T::AccountId, T::Shares> for Module<T> {
     ) -> Result<T::Shares, DispatchError> {
         let old_profile = <Members<T>>::get(organization, who)
             .ok_or(Error::<T>::CannotReserveIfMemberProfileDNE)?;
+        ensure!(
+            old_profile.is_unlocked(),
+            Error::<T>::CannotReserveLockedShares
+        );
         let amount_to_reserve = if let Some(amt) = amount {
             ensure!(
                 amt >= old_profile.total(),
@@ -738,9 +1844,7 @@ impl<T: Trait> ReserveProfile<T::OrgId, T::AccountId, T::Shares> for Module<T> {
         Ok(amount_to_unreserve)
     }
 }
-// TODO: enforce lock on other actions in this module?
-// -> burning shares is not allowed?
-// -> reserving shares is not allowed?
+// locked or reserved shares cannot be burned, left or transferred, and locked shares cannot be reserved
 impl<T: Trait> LockProfile<T::OrgId, T::AccountId> for Module<T> {
     fn lock_profile(
         organization: T::OrgId,
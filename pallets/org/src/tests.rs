@@ -2,6 +2,7 @@
 
 use super::*;
 use frame_support::{
+    assert_noop,
     assert_ok,
     impl_outer_event,
     impl_outer_origin,
@@ -14,6 +15,7 @@ use sp_runtime::{
     traits::IdentityLookup,
     Perbill,
 };
+use std::cell::RefCell;
 
 pub type AccountId = u64;
 pub type BlockNumber = u64;
@@ -29,10 +31,59 @@ mod org {
 impl_outer_event! {
     pub enum TestEvent for TestRuntime {
         system<T>,
+        pallet_balances<T>,
         org<T>,
     }
 }
 
+thread_local! {
+    static ORG_VOTE_OUTCOME: RefCell<VoteOutcome> =
+        RefCell::new(VoteOutcome::Voting);
+}
+fn set_org_vote_outcome(outcome: VoteOutcome) {
+    ORG_VOTE_OUTCOME.with(|o| *o.borrow_mut() = outcome);
+}
+/// Stands in for the vote module which depends on this module
+pub struct MockVote;
+impl OpenVote<u64, u64, u64, u32> for MockVote {
+    type VoteIdentifier = u64;
+    fn open_vote(
+        _: Option<u32>,
+        _: u64,
+        _: u64,
+        _: Option<u64>,
+        _: Option<u64>,
+    ) -> Result<u64, DispatchError> {
+        set_org_vote_outcome(VoteOutcome::Voting);
+        Ok(1)
+    }
+    fn open_unanimous_consent(
+        _: Option<u32>,
+        _: u64,
+        _: Option<u64>,
+    ) -> Result<u64, DispatchError> {
+        Ok(1)
+    }
+}
+impl GetVoteOutcome<u64> for MockVote {
+    type Outcome = VoteOutcome;
+    fn get_vote_outcome(_: u64) -> Result<VoteOutcome, DispatchError> {
+        Ok(ORG_VOTE_OUTCOME.with(|o| *o.borrow()))
+    }
+}
+impl ControlOpenVote<u64, u64> for MockVote {
+    fn extend_vote(_: u64, blocks: u64) -> Result<u64, DispatchError> {
+        Ok(blocks)
+    }
+    fn close_vote_early(_: u64) -> Result<VoteOutcome, DispatchError> {
+        Ok(ORG_VOTE_OUTCOME.with(|o| *o.borrow()))
+    }
+    fn cancel_vote(_: u64) -> DispatchResult {
+        set_org_vote_outcome(VoteOutcome::Cancelled);
+        Ok(())
+    }
+}
+
 #[derive(Clone, Eq, PartialEq)]
 pub struct TestRuntime;
 parameter_types! {
@@ -41,6 +92,15 @@ parameter_types! {
     pub const MaximumBlockLength: u32 = 2 * 1024;
     pub const AvailableBlockRatio: Perbill = Perbill::one();
     pub const ReservationLimit: u32 = 10000;
+    pub const MaxMembers: u32 = 10000;
+    pub const MaxOrgDepth: u32 = 2;
+    pub const OrgVoteDuration: u64 = 10;
+    pub const SignalShareClass: ShareClass = ShareClass::Voting;
+    pub const SupervisorTermLength: u64 = 100;
+    pub const MaxConsecutiveSupervisorTerms: u32 = 2;
+    pub const MembershipDeposit: u64 = 10;
+    pub const MaxMembershipApplications: u32 = 2;
+    pub const ExistentialDeposit: u64 = 1;
 }
 impl frame_system::Trait for TestRuntime {
     type Origin = Origin;
@@ -68,17 +128,38 @@ impl frame_system::Trait for TestRuntime {
     type OnKilledAccount = ();
     type BaseCallFilter = ();
 }
+impl pallet_balances::Trait for TestRuntime {
+    type Balance = u64;
+    type Event = TestEvent;
+    type DustRemoval = ();
+    type ExistentialDeposit = ExistentialDeposit;
+    type AccountStore = System;
+}
 impl Trait for TestRuntime {
     type Event = TestEvent;
     type IpfsReference = u32; // TODO: replace with utils_identity::Cid
     type OrgId = u64;
     type Shares = u64;
     type ReservationLimit = ReservationLimit;
+    type MaxMembers = MaxMembers;
+    type MaxOrgDepth = MaxOrgDepth;
+    type RemovalGuard = ();
+    type OrgVote = MockVote;
+    type OrgVoteId = u64;
+    type OrgVoteDuration = OrgVoteDuration;
+    type SignalShareClass = SignalShareClass;
+    type SupervisorTermLength = SupervisorTermLength;
+    type MaxConsecutiveSupervisorTerms = MaxConsecutiveSupervisorTerms;
+    type MembershipCurrency = Balances;
+    type MembershipDeposit = MembershipDeposit;
+    type MaxMembershipApplications = MaxMembershipApplications;
+    type ShareChangeHandler = ();
 }
 pub type System = system::Module<TestRuntime>;
 pub type Org = Module<TestRuntime>;
+pub type Balances = pallet_balances::Module<TestRuntime>;
 
-fn get_last_event() -> RawEvent<u64, u64, u64, u32> {
+fn get_last_event() -> RawEvent<u64, u64, u64, u32, u64, u64> {
     System::events()
         .into_iter()
         .map(|r| r.event)
@@ -93,14 +174,20 @@ fn get_last_event() -> RawEvent<u64, u64, u64, u32> {
         .unwrap()
 }
 
-fn new_test_ext() -> sp_io::TestExternalities {
+pub fn new_test_ext() -> sp_io::TestExternalities {
     let mut t = frame_system::GenesisConfig::default()
         .build_storage::<TestRuntime>()
         .unwrap();
+    pallet_balances::GenesisConfig::<TestRuntime> {
+        balances: vec![(1, 100), (2, 100), (7, 100), (8, 100), (9, 100)],
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
     GenesisConfig::<TestRuntime> {
         first_organization_supervisor: 1,
         first_organization_value_constitution: 1738,
         first_organization_flat_membership: vec![1, 2, 3, 4, 5, 6],
+        organizations: vec![],
     }
     .assimilate_storage(&mut t)
     .unwrap();
@@ -125,6 +212,53 @@ fn genesis_config_works() {
     });
 }
 
+#[test]
+fn multi_organization_genesis_works() {
+    let mut t = frame_system::GenesisConfig::default()
+        .build_storage::<TestRuntime>()
+        .unwrap();
+    GenesisConfig::<TestRuntime> {
+        first_organization_supervisor: 1,
+        first_organization_value_constitution: 1738,
+        first_organization_flat_membership: vec![],
+        organizations: vec![
+            OrganizationGenesis::new(
+                Some(1),
+                None,
+                1738,
+                OrganizationSource::Accounts(vec![1, 2, 3]),
+            ),
+            OrganizationGenesis::new(
+                None,
+                Some(1),
+                1739,
+                OrganizationSource::AccountsWeighted(vec![(1, 10), (4, 5)]),
+            ),
+        ],
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+    let mut ext: sp_io::TestExternalities = t.into();
+    ext.execute_with(|| {
+        // the first organization is skipped without members
+        assert_eq!(Org::organization_counter(), 2);
+        assert_eq!(
+            Org::organization_states(1u64).unwrap(),
+            Organization::new(Some(1), None, 1738)
+        );
+        assert_eq!(
+            Org::organization_states(2u64).unwrap(),
+            Organization::new(None, Some(1), 1739)
+        );
+        assert_eq!(Org::sub_organizations(1), vec![2]);
+        assert!(Org::is_member_of_group(1u64, &3));
+        assert!(!Org::is_member_of_group(2u64, &3));
+        assert_eq!(Org::members(2, 1).unwrap().total(), 10);
+        assert_eq!(Org::members(2, 4).unwrap().total(), 5);
+        assert_eq!(Org::total_issuance(2), 15);
+    });
+}
+
 #[test]
 fn organization_registration() {
     new_test_ext().execute_with(|| {
@@ -258,6 +392,37 @@ fn share_unlock() {
     });
 }
 
+#[test]
+fn locked_or_reserved_shares_cannot_be_burned() {
+    new_test_ext().execute_with(|| {
+        let one = Origin::signed(1);
+        assert_ok!(Org::lock_shares(one.clone(), 1, 2));
+        // locked shares cannot be reserved or burned by the supervisor
+        assert_noop!(
+            Org::reserve_shares(one.clone(), 1, 2),
+            Error::<TestRuntime>::CannotReserveLockedShares
+        );
+        assert_noop!(
+            Org::burn_shares(one.clone(), 1, 2, 1),
+            Error::<TestRuntime>::CannotMoveLockedOrReservedShares
+        );
+        assert_ok!(Org::reserve_shares(one.clone(), 1, 3));
+        assert_noop!(
+            Org::burn_shares(one.clone(), 1, 3, 1),
+            Error::<TestRuntime>::CannotMoveLockedOrReservedShares
+        );
+        // nothing in the batch is burned if one profile is locked
+        assert_noop!(
+            Org::batch_burn_shares(one.clone(), 1, vec![(4, 1), (2, 1)]),
+            Error::<TestRuntime>::CannotMoveLockedOrReservedShares
+        );
+        assert_ok!(Org::unlock_shares(one.clone(), 1, 2));
+        assert_ok!(Org::unreserve_shares(one.clone(), 1, 3));
+        assert_ok!(Org::batch_burn_shares(one, 1, vec![(2, 1), (3, 1)]));
+        assert_eq!(Org::total_issuance(1), 4);
+    });
+}
+
 #[test]
 fn share_issuance() {
     new_test_ext().execute_with(|| {
@@ -267,7 +432,7 @@ fn share_issuance() {
 
         assert_eq!(pre_shares, 1);
         // issue 10 new shares to member 1
-        assert_ok!(Org::issue_shares(one.clone(), 1, 1, 10));
+        assert_ok!(Org::issue_shares(one.clone(), 1, 1, 10, None));
 
         let post_profile = Org::members(1, 1).unwrap();
         let post_shares = post_profile.total();
@@ -285,7 +450,7 @@ fn share_burn() {
 
         assert_eq!(pre_shares, 1);
         // issue 10 new shares to 10
-        assert_ok!(Org::issue_shares(one.clone(), 1, 1, 10));
+        assert_ok!(Org::issue_shares(one.clone(), 1, 1, 10, None));
 
         let pre_pre_profile = Org::members(1, 1).unwrap();
         let pre_pre_shares = pre_pre_profile.total();
@@ -299,3 +464,785 @@ fn share_burn() {
         assert_eq!(post_shares, 6);
     });
 }
+
+#[test]
+fn member_count_index_works() {
+    new_test_ext().execute_with(|| {
+        let one = Origin::signed(1);
+        assert_eq!(Org::member_count(1), 6);
+        assert_ok!(Org::register_flat_org(
+            one.clone(),
+            Some(1),
+            None,
+            1738,
+            vec![7, 8, 9]
+        ));
+        assert_eq!(Org::member_count(2), 3);
+        assert_eq!(Org::get_group(2).unwrap().0, vec![7, 8, 9]);
+        // issuing to an existing member does not change the count
+        assert_ok!(Org::issue_shares(one.clone(), 1, 1, 10, None));
+        assert_ok!(Org::issue_shares(one.clone(), 1, 7, 10, None));
+        assert_eq!(Org::member_count(1), 7);
+        assert_ok!(Org::burn_shares(one, 1, 7, 10));
+        assert_eq!(Org::member_count(1), 6);
+        assert_eq!(Org::get_group(1).unwrap().0, vec![1, 2, 3, 4, 5, 6]);
+    });
+}
+
+#[test]
+fn batch_share_changes_are_checked_before_writing() {
+    new_test_ext().execute_with(|| {
+        let one = Origin::signed(1);
+        // only one more member fits in the organization
+        <MemberCount<TestRuntime>>::insert(1, MaxMembers::get() - 1);
+        assert_noop!(
+            Org::batch_issue_shares(
+                one.clone(),
+                1,
+                vec![(1, 5), (7, 1), (8, 1)]
+            ),
+            Error::<TestRuntime>::MemberCountWouldExceedMaxMembers
+        );
+        assert_ok!(Org::batch_issue_shares(
+            one.clone(),
+            1,
+            vec![(1, 5), (7, 1), (7, 2)]
+        ));
+        assert_eq!(Org::member_count(1), MaxMembers::get());
+        assert_eq!(Org::members(1, 7).unwrap().total(), 3);
+        assert_noop!(
+            Org::batch_burn_shares(one, 1, vec![(1, 1), (8, 1)]),
+            Error::<TestRuntime>::NotEnoughSharesToSatisfyBurnRequest
+        );
+    });
+}
+
+#[test]
+fn member_count_migration_works() {
+    new_test_ext().execute_with(|| {
+        // chains with members written before the index existed
+        <MemberCount<TestRuntime>>::remove(1);
+        <StorageVersion>::put(Releases::V1);
+        Org::migrate_member_count();
+        assert_eq!(Org::member_count(1), 6);
+        assert_eq!(<StorageVersion>::get(), Releases::V2);
+    });
+}
+
+#[test]
+fn dissolve_organization_works() {
+    new_test_ext().execute_with(|| {
+        let one = Origin::signed(1);
+        assert_ok!(Org::register_flat_org(
+            one.clone(),
+            Some(2),
+            Some(1),
+            1738,
+            vec![2, 3]
+        ));
+        assert_ok!(Org::register_flat_org(
+            one.clone(),
+            Some(3),
+            Some(2),
+            1738,
+            vec![3, 4]
+        ));
+        assert_eq!(Org::organization_counter(), 3);
+        // only the supervisor of the org or its parent may propose its dissolution
+        assert_noop!(
+            Org::dissolve_organization(Origin::signed(4), 2, true),
+            Error::<TestRuntime>::NotAuthorizedToDissolveOrganization
+        );
+        assert_noop!(
+            Org::dissolve_organization(Origin::signed(2), 2, false),
+            Error::<TestRuntime>::CannotDissolveOrganizationWithSubOrganizationsUnlessRecursive
+        );
+        assert_ok!(Org::dissolve_organization(Origin::signed(2), 2, true));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::DissolutionProposed(2, 2, true, 1)
+        );
+        assert_noop!(
+            Org::dissolve_organization(Origin::signed(1), 2, true),
+            Error::<TestRuntime>::DissolutionAlreadyProposed
+        );
+        // nothing is removed until the members approve
+        assert_noop!(
+            Org::poll_dissolution(one.clone(), 2),
+            Error::<TestRuntime>::DissolutionVoteUndecided
+        );
+        assert_eq!(Org::organization_counter(), 3);
+        assert_ok!(Org::apply_for_membership(Origin::signed(7), 3, 10, None));
+        assert_eq!(Balances::reserved_balance(7), 10);
+        set_org_vote_outcome(VoteOutcome::Approved);
+        assert_ok!(Org::poll_dissolution(one, 2));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::OrganizationDissolved(2, 2, vec![3, 2])
+        );
+        assert_eq!(Org::organization_counter(), 1);
+        assert!(Org::organization_states(2).is_none());
+        assert!(Org::organization_states(3).is_none());
+        assert!(!Org::is_member_of_group(3, &4));
+        assert_eq!(Org::member_count(2), 0);
+        assert_eq!(Org::total_issuance(3), 0);
+        assert!(Org::get_group(2).is_none());
+        assert!(Org::organization_dissolutions(2).is_none());
+        // pending membership applications are cancelled and refunded
+        assert_eq!(MockVote::get_vote_outcome(1), Ok(VoteOutcome::Cancelled));
+        assert_eq!(Balances::reserved_balance(7), 0);
+        assert!(Org::membership_applications(3, 7).is_none());
+        assert_eq!(Org::membership_application_count(3), 0);
+        // the parent organization is untouched
+        assert_eq!(Org::member_count(1), 6);
+    });
+}
+
+#[test]
+fn rejected_dissolution_keeps_organization() {
+    new_test_ext().execute_with(|| {
+        let one = Origin::signed(1);
+        assert_ok!(Org::register_flat_org(
+            one.clone(),
+            Some(2),
+            Some(1),
+            1738,
+            vec![2, 3]
+        ));
+        assert_ok!(Org::dissolve_organization(Origin::signed(2), 2, false));
+        set_org_vote_outcome(VoteOutcome::Rejected);
+        assert_ok!(Org::poll_dissolution(one.clone(), 2));
+        assert_eq!(get_last_event(), RawEvent::DissolutionFailed(2, 1));
+        assert!(Org::organization_states(2).is_some());
+        assert!(Org::organization_dissolutions(2).is_none());
+        assert_noop!(
+            Org::poll_dissolution(one, 2),
+            Error::<TestRuntime>::NoDissolutionProposed
+        );
+    });
+}
+
+#[test]
+fn supervisor_election_works() {
+    new_test_ext().execute_with(|| {
+        // only members may propose and only members may be elected
+        assert_noop!(
+            Org::propose_supervisor_change(Origin::signed(7), 1, 2),
+            Error::<TestRuntime>::NotAuthorizedToProposeSupervisorChange
+        );
+        assert_noop!(
+            Org::propose_supervisor_change(Origin::signed(2), 1, 7),
+            Error::<TestRuntime>::SupervisorCandidateMustBeMember
+        );
+        assert_ok!(Org::propose_supervisor_change(Origin::signed(2), 1, 2));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::SupervisorChangeProposed(2, 1, Some(2), 1)
+        );
+        assert_noop!(
+            Org::remove_supervisor(Origin::signed(3), 1),
+            Error::<TestRuntime>::SupervisorChangeAlreadyProposed
+        );
+        assert_noop!(
+            Org::poll_supervisor_change(Origin::signed(3), 1),
+            Error::<TestRuntime>::SupervisorChangeVoteUndecided
+        );
+        set_org_vote_outcome(VoteOutcome::Approved);
+        assert_ok!(Org::poll_supervisor_change(Origin::signed(3), 1));
+        assert_eq!(get_last_event(), RawEvent::SupervisorElected(1, 2, 101));
+        assert!(Org::is_organization_supervisor(1, &2));
+        assert!(!Org::is_organization_supervisor(1, &1));
+        // the supervisor loses its permissions when the term ends
+        System::set_block_number(102);
+        assert!(!Org::is_organization_supervisor(1, &2));
+    });
+}
+
+#[test]
+fn supervisor_term_limits_work() {
+    new_test_ext().execute_with(|| {
+        for _ in 0..2 {
+            assert_ok!(Org::propose_supervisor_change(Origin::signed(2), 1, 2));
+            set_org_vote_outcome(VoteOutcome::Approved);
+            assert_ok!(Org::poll_supervisor_change(Origin::signed(2), 1));
+        }
+        assert_eq!(Org::supervisor_terms(1).unwrap().consecutive_terms(), 2);
+        assert_noop!(
+            Org::propose_supervisor_change(Origin::signed(2), 1, 2),
+            Error::<TestRuntime>::SupervisorExceedsMaxConsecutiveTerms
+        );
+        // another candidate may be elected
+        assert_ok!(Org::propose_supervisor_change(Origin::signed(2), 1, 3));
+    });
+}
+
+#[test]
+fn supervisor_recall_works() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Org::remove_supervisor(Origin::signed(4), 1));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::SupervisorChangeProposed(4, 1, None, 1)
+        );
+        set_org_vote_outcome(VoteOutcome::Rejected);
+        assert_ok!(Org::poll_supervisor_change(Origin::signed(4), 1));
+        assert_eq!(get_last_event(), RawEvent::SupervisorChangeFailed(1, 1));
+        assert!(Org::is_organization_supervisor(1, &1));
+        assert_ok!(Org::remove_supervisor(Origin::signed(4), 1));
+        set_org_vote_outcome(VoteOutcome::Approved);
+        assert_ok!(Org::poll_supervisor_change(Origin::signed(4), 1));
+        assert_eq!(get_last_event(), RawEvent::SupervisorRemoved(1, Some(1)));
+        assert!(!Org::is_organization_supervisor(1, &1));
+    });
+}
+
+#[test]
+fn constitution_amendment_works() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Org::propose_constitution_amendment(Origin::signed(7), 1, 1739),
+            Error::<TestRuntime>::NotAuthorizedToProposeConstitutionAmendment
+        );
+        assert_ok!(Org::propose_constitution_amendment(
+            Origin::signed(2),
+            1,
+            1739
+        ));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::ConstitutionAmendmentProposed(2, 1, 1739, 1)
+        );
+        assert_noop!(
+            Org::propose_constitution_amendment(Origin::signed(3), 1, 1740),
+            Error::<TestRuntime>::ConstitutionAmendmentAlreadyProposed
+        );
+        assert_noop!(
+            Org::poll_constitution_amendment(Origin::signed(3), 1),
+            Error::<TestRuntime>::ConstitutionAmendmentVoteUndecided
+        );
+        set_org_vote_outcome(VoteOutcome::Approved);
+        System::set_block_number(5);
+        assert_ok!(Org::poll_constitution_amendment(Origin::signed(3), 1));
+        assert_eq!(get_last_event(), RawEvent::ConstitutionAmended(1, 1739));
+        assert_eq!(Org::organization_states(1).unwrap().constitution(), 1739);
+        assert_eq!(Org::constitution_history(1), vec![(1738, 5)]);
+        // undecided amendments fail after the vote deadline
+        assert_ok!(Org::propose_constitution_amendment(
+            Origin::signed(3),
+            1,
+            1740
+        ));
+        System::set_block_number(16);
+        assert_ok!(Org::poll_constitution_amendment(Origin::signed(3), 1));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::ConstitutionAmendmentFailed(1, 1)
+        );
+        assert_eq!(Org::organization_states(1).unwrap().constitution(), 1739);
+        assert_eq!(Org::constitution_history(1), vec![(1738, 5)]);
+    });
+}
+
+#[test]
+fn leave_organization_works() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Org::leave_organization(Origin::signed(7), 1),
+            Error::<TestRuntime>::CannotLeaveOrganizationIfNotMember
+        );
+        // locked shares cannot be burned by their owner
+        assert_ok!(Org::lock_shares(Origin::signed(2), 1, 2));
+        assert_noop!(
+            Org::leave_organization(Origin::signed(2), 1),
+            Error::<TestRuntime>::CannotMoveLockedOrReservedShares
+        );
+        assert_ok!(Org::leave_organization(Origin::signed(3), 1));
+        assert_eq!(get_last_event(), RawEvent::MemberLeftOrg(1, 3, 1));
+        assert!(!Org::is_member_of_group(1, &3));
+        assert_eq!(Org::total_issuance(1), 5);
+        assert_eq!(Org::member_count(1), 5);
+    });
+}
+
+#[test]
+fn transfer_shares_works() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Org::transfer_shares(Origin::signed(2), 1, 3, 1),
+            Error::<TestRuntime>::SharesNotTransferableInOrganization
+        );
+        assert_noop!(
+            Org::set_share_transferability(Origin::signed(2), 1, true),
+            Error::<TestRuntime>::NotAuthorizedToSetShareTransferability
+        );
+        assert_ok!(Org::set_share_transferability(Origin::signed(1), 1, true));
+        assert_noop!(
+            Org::transfer_shares(Origin::signed(2), 1, 7, 1),
+            Error::<TestRuntime>::CannotTransferSharesToNonMember
+        );
+        // reserved shares cannot be transferred
+        assert_ok!(Org::reserve_shares(Origin::signed(4), 1, 4));
+        assert_noop!(
+            Org::transfer_shares(Origin::signed(4), 1, 3, 1),
+            Error::<TestRuntime>::CannotMoveLockedOrReservedShares
+        );
+        assert_ok!(Org::transfer_shares(Origin::signed(2), 1, 3, 1));
+        assert_eq!(get_last_event(), RawEvent::SharesTransferred(1, 2, 3, 1));
+        assert_eq!(Org::members(1, 3).unwrap().total(), 2);
+        assert!(!Org::is_member_of_group(1, &2));
+        assert_eq!(Org::total_issuance(1), 6);
+        assert_eq!(Org::member_count(1), 5);
+    });
+}
+
+#[test]
+fn membership_application_works() {
+    new_test_ext().execute_with(|| {
+        let one = Origin::signed(1);
+        let seven = Origin::signed(7);
+        let eight = Origin::signed(8);
+        assert_noop!(
+            Org::apply_for_membership(one.clone(), 1, 10, None),
+            Error::<TestRuntime>::CannotApplyForMembershipIfAlreadyMember
+        );
+        assert_noop!(
+            Org::set_membership_shares(seven.clone(), 1, 5),
+            Error::<TestRuntime>::NotAuthorizedToSetMembershipShares
+        );
+        assert_ok!(Org::set_membership_shares(one.clone(), 1, 5));
+        assert_ok!(Org::apply_for_membership(seven.clone(), 1, 10, Some(20)));
+        assert_eq!(get_last_event(), RawEvent::MembershipApplied(7, 1, 10, 1));
+        assert_eq!(Balances::reserved_balance(7), 20);
+        assert_noop!(
+            Org::apply_for_membership(seven.clone(), 1, 11, None),
+            Error::<TestRuntime>::MembershipApplicationAlreadyExists
+        );
+        assert_noop!(
+            Org::poll_membership_application(one.clone(), 1, 7),
+            Error::<TestRuntime>::MembershipApplicationVoteUndecided
+        );
+        // acceptance issues the configured shares and returns the deposit
+        set_org_vote_outcome(VoteOutcome::Approved);
+        assert_ok!(Org::poll_membership_application(one.clone(), 1, 7));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::MembershipApplicationAccepted(1, 7, 5)
+        );
+        assert_eq!(Org::members(1, 7).unwrap().total(), 5);
+        assert_eq!(Balances::reserved_balance(7), 0);
+        assert!(Org::membership_applications(1, 7).is_none());
+        // rejection slashes the deposit without issuing shares
+        assert_ok!(Org::apply_for_membership(eight.clone(), 1, 12, Some(30)));
+        set_org_vote_outcome(VoteOutcome::Rejected);
+        assert_ok!(Org::poll_membership_application(one, 1, 8));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::MembershipApplicationRejected(1, 8, 1)
+        );
+        assert!(Org::members(1, 8).is_none());
+        assert_eq!(Balances::reserved_balance(8), 0);
+        assert_eq!(Balances::free_balance(8), 70);
+        // the deposit is at least the minimum, reserved if none is given
+        assert_noop!(
+            Org::apply_for_membership(eight.clone(), 1, 13, Some(5)),
+            Error::<TestRuntime>::MembershipDepositBelowMinimum
+        );
+        set_org_vote_outcome(VoteOutcome::Voting);
+        assert_ok!(Org::apply_for_membership(eight.clone(), 1, 13, None));
+        assert_eq!(Balances::reserved_balance(8), 10);
+        assert_ok!(Org::withdraw_membership_application(eight.clone(), 1));
+        assert_eq!(Balances::reserved_balance(8), 0);
+        assert_eq!(Org::membership_application_count(1), 0);
+        assert_noop!(
+            Org::withdraw_membership_application(eight, 1),
+            Error::<TestRuntime>::NoMembershipApplication
+        );
+    });
+}
+
+#[test]
+fn expired_membership_application_returns_deposit() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Org::apply_for_membership(
+            Origin::signed(7),
+            1,
+            10,
+            Some(20)
+        ));
+        assert_eq!(Balances::reserved_balance(7), 20);
+        // the vote expired without an outcome
+        set_org_vote_outcome(VoteOutcome::Voting);
+        System::set_block_number(12);
+        assert_ok!(Org::poll_membership_application(Origin::signed(1), 1, 7));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::MembershipApplicationRejected(1, 7, 1)
+        );
+        assert!(Org::members(1, 7).is_none());
+        assert_eq!(Balances::reserved_balance(7), 0);
+        assert_eq!(Balances::free_balance(7), 100);
+        assert_eq!(Org::membership_application_count(1), 0);
+    });
+}
+
+#[test]
+fn pending_membership_applications_are_bounded() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Org::apply_for_membership(Origin::signed(7), 1, 10, None));
+        assert_ok!(Org::apply_for_membership(Origin::signed(8), 1, 11, None));
+        assert_eq!(Org::membership_application_count(1), 2);
+        assert_noop!(
+            Org::apply_for_membership(Origin::signed(9), 1, 12, None),
+            Error::<TestRuntime>::TooManyMembershipApplications
+        );
+        assert_ok!(Org::withdraw_membership_application(Origin::signed(7), 1));
+        assert_eq!(Org::membership_application_count(1), 1);
+        assert_ok!(Org::apply_for_membership(Origin::signed(9), 1, 12, None));
+        assert_eq!(Org::membership_application_count(1), 2);
+    });
+}
+
+#[test]
+fn only_undecided_membership_applications_can_be_withdrawn() {
+    new_test_ext().execute_with(|| {
+        let seven = Origin::signed(7);
+        // withdrawal cancels the vote and returns the deposit
+        assert_ok!(Org::apply_for_membership(seven.clone(), 1, 10, Some(20)));
+        assert_eq!(Balances::reserved_balance(7), 20);
+        assert_ok!(Org::withdraw_membership_application(seven.clone(), 1));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::MembershipApplicationWithdrawn(1, 7)
+        );
+        assert_eq!(MockVote::get_vote_outcome(1), Ok(VoteOutcome::Cancelled));
+        assert_eq!(Balances::reserved_balance(7), 0);
+        assert!(Org::membership_applications(1, 7).is_none());
+        // decided applications are polled instead
+        assert_ok!(Org::apply_for_membership(seven.clone(), 1, 11, Some(20)));
+        set_org_vote_outcome(VoteOutcome::Approved);
+        assert_noop!(
+            Org::withdraw_membership_application(seven.clone(), 1),
+            Error::<TestRuntime>::CannotWithdrawDecidedMembershipApplication
+        );
+        // so are applications past their deadline
+        set_org_vote_outcome(VoteOutcome::Voting);
+        System::set_block_number(12);
+        assert_noop!(
+            Org::withdraw_membership_application(seven, 1),
+            Error::<TestRuntime>::CannotWithdrawDecidedMembershipApplication
+        );
+        assert_eq!(Balances::reserved_balance(7), 20);
+    });
+}
+
+#[test]
+fn invitation_works() {
+    new_test_ext().execute_with(|| {
+        let one = Origin::signed(1);
+        let two = Origin::signed(2);
+        let seven = Origin::signed(7);
+        assert_noop!(
+            Org::invite_member(two, 1, 7, 3),
+            Error::<TestRuntime>::NotAuthorizedToInviteMember
+        );
+        assert_noop!(
+            Org::invite_member(one.clone(), 1, 2, 3),
+            Error::<TestRuntime>::CannotInviteExistingMember
+        );
+        assert_ok!(Org::invite_member(one.clone(), 1, 7, 3));
+        assert_eq!(get_last_event(), RawEvent::MemberInvited(1, 7, 3));
+        // the invitee is not a member until it accepts
+        assert!(Org::members(1, 7).is_none());
+        assert_ok!(Org::accept_invitation(seven.clone(), 1));
+        assert_eq!(get_last_event(), RawEvent::InvitationAccepted(1, 7, 3));
+        assert_eq!(Org::members(1, 7).unwrap().total(), 3);
+        assert_noop!(
+            Org::accept_invitation(seven, 1),
+            Error::<TestRuntime>::NoInvitationToAccept
+        );
+        let eight = Origin::signed(8);
+        assert_ok!(Org::invite_member(one, 1, 8, 3));
+        assert_ok!(Org::decline_invitation(eight.clone(), 1));
+        assert_eq!(get_last_event(), RawEvent::InvitationDeclined(1, 8));
+        assert!(Org::invitations(1, 8).is_none());
+        assert_noop!(
+            Org::accept_invitation(eight, 1),
+            Error::<TestRuntime>::NoInvitationToAccept
+        );
+    });
+}
+
+#[test]
+fn organization_hierarchy_works() {
+    new_test_ext().execute_with(|| {
+        let one = Origin::signed(1);
+        assert_noop!(
+            Org::register_flat_org(one.clone(), None, Some(9), 1738, vec![2]),
+            Error::<TestRuntime>::ParentOrganizationMustExistToRegisterSubOrganization
+        );
+        for parent in 1u64..3u64 {
+            assert_ok!(Org::register_flat_org(
+                one.clone(),
+                Some(1),
+                Some(parent),
+                1738,
+                vec![2, 3]
+            ));
+        }
+        assert_ok!(Org::register_flat_org(
+            one.clone(),
+            Some(1),
+            Some(1),
+            1738,
+            vec![4]
+        ));
+        // organization 3 already has `MaxOrgDepth` ancestors
+        assert_noop!(
+            Org::register_flat_org(one, None, Some(3), 1738, vec![2]),
+            Error::<TestRuntime>::SubOrganizationWouldExceedMaxOrgDepth
+        );
+        assert_eq!(Org::sub_organizations(1), vec![2, 4]);
+        assert_eq!(Org::parent_organization(3), Some(2));
+        assert_eq!(Org::ancestors(3), vec![2, 1]);
+        assert!(Org::ancestors(1).is_empty());
+        assert_eq!(Org::descendants(1), vec![2, 4, 3]);
+        assert!(Org::is_ancestor(1, 3));
+        assert!(!Org::is_ancestor(4, 3));
+        // removal keeps the index consistent
+        assert_ok!(Org::dissolve_organization(Origin::signed(1), 2, true));
+        set_org_vote_outcome(VoteOutcome::Approved);
+        assert_ok!(Org::poll_dissolution(Origin::signed(1), 2));
+        assert_eq!(Org::sub_organizations(1), vec![4]);
+        assert!(Org::sub_organizations(2).is_empty());
+        assert_eq!(Org::descendants(1), vec![4]);
+    });
+}
+
+#[test]
+fn sub_organizations_migration_works() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Org::register_flat_org(
+            Origin::signed(1),
+            Some(1),
+            Some(1),
+            1738,
+            vec![2, 3]
+        ));
+        // chains with organizations registered before the index existed
+        <SubOrganizations<TestRuntime>>::remove(1);
+        <StorageVersion>::put(Releases::V2);
+        Org::migrate_sub_organizations();
+        assert_eq!(Org::sub_organizations(1), vec![2]);
+        assert_eq!(<StorageVersion>::get(), Releases::V3);
+    });
+}
+
+#[test]
+fn share_classes_work() {
+    new_test_ext().execute_with(|| {
+        let one = Origin::signed(1);
+        assert_noop!(
+            Org::issue_class_shares(
+                Origin::signed(2),
+                1,
+                ShareClass::Revenue,
+                7,
+                6
+            ),
+            Error::<TestRuntime>::NotAuthorizedToIssueShares
+        );
+        // revenue shares do not make the holder a member
+        assert_ok!(Org::issue_class_shares(
+            one.clone(),
+            1,
+            ShareClass::Revenue,
+            7,
+            6
+        ));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::ClassSharesIssued(1, ShareClass::Revenue, 7, 6)
+        );
+        assert!(!Org::is_member_of_group(1, &7));
+        assert_ok!(Org::issue_class_shares(
+            one.clone(),
+            1,
+            ShareClass::Voting,
+            2,
+            4
+        ));
+        // common shares count towards every class
+        assert_eq!(Org::class_issuance(1, ShareClass::Common), 6);
+        assert_eq!(Org::class_issuance(1, ShareClass::Revenue), 12);
+        assert_eq!(Org::class_issuance(1, ShareClass::Voting), 10);
+        assert_eq!(Org::class_shares(1, ShareClass::Revenue, &7), 6);
+        assert_eq!(Org::class_shares(1, ShareClass::Voting, &7), 0);
+        assert_eq!(Org::class_shares(1, ShareClass::Voting, &2), 5);
+        assert_eq!(Org::class_shares(1, ShareClass::Revenue, &2), 1);
+        let revenue = Org::class_ownership(1, ShareClass::Revenue).unwrap();
+        assert_eq!(revenue.len(), 7);
+        assert!(revenue.contains(&(7, 6)));
+        assert!(Org::class_ownership(2, ShareClass::Revenue).is_none());
+        // issuing common shares through the class call issues member shares
+        assert_ok!(Org::issue_class_shares(
+            one.clone(),
+            1,
+            ShareClass::Common,
+            8,
+            1
+        ));
+        assert!(Org::is_member_of_group(1, &8));
+        assert_noop!(
+            Org::burn_class_shares(one.clone(), 1, ShareClass::Revenue, 7, 7),
+            Error::<TestRuntime>::NotEnoughClassSharesToSatisfyBurnRequest
+        );
+        assert_ok!(Org::burn_class_shares(one, 1, ShareClass::Revenue, 7, 6));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::ClassSharesBurned(1, ShareClass::Revenue, 7, 6)
+        );
+        assert_eq!(Org::class_issuance(1, ShareClass::Revenue), 7);
+        assert_eq!(Org::class_holder_count((1, ShareClass::Revenue)), 0);
+        assert_eq!(
+            Org::class_ownership(1, ShareClass::Revenue).unwrap().len(),
+            7
+        );
+    });
+}
+
+#[test]
+fn vesting_works() {
+    new_test_ext().execute_with(|| {
+        let one = Origin::signed(1);
+        assert_noop!(
+            Org::issue_shares(
+                one.clone(),
+                1,
+                7,
+                10,
+                Some(VestingTerms::new(30, 20))
+            ),
+            Error::<TestRuntime>::InvalidVestingTerms
+        );
+        assert_ok!(Org::issue_shares(
+            one.clone(),
+            1,
+            7,
+            10,
+            Some(VestingTerms::new(10, 20))
+        ));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::VestingSharesIssued(1, 7, 10, VestingTerms::new(10, 20))
+        );
+        // unvested shares carry no voting or payout weight
+        assert_eq!(Org::unvested_shares(1, &7), 10);
+        assert_eq!(Org::class_shares(1, ShareClass::Common, &7), 0);
+        assert_eq!(Org::class_issuance(1, ShareClass::Voting), 6);
+        assert!(!Org::class_ownership(1, ShareClass::Revenue)
+            .unwrap()
+            .iter()
+            .any(|(who, _)| *who == 7));
+        // half of the shares have vested at the cliff
+        System::set_block_number(11);
+        assert_eq!(Org::unvested_shares(1, &7), 5);
+        assert_eq!(Org::class_shares(1, ShareClass::Common, &7), 5);
+        assert_eq!(Org::class_issuance(1, ShareClass::Common), 11);
+        assert_ok!(Org::set_share_transferability(one.clone(), 1, true));
+        assert_noop!(
+            Org::transfer_shares(Origin::signed(7), 1, 1, 6),
+            Error::<TestRuntime>::CannotMoveUnvestedShares
+        );
+        // the unvested remainder is revoked by a member vote
+        assert_noop!(
+            Org::propose_vesting_revocation(Origin::signed(2), 1, 7),
+            Error::<TestRuntime>::NotAuthorizedToProposeVestingRevocation
+        );
+        assert_ok!(Org::propose_vesting_revocation(one.clone(), 1, 7));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::VestingRevocationProposed(1, 1, 7, 1)
+        );
+        assert_noop!(
+            Org::poll_vesting_revocation(one.clone(), 1, 7),
+            Error::<TestRuntime>::VestingRevocationVoteUndecided
+        );
+        set_org_vote_outcome(VoteOutcome::Approved);
+        assert_ok!(Org::poll_vesting_revocation(one, 1, 7));
+        assert_eq!(get_last_event(), RawEvent::VestingRevoked(1, 7, 5));
+        assert_eq!(Org::members(1, 7).unwrap().total(), 5);
+        assert!(Org::vesting_schedules(1, 7).is_none());
+        assert_eq!(Org::class_shares(1, ShareClass::Common, &7), 5);
+        assert_eq!(Org::total_issuance(1), 11);
+    });
+}
+
+#[test]
+fn locked_or_reserved_shares_do_not_block_vesting_revocation() {
+    new_test_ext().execute_with(|| {
+        let one = Origin::signed(1);
+        let seven = Origin::signed(7);
+        assert_ok!(Org::issue_shares(
+            one.clone(),
+            1,
+            7,
+            10,
+            Some(VestingTerms::new(10, 20))
+        ));
+        System::set_block_number(11);
+        assert_ok!(Org::propose_vesting_revocation(one.clone(), 1, 7));
+        // the member reserves and locks its own shares
+        assert_ok!(Org::reserve_shares(seven.clone(), 1, 7));
+        assert_ok!(Org::lock_shares(seven, 1, 7));
+        assert_noop!(
+            Org::burn_shares(one.clone(), 1, 7, 5),
+            Error::<TestRuntime>::CannotMoveLockedOrReservedShares
+        );
+        set_org_vote_outcome(VoteOutcome::Approved);
+        assert_ok!(Org::poll_vesting_revocation(one, 1, 7));
+        assert_eq!(get_last_event(), RawEvent::VestingRevoked(1, 7, 5));
+        let profile = Org::members(1, 7).unwrap();
+        assert_eq!(profile.total(), 5);
+        assert!(!profile.is_movable());
+        assert!(Org::vesting_schedules(1, 7).is_none());
+        assert_eq!(Org::total_issuance(1), 11);
+    });
+}
+
+#[test]
+fn vested_schedule_is_replaced_by_new_vesting_shares() {
+    new_test_ext().execute_with(|| {
+        let one = Origin::signed(1);
+        assert_ok!(Org::issue_shares(
+            one.clone(),
+            1,
+            7,
+            10,
+            Some(VestingTerms::new(10, 20))
+        ));
+        System::set_block_number(15);
+        assert_noop!(
+            Org::issue_shares(
+                one.clone(),
+                1,
+                7,
+                4,
+                Some(VestingTerms::new(10, 20))
+            ),
+            Error::<TestRuntime>::VestingScheduleAlreadyExists
+        );
+        System::set_block_number(21);
+        assert_eq!(Org::unvested_shares(1, &7), 0);
+        assert_ok!(Org::issue_shares(
+            one,
+            1,
+            7,
+            4,
+            Some(VestingTerms::new(10, 20))
+        ));
+        assert_eq!(Org::vesting_schedules(1, 7).unwrap().total(), 4);
+        assert_eq!(Org::members(1, 7).unwrap().total(), 14);
+        assert_eq!(Org::unvested_shares(1, &7), 4);
+        assert_eq!(Org::class_shares(1, ShareClass::Common, &7), 10);
+    });
+}
@@ -0,0 +1,42 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::too_many_arguments)]
+#![allow(clippy::unnecessary_mut_passed)]
+//! Runtime api for discovering votes and reading their tallies without iterating vote module storage
+
+use codec::Codec;
+use sp_std::prelude::*;
+use util::vote::{
+    VoteSummary,
+    VoterView,
+};
+
+sp_api::decl_runtime_apis! {
+    pub trait VoteApi<AccountId, OrgId, VoteId, Signal, BlockNumber, Hash>
+    where
+        AccountId: Codec,
+        OrgId: Codec,
+        VoteId: Codec,
+        Signal: Codec,
+        BlockNumber: Codec,
+        Hash: Codec,
+    {
+        /// Votes for the organization that still accept votes
+        fn open_votes(org: OrgId) -> Vec<VoteId>;
+        /// The most recently closed votes for the organization, oldest first
+        fn closed_votes(org: OrgId) -> Vec<VoteId>;
+        /// Open votes in which the account holds signal
+        fn account_votes(who: AccountId) -> Vec<VoteId>;
+        /// Votes that still accept votes in which the account holds signal but has not voted
+        /// or has not yet revealed its commitment
+        fn pending_votes(who: AccountId) -> Vec<VoteId>;
+        /// The tally, outcome and remaining blocks of the vote with the account's vote if `who` is provided
+        fn vote_summary(
+            vote_id: VoteId,
+            who: Option<AccountId>,
+        ) -> Option<VoteSummary<Signal, BlockNumber, Hash>>;
+        /// The justifications referenced by the voters of the vote with their directions
+        fn vote_justifications(
+            vote_id: VoteId,
+        ) -> Vec<(AccountId, VoterView, Hash)>;
+    }
+}
@@ -0,0 +1,295 @@
+//! Rpc interface for the vote module runtime api
+
+use codec::{
+    Codec,
+    Encode,
+};
+use frame_support::Parameter;
+use jsonrpc_core::{
+    Error as RpcError,
+    ErrorCode,
+    Result,
+};
+use jsonrpc_derive::rpc;
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::Bytes;
+use sp_runtime::{
+    generic::BlockId,
+    traits::Block as BlockT,
+};
+use std::{
+    marker::PhantomData,
+    sync::Arc,
+};
+use util::{
+    traits::VoteVector,
+    vote::{
+        VoteOutcome,
+        VoteSummary,
+        VoterView,
+    },
+};
+
+pub use vote_rpc_runtime_api::VoteApi as VoteRuntimeApi;
+
+/// The account's vote, the justification is the SCALE encoded reference
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VoterInfo<Signal> {
+    pub magnitude: Signal,
+    pub direction: VoterView,
+    pub justification: Option<Bytes>,
+}
+
+/// A voter's justification, the justification is the SCALE encoded reference
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VoterJustification<AccountId> {
+    pub who: AccountId,
+    pub direction: VoterView,
+    pub justification: Bytes,
+}
+
+/// The tally, outcome and remaining blocks of a vote
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VoteInfo<Signal, BlockNumber> {
+    pub in_favor: Signal,
+    pub against: Signal,
+    pub turnout: Signal,
+    pub all_possible_turnout: Signal,
+    pub passage_threshold: Signal,
+    pub rejection_threshold: Option<Signal>,
+    pub outcome: VoteOutcome,
+    pub remaining_blocks: Option<BlockNumber>,
+    pub vote: Option<VoterInfo<Signal>>,
+}
+
+impl<Signal, BlockNumber, Hash> From<VoteSummary<Signal, BlockNumber, Hash>>
+    for VoteInfo<Signal, BlockNumber>
+where
+    Signal: Parameter
+        + Copy
+        + Default
+        + From<u32>
+        + PartialOrd
+        + core::ops::Add<Output = Signal>
+        + core::ops::Sub<Output = Signal>,
+    BlockNumber: Parameter + Copy + Default,
+    Hash: Codec + Clone,
+{
+    fn from(summary: VoteSummary<Signal, BlockNumber, Hash>) -> Self {
+        let state = summary.state;
+        VoteInfo {
+            in_favor: state.in_favor(),
+            against: state.against(),
+            turnout: state.turnout(),
+            all_possible_turnout: state.all_possible_turnout(),
+            passage_threshold: state.passage_threshold(),
+            rejection_threshold: state.rejection_threshold(),
+            outcome: state.outcome(),
+            remaining_blocks: summary.remaining_blocks,
+            vote: summary.vote.map(|vote| {
+                VoterInfo {
+                    magnitude: vote.magnitude(),
+                    direction: vote.direction(),
+                    justification: vote
+                        .justification()
+                        .map(|j| Bytes(j.encode())),
+                }
+            }),
+        }
+    }
+}
+
+#[rpc]
+pub trait VoteApi<BlockHash, AccountId, OrgId, VoteId, Signal, BlockNumber> {
+    /// Votes for the organization that still accept votes
+    #[rpc(name = "vote_openVotes")]
+    fn open_votes(
+        &self,
+        org: OrgId,
+        at: Option<BlockHash>,
+    ) -> Result<Vec<VoteId>>;
+    /// The most recently closed votes for the organization, oldest first
+    #[rpc(name = "vote_closedVotes")]
+    fn closed_votes(
+        &self,
+        org: OrgId,
+        at: Option<BlockHash>,
+    ) -> Result<Vec<VoteId>>;
+    /// Open votes in which the account holds signal
+    #[rpc(name = "vote_accountVotes")]
+    fn account_votes(
+        &self,
+        who: AccountId,
+        at: Option<BlockHash>,
+    ) -> Result<Vec<VoteId>>;
+    /// Votes that still accept votes in which the account holds signal but has not voted
+    /// or has not yet revealed its commitment
+    #[rpc(name = "vote_pendingVotes")]
+    fn pending_votes(
+        &self,
+        who: AccountId,
+        at: Option<BlockHash>,
+    ) -> Result<Vec<VoteId>>;
+    /// The tally, outcome and remaining blocks of the vote with the account's vote if `who` is provided
+    #[rpc(name = "vote_voteInfo")]
+    fn vote_info(
+        &self,
+        vote_id: VoteId,
+        who: Option<AccountId>,
+        at: Option<BlockHash>,
+    ) -> Result<Option<VoteInfo<Signal, BlockNumber>>>;
+    /// The justifications referenced by the voters of the vote with their directions
+    #[rpc(name = "vote_voteJustifications")]
+    fn vote_justifications(
+        &self,
+        vote_id: VoteId,
+        at: Option<BlockHash>,
+    ) -> Result<Vec<VoterJustification<AccountId>>>;
+}
+
+/// Implements the `VoteApi` rpc for the runtime api of the client
+pub struct Vote<C, B, H> {
+    client: Arc<C>,
+    _marker: PhantomData<(B, H)>,
+}
+
+impl<C, B, H> Vote<C, B, H> {
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+const RUNTIME_ERROR: i64 = 1;
+
+fn runtime_error(e: impl core::fmt::Debug) -> RpcError {
+    RpcError {
+        code: ErrorCode::ServerError(RUNTIME_ERROR),
+        message: "Unable to query the vote runtime api.".into(),
+        data: Some(format!("{:?}", e).into()),
+    }
+}
+
+impl<C, Block, AccountId, OrgId, VoteId, Signal, BlockNumber, Hash>
+    VoteApi<
+        <Block as BlockT>::Hash,
+        AccountId,
+        OrgId,
+        VoteId,
+        Signal,
+        BlockNumber,
+    > for Vote<C, Block, Hash>
+where
+    Block: BlockT,
+    C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+    C::Api: VoteRuntimeApi<
+        Block,
+        AccountId,
+        OrgId,
+        VoteId,
+        Signal,
+        BlockNumber,
+        Hash,
+    >,
+    AccountId: Codec,
+    OrgId: Codec,
+    VoteId: Codec,
+    Signal: Parameter
+        + Copy
+        + Default
+        + From<u32>
+        + PartialOrd
+        + core::ops::Add<Output = Signal>
+        + core::ops::Sub<Output = Signal>
+        + Send
+        + Sync
+        + 'static,
+    BlockNumber: Parameter + Copy + Default + Send + Sync + 'static,
+    Hash: Codec + Clone + Send + Sync + 'static,
+{
+    fn open_votes(
+        &self,
+        org: OrgId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Vec<VoteId>> {
+        let api = self.client.runtime_api();
+        let at =
+            BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+        api.open_votes(&at, org).map_err(runtime_error)
+    }
+    fn closed_votes(
+        &self,
+        org: OrgId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Vec<VoteId>> {
+        let api = self.client.runtime_api();
+        let at =
+            BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+        api.closed_votes(&at, org).map_err(runtime_error)
+    }
+    fn account_votes(
+        &self,
+        who: AccountId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Vec<VoteId>> {
+        let api = self.client.runtime_api();
+        let at =
+            BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+        api.account_votes(&at, who).map_err(runtime_error)
+    }
+    fn pending_votes(
+        &self,
+        who: AccountId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Vec<VoteId>> {
+        let api = self.client.runtime_api();
+        let at =
+            BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+        api.pending_votes(&at, who).map_err(runtime_error)
+    }
+    fn vote_info(
+        &self,
+        vote_id: VoteId,
+        who: Option<AccountId>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Option<VoteInfo<Signal, BlockNumber>>> {
+        let api = self.client.runtime_api();
+        let at =
+            BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+        let summary =
+            api.vote_summary(&at, vote_id, who).map_err(runtime_error)?;
+        Ok(summary.map(Into::into))
+    }
+    fn vote_justifications(
+        &self,
+        vote_id: VoteId,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> Result<Vec<VoterJustification<AccountId>>> {
+        let api = self.client.runtime_api();
+        let at =
+            BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+        let justifications = api
+            .vote_justifications(&at, vote_id)
+            .map_err(runtime_error)?;
+        Ok(justifications
+            .into_iter()
+            .map(|(who, direction, justification)| {
+                VoterJustification {
+                    who,
+                    direction,
+                    justification: Bytes(justification.encode()),
+                }
+            })
+            .collect())
+    }
+}
@@ -10,13 +10,26 @@
 #[cfg(test)]
 mod tests;
 
-use codec::Codec;
+use codec::{
+    Codec,
+    Decode,
+    Encode,
+};
 use frame_support::{
     decl_error,
     decl_event,
     decl_module,
     decl_storage,
     ensure,
+    storage::{
+        unhashed,
+        IterableStorageDoubleMap,
+        IterableStorageMap,
+        StorageDoubleMap,
+    },
+    traits::Get,
+    weights::Weight,
+    Blake2_256,
     Parameter,
 };
 use frame_system::{
@@ -27,12 +40,15 @@ use sp_runtime::{
     traits::{
         AtLeast32Bit,
         CheckedSub,
+        Hash,
         MaybeSerializeDeserialize,
         Member,
+        One,
         Zero,
     },
     DispatchError,
     DispatchResult,
+    RuntimeDebug,
 };
 use sp_std::{
     fmt::Debug,
@@ -40,26 +56,37 @@ use sp_std::{
 };
 use util::{
     traits::{
-        AccessGenesis,
         Apply,
         ApplyVote,
+        BurnableSignal,
         CheckVoteStatus,
+        CommitRevealVoteOnProposal,
+        ControlOpenVote,
         GenerateUniqueID,
         GetGroup,
         GetVoteOutcome,
+        GroupMembership,
         IDIsAvailable,
+        JustifiedVote,
         MintableSignal,
+        OpenCommitRevealVote,
         OpenVote,
+        OrganizationRemovalGuard,
         OrganizationSupervisorPermissions,
-        ShareInformation,
+        ShareChangeHandler,
+        ShareClassInformation,
         UpdateVoteTopic,
         VoteOnProposal,
         VoteVector,
     },
     vote::{
+        SignalPolicy,
         Vote,
+        VoteControl,
         VoteOutcome,
         VoteState,
+        VoteStateV1,
+        VoteSummary,
         VoterView,
     },
 };
@@ -94,17 +121,37 @@ pub trait Trait: frame_system::Trait + org::Trait {
         + CheckedSub
         + Zero
         + From<Self::Shares>;
+
+    /// The number of closed votes kept in each organization's history
+    type MaxClosedVoteHistory: Get<u32>;
+
+    /// The maximum number of blocks members vote on a governed vote control
+    type MaxVoteControlDuration: Get<Self::BlockNumber>;
 }
 
 decl_event!(
     pub enum Event<T>
     where
         <T as frame_system::Trait>::AccountId,
+        <T as frame_system::Trait>::BlockNumber,
         <T as org::Trait>::OrgId,
         <T as Trait>::VoteId,
     {
         NewVoteStarted(AccountId, OrgId, VoteId),
         Voted(VoteId, AccountId, VoterView),
+        VoteCommitted(VoteId, AccountId),
+        VoteRevealed(VoteId, AccountId, VoterView),
+        /// Caller, VoteId, new expiry
+        VoteExtended(AccountId, VoteId, BlockNumber),
+        VoteClosedEarly(AccountId, VoteId, VoteOutcome),
+        VoteCancelled(AccountId, VoteId),
+        /// Caller, controlled VoteId, governing VoteId
+        VoteControlProposed(AccountId, VoteId, VoteId, VoteControl<BlockNumber>),
+        /// Caller, governing VoteId, governing vote outcome
+        VoteControlPolled(AccountId, VoteId, VoteOutcome),
+        /// Governing VoteId, controlled VoteId of an approved control that could not be executed
+        VoteControlFailed(VoteId, VoteId),
+        SignalPolicySet(AccountId, OrgId, SignalPolicy),
     }
 );
 
@@ -121,11 +168,57 @@ decl_error! {
         CannotUpdateVoteTopicIfVoteStateDNE,
         // i.e. changing from any non-NoVote view to NoVote (some vote changes aren't allowed to simplify assumptions)
         VoteChangeNotSupported,
+        CommitRevealVoteRequiresCommitThenReveal,
+        NotCommitRevealVoteSoCannotCommitOrReveal,
+        CommitPhaseEndedSoCommitmentsNotAccepted,
+        RevealPhaseNotStartedSoRevealsNotAccepted,
+        NoCommitmentForVoterToReveal,
+        RevealDoesNotMatchCommitment,
+        VoteClosedSoVotesNotAccepted,
+        NoVoteStateForControlRequest,
+        NoOrganizationForVote,
+        NotAuthorizedToControlVote,
+        NotAuthorizedToProposeVoteControl,
+        CannotControlVoteThatIsClosed,
+        CannotControlVoteThatIsExpired,
+        CannotExtendVoteWithoutExpiry,
+        CannotExtendExpiredVote,
+        CannotCloseVoteEarlyIfOutcomeUndecided,
+        NoVoteControlProposalForGoverningVote,
+        VoteControlDurationOutOfBounds,
+        NotAuthorizedToSetSignalPolicy,
+        CannotChangeSharesWhileOrgHasOpenVotes,
+        CannotBurnMoreSignalThanMinted,
+        NoVoteStateForSignalUpdate,
+        AgainstVoteRequiresJustification,
+        CannotRemoveOrganizationWithOpenVotes,
+    }
+}
+
+/// Storage layout versions of this module, used for runtime upgrade migrations
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub enum Releases {
+    /// `VoteLogger` keyed by opaque hashes
+    V1,
+    /// `VoteLogger` keyed by `blake2_128_concat` to iterate the votes of a vote
+    V2,
+    /// `VoteState` with the commit-reveal phase and the block at which the vote was closed
+    V3,
+    /// Open votes with an expiry indexed in `VotesExpiringAt`
+    V4,
+}
+
+impl Default for Releases {
+    fn default() -> Self {
+        Releases::V1
     }
 }
 
 decl_storage! {
     trait Store for Module<T: Trait> as Vote {
+        /// The storage layout version, for runtime upgrade migrations
+        StorageVersion build(|_| Releases::V4): Releases;
+
         /// The nonce for unique vote id generation
         VoteIdCounter get(fn vote_id_counter): T::VoteId;
 
@@ -142,8 +235,50 @@ decl_storage! {
 
         /// Tracks all votes and signal for each participating account
         pub VoteLogger get(fn vote_logger): double_map
+            hasher(blake2_128_concat) T::VoteId,
+            hasher(blake2_128_concat) T::AccountId  => Option<Vote<T::Signal, T::IpfsReference>>;
+
+        /// Commitments `hash(voter, vote_id, direction, salt)` submitted in the commit phase of commit-reveal votes
+        pub VoteCommitments get(fn vote_commitments): double_map
             hasher(opaque_blake2_256) T::VoteId,
-            hasher(opaque_blake2_256) T::AccountId  => Option<Vote<T::Signal, T::IpfsReference>>;
+            hasher(opaque_blake2_256) T::AccountId  => Option<T::Hash>;
+
+        /// The organization whose members vote in the vote
+        pub VoteOrganization get(fn vote_organization): map
+            hasher(opaque_blake2_256) T::VoteId => Option<T::OrgId>;
+
+        /// Governing votes on vote controls, governing VoteId => (controlled VoteId, control)
+        pub VoteControlProposals get(fn vote_control_proposals): map
+            hasher(opaque_blake2_256) T::VoteId => Option<(T::VoteId, VoteControl<T::BlockNumber>)>;
+
+        /// Votes that were opened and not closed early or cancelled for each organization
+        pub OpenVotes get(fn open_votes): map
+            hasher(blake2_128_concat) T::OrgId => Vec<T::VoteId>;
+
+        /// Open votes with an expiry by the first block at which they no longer accept votes, votes that never expire are not indexed
+        pub VotesExpiringAt get(fn votes_expiring_at): map
+            hasher(blake2_128_concat) T::BlockNumber => Vec<T::VoteId>;
+
+        /// Open votes in which the account holds signal, archived votes are removed
+        pub AccountVotes get(fn account_votes): double_map
+            hasher(blake2_128_concat) T::AccountId,
+            hasher(blake2_128_concat) T::VoteId => bool;
+
+        /// The most recently closed votes for each organization, oldest first and bounded by `MaxClosedVoteHistory`
+        pub ClosedVotes get(fn closed_votes): map
+            hasher(blake2_128_concat) T::OrgId => Vec<T::VoteId>;
+
+        /// Votes that mint 1 signal per member instead of signal weighted by shares
+        pub EqualSignalVotes get(fn equal_signal_votes): map
+            hasher(opaque_blake2_256) T::VoteId => bool;
+
+        /// How minted signal follows share changes while the organization has open votes
+        pub SignalPolicies get(fn signal_policies): map
+            hasher(blake2_128_concat) T::OrgId => SignalPolicy;
+
+        /// Votes for which votes against must reference a justification, set when the vote opens
+        pub JustificationRequired get(fn justification_required): map
+            hasher(opaque_blake2_256) T::VoteId => bool;
     }
 }
 
@@ -152,6 +287,24 @@ decl_module! {
         type Error = Error<T>;
         fn deposit_event() = default;
 
+        fn on_initialize(now: T::BlockNumber) -> Weight {
+            // votes that no longer accept votes from this block leave the open votes
+            <VotesExpiringAt<T>>::take(now).into_iter().fold(
+                T::DbWeight::get().reads_writes(1, 1),
+                |weight, vote_id| weight.saturating_add(Self::archive_vote(vote_id)),
+            )
+        }
+
+        fn on_runtime_upgrade() -> Weight {
+            // the vote logger migration reads the migrated org members and
+            // the runtime does not order the upgrades of its modules
+            <org::Module<T>>::migrate_member_count()
+                .saturating_add(<org::Module<T>>::migrate_sub_organizations())
+                .saturating_add(Self::migrate_vote_logger())
+                .saturating_add(Self::migrate_vote_states())
+                .saturating_add(Self::migrate_votes_expiring_at())
+        }
+
         #[weight = 0]
         pub fn create_threshold_approval_vote(
             origin,
@@ -199,6 +352,516 @@ decl_module! {
             Self::deposit_event(RawEvent::Voted(vote_id, voter, direction));
             Ok(())
         }
+        #[weight = 0]
+        pub fn create_commit_reveal_vote(
+            origin,
+            topic: Option<T::IpfsReference>,
+            organization: T::OrgId,
+            support_requirement: T::Signal,
+            rejection_requirement: Option<T::Signal>,
+            commit_duration: T::BlockNumber,
+            reveal_duration: T::BlockNumber,
+        ) -> DispatchResult {
+            let vote_creator = ensure_signed(origin)?;
+            // default authentication is organization supervisor or sudo key
+            let authentication: bool = <org::Module<T>>::is_organization_supervisor(organization, &vote_creator);
+            ensure!(authentication, Error::<T>::NotAuthorizedToCreateVoteForOrganization);
+            let new_vote_id = Self::open_commit_reveal_vote(topic, organization, support_requirement, rejection_requirement, commit_duration, reveal_duration)?;
+            // emit event
+            Self::deposit_event(RawEvent::NewVoteStarted(vote_creator, organization, new_vote_id));
+            Ok(())
+        }
+        #[weight = 0]
+        pub fn commit_vote(
+            origin,
+            vote_id: T::VoteId,
+            commitment: T::Hash,
+        ) -> DispatchResult {
+            let voter = ensure_signed(origin)?;
+            <Self as CommitRevealVoteOnProposal<_, _, _, _, _, _, _>>::commit_vote(vote_id, voter.clone(), commitment)?;
+            Self::deposit_event(RawEvent::VoteCommitted(vote_id, voter));
+            Ok(())
+        }
+        #[weight = 0]
+        pub fn reveal_vote(
+            origin,
+            vote_id: T::VoteId,
+            direction: VoterView,
+            salt: T::Hash,
+            justification: Option<T::IpfsReference>,
+        ) -> DispatchResult {
+            let voter = ensure_signed(origin)?;
+            <Self as CommitRevealVoteOnProposal<_, _, _, _, _, _, _>>::reveal_vote(vote_id, voter.clone(), direction, salt, justification)?;
+            Self::deposit_event(RawEvent::VoteRevealed(vote_id, voter, direction));
+            Ok(())
+        }
+        #[weight = 0]
+        pub fn extend_vote(
+            origin,
+            vote_id: T::VoteId,
+            blocks: T::BlockNumber,
+        ) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            let authentication: bool = Self::is_vote_supervisor(vote_id, &caller)?;
+            ensure!(authentication, Error::<T>::NotAuthorizedToControlVote);
+            Self::execute_vote_control(caller, vote_id, VoteControl::Extend(blocks))
+        }
+        #[weight = 0]
+        pub fn close_vote_early(
+            origin,
+            vote_id: T::VoteId,
+        ) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            let authentication: bool = Self::is_vote_supervisor(vote_id, &caller)?;
+            ensure!(authentication, Error::<T>::NotAuthorizedToControlVote);
+            Self::execute_vote_control(caller, vote_id, VoteControl::CloseEarly)
+        }
+        #[weight = 0]
+        pub fn cancel_vote(
+            origin,
+            vote_id: T::VoteId,
+        ) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            let authentication: bool = Self::is_vote_supervisor(vote_id, &caller)?;
+            ensure!(authentication, Error::<T>::NotAuthorizedToControlVote);
+            Self::execute_vote_control(caller, vote_id, VoteControl::Cancel)
+        }
+        #[weight = 0]
+        pub fn propose_vote_control(
+            origin,
+            vote_id: T::VoteId,
+            control: VoteControl<T::BlockNumber>,
+            duration: T::BlockNumber,
+        ) -> DispatchResult {
+            let proposer = ensure_signed(origin)?;
+            ensure!(!duration.is_zero() && duration <= T::MaxVoteControlDuration::get(), Error::<T>::VoteControlDurationOutOfBounds);
+            let organization = <VoteOrganization<T>>::get(vote_id).ok_or(Error::<T>::NoOrganizationForVote)?;
+            // any member of the organization may propose a governed vote control
+            let authentication: bool = <org::Module<T>>::is_member_of_group(organization, &proposer);
+            ensure!(authentication, Error::<T>::NotAuthorizedToProposeVoteControl);
+            let vote_state = <VoteStates<T>>::get(vote_id).ok_or(Error::<T>::NoVoteStateForControlRequest)?;
+            ensure!(!vote_state.is_closed(), Error::<T>::CannotControlVoteThatIsClosed);
+            // governing vote passes with a majority of the organization's shares
+            let total_signal: T::Signal = <org::Module<T>>::class_issuance(organization, T::SignalShareClass::get()).into();
+            let passage_threshold = total_signal / 2u32.into() + 1u32.into();
+            let governing_vote_id = Self::open_vote(None, organization, passage_threshold, None, Some(duration))?;
+            <VoteControlProposals<T>>::insert(governing_vote_id, (vote_id, control));
+            Self::deposit_event(RawEvent::VoteControlProposed(proposer, vote_id, governing_vote_id, control));
+            Ok(())
+        }
+        #[weight = 0]
+        pub fn poll_vote_control(
+            origin,
+            governing_vote_id: T::VoteId,
+        ) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            let (vote_id, control) = <VoteControlProposals<T>>::get(governing_vote_id)
+                .ok_or(Error::<T>::NoVoteControlProposalForGoverningVote)?;
+            let outcome = Self::get_vote_outcome(governing_vote_id)?;
+            match outcome {
+                VoteOutcome::Approved => {
+                    <VoteControlProposals<T>>::remove(governing_vote_id);
+                    // the controlled vote may have closed or expired while the governing vote was open
+                    if Self::execute_vote_control(caller.clone(), vote_id, control).is_err() {
+                        Self::deposit_event(RawEvent::VoteControlFailed(governing_vote_id, vote_id));
+                    }
+                }
+                VoteOutcome::Rejected | VoteOutcome::Cancelled => {
+                    <VoteControlProposals<T>>::remove(governing_vote_id);
+                }
+                _ => (),
+            }
+            Self::deposit_event(RawEvent::VoteControlPolled(caller, governing_vote_id, outcome));
+            Ok(())
+        }
+        #[weight = 0]
+        pub fn set_signal_policy(
+            origin,
+            organization: T::OrgId,
+            policy: SignalPolicy,
+        ) -> DispatchResult {
+            let caller = ensure_signed(origin)?;
+            let authentication: bool = <org::Module<T>>::is_organization_supervisor(organization, &caller);
+            ensure!(authentication, Error::<T>::NotAuthorizedToSetSignalPolicy);
+            <SignalPolicies<T>>::insert(organization, policy);
+            Self::deposit_event(RawEvent::SignalPolicySet(caller, organization, policy));
+            Ok(())
+        }
+        #[weight = 0]
+        pub fn create_justified_threshold_approval_vote(
+            origin,
+            topic: Option<T::IpfsReference>,
+            organization: T::OrgId,
+            support_requirement: T::Signal,
+            rejection_requirement: Option<T::Signal>,
+            duration: Option<T::BlockNumber>,
+        ) -> DispatchResult {
+            let vote_creator = ensure_signed(origin)?;
+            // default authentication is organization supervisor or sudo key
+            let authentication: bool = <org::Module<T>>::is_organization_supervisor(organization, &vote_creator);
+            ensure!(authentication, Error::<T>::NotAuthorizedToCreateVoteForOrganization);
+            // votes against must reference a justification for the whole vote
+            let new_vote_id = Self::open_justified_vote(topic, organization, support_requirement, rejection_requirement, duration)?;
+            // emit event
+            Self::deposit_event(RawEvent::NewVoteStarted(vote_creator, organization, new_vote_id));
+            Ok(())
+        }
+    }
+}
+
+impl<T: Trait> Module<T> {
+    /// The commitment expected in the commit phase for the voter's direction and salt
+    /// -> the voter and the vote are hashed so that commitments cannot be copied by other voters
+    pub fn vote_commitment(
+        voter: &T::AccountId,
+        vote_id: T::VoteId,
+        direction: VoterView,
+        salt: T::Hash,
+    ) -> T::Hash {
+        T::Hashing::hash_of(&(voter, vote_id, direction, salt))
+    }
+    fn insert_open_vote(
+        vote_id: T::VoteId,
+        organization: T::OrgId,
+        vote_state: VoteState<T::Signal, T::BlockNumber, T::IpfsReference>,
+    ) {
+        if let Some(expires) = vote_state.expires() {
+            Self::index_expiry(vote_id, expires);
+        }
+        <VoteStates<T>>::insert(vote_id, vote_state);
+        <VoteOrganization<T>>::insert(vote_id, organization);
+        <OpenVotes<T>>::mutate(organization, |votes| votes.push(vote_id));
+        // increment open vote count
+        let new_vote_count = <OpenVoteCounter>::get() + 1u32;
+        <OpenVoteCounter>::put(new_vote_count);
+    }
+    /// Indexes the vote to be archived in the block after its last voting block
+    fn index_expiry(vote_id: T::VoteId, expires: T::BlockNumber) {
+        <VotesExpiringAt<T>>::mutate(expires + One::one(), |votes| {
+            votes.push(vote_id)
+        });
+    }
+    /// Removes the vote from the index when it is closed, cancelled or extended
+    fn unindex_expiry(vote_id: T::VoteId, expires: T::BlockNumber) {
+        let block = expires + One::one();
+        let mut votes = <VotesExpiringAt<T>>::get(block);
+        votes.retain(|id| *id != vote_id);
+        if votes.is_empty() {
+            <VotesExpiringAt<T>>::remove(block);
+        } else {
+            <VotesExpiringAt<T>>::insert(block, votes);
+        }
+    }
+    /// Re-keys the `VoteLogger` entries written with opaque hashes, the
+    /// opaque keys cannot be reversed so every stored vote is checked for
+    /// the accounts that are members of any organization
+    pub fn migrate_vote_logger() -> Weight {
+        if <StorageVersion>::get() != Releases::V1 {
+            return 0
+        }
+        let mut reads: Weight = 2;
+        let mut writes: Weight = 1;
+        // `Members` is migrated by the org module before this runs
+        let mut accounts: Vec<T::AccountId> = <org::Members<T>>::iter()
+            .map(|(_, who, _)| who)
+            .collect();
+        accounts.sort();
+        accounts.dedup();
+        reads += accounts.len() as Weight;
+        let last_vote_id = <VoteIdCounter<T>>::get();
+        let mut vote_id: T::VoteId = 1u32.into();
+        while vote_id <= last_vote_id {
+            reads += 1;
+            if <VoteStates<T>>::contains_key(vote_id) {
+                for who in accounts.iter() {
+                    reads += 1;
+                    if <VoteLogger<T>>::migrate_keys::<
+                        Blake2_256,
+                        Blake2_256,
+                        _,
+                        _,
+                    >(vote_id, who)
+                    .is_some()
+                    {
+                        writes += 2;
+                    }
+                }
+            }
+            vote_id += 1u32.into();
+        }
+        <StorageVersion>::put(Releases::V2);
+        T::DbWeight::get().reads_writes(reads, writes)
+    }
+    /// Decodes the vote states stored before commit-reveal votes and vote
+    /// controls, which are neither commit-reveal votes nor closed
+    pub fn migrate_vote_states() -> Weight {
+        if <StorageVersion>::get() != Releases::V2 {
+            return 0
+        }
+        let mut reads: Weight = 2;
+        let mut writes: Weight = 1;
+        let last_vote_id = <VoteIdCounter<T>>::get();
+        let mut vote_id: T::VoteId = 1u32.into();
+        while vote_id <= last_vote_id {
+            reads += 1;
+            let key = <VoteStates<T>>::hashed_key_for(vote_id);
+            if let Some(old_state) = unhashed::get::<
+                VoteStateV1<T::Signal, T::BlockNumber, T::IpfsReference>,
+            >(&key)
+            {
+                <VoteStates<T>>::insert(vote_id, VoteState::from(old_state));
+                writes += 1;
+            }
+            vote_id += 1u32.into();
+        }
+        <StorageVersion>::put(Releases::V3);
+        T::DbWeight::get().reads_writes(reads, writes)
+    }
+    /// Indexes the open votes with an expiry in `VotesExpiringAt`, votes that
+    /// expired before the upgrade are archived in the current block
+    pub fn migrate_votes_expiring_at() -> Weight {
+        if <StorageVersion>::get() != Releases::V3 {
+            return 0
+        }
+        let mut reads: Weight = 2;
+        let mut writes: Weight = 1;
+        let now = system::Module::<T>::block_number();
+        for (_, votes) in <OpenVotes<T>>::iter() {
+            reads += 1;
+            for vote_id in votes {
+                reads += 1;
+                if let Some(expires) =
+                    <VoteStates<T>>::get(vote_id).and_then(|s| s.expires())
+                {
+                    writes += 1;
+                    let block = expires + One::one();
+                    let block = if block < now { now } else { block };
+                    <VotesExpiringAt<T>>::mutate(block, |votes| {
+                        votes.push(vote_id)
+                    });
+                }
+            }
+        }
+        <StorageVersion>::put(Releases::V4);
+        T::DbWeight::get().reads_writes(reads, writes)
+    }
+    /// Moves the vote from the open votes to the closed votes of its
+    /// organization and removes it from the votes of its voters, a vote which
+    /// is not open anymore is left as is, returns the weight used
+    fn archive_vote(vote_id: T::VoteId) -> Weight {
+        let mut reads: Weight = 1;
+        let mut writes: Weight = 0;
+        if let Some(organization) = <VoteOrganization<T>>::get(vote_id) {
+            reads += 1;
+            writes += 1;
+            let was_open = <OpenVotes<T>>::mutate(organization, |votes| {
+                let len = votes.len();
+                votes.retain(|id| *id != vote_id);
+                votes.len() < len
+            });
+            if !was_open {
+                return T::DbWeight::get().reads_writes(reads, writes)
+            }
+            reads += 2;
+            writes += 2;
+            <ClosedVotes<T>>::mutate(organization, |votes| {
+                votes.push(vote_id);
+                let max_history = T::MaxClosedVoteHistory::get() as usize;
+                if votes.len() > max_history {
+                    let excess = votes.len() - max_history;
+                    votes.drain(..excess);
+                }
+            });
+            <OpenVoteCounter>::mutate(|count| *count = count.saturating_sub(1));
+            // the vote logger is kept for audit
+            <VoteLogger<T>>::iter_prefix(vote_id).for_each(|(who, _)| {
+                reads += 1;
+                writes += 1;
+                <AccountVotes<T>>::remove(who, vote_id)
+            });
+        }
+        T::DbWeight::get().reads_writes(reads, writes)
+    }
+    fn archive_expired_votes(organization: T::OrgId) {
+        <OpenVotes<T>>::get(organization)
+            .into_iter()
+            .filter(|vote_id| {
+                if let Some(state) = <VoteStates<T>>::get(vote_id) {
+                    Self::check_vote_expired(&state)
+                } else {
+                    false
+                }
+            })
+            .for_each(|vote_id| {
+                Self::archive_vote(vote_id);
+            });
+    }
+    /// Open votes for the organization that still accept votes
+    pub fn live_votes(organization: T::OrgId) -> Vec<T::VoteId> {
+        <OpenVotes<T>>::get(organization)
+            .into_iter()
+            .filter(|vote_id| {
+                if let Some(state) = <VoteStates<T>>::get(vote_id) {
+                    !state.is_closed() && !Self::check_vote_expired(&state)
+                } else {
+                    false
+                }
+            })
+            .collect()
+    }
+    /// Open votes in which the account holds signal
+    pub fn votes_for_account(who: &T::AccountId) -> Vec<T::VoteId> {
+        <AccountVotes<T>>::iter_prefix(who)
+            .map(|(vote_id, _)| vote_id)
+            .collect()
+    }
+    /// Votes that still accept votes in which the account holds signal but has not voted
+    /// or has not yet revealed its commitment
+    pub fn pending_votes_for_account(who: &T::AccountId) -> Vec<T::VoteId> {
+        <AccountVotes<T>>::iter_prefix(who)
+            .map(|(vote_id, _)| vote_id)
+            .filter(|vote_id| {
+                let live = if let Some(state) = <VoteStates<T>>::get(vote_id) {
+                    !state.is_closed() && !Self::check_vote_expired(&state)
+                } else {
+                    false
+                };
+                let not_voted = <VoteLogger<T>>::get(vote_id, who)
+                    .map(|vote| vote.direction() == VoterView::NoVote)
+                    .unwrap_or(false);
+                let not_revealed =
+                    <VoteCommitments<T>>::contains_key(vote_id, who);
+                live && (not_voted || not_revealed)
+            })
+            .collect()
+    }
+    /// The tally, outcome and remaining blocks of the vote with the account's vote if `who` is provided
+    pub fn vote_summary(
+        vote_id: T::VoteId,
+        who: Option<T::AccountId>,
+    ) -> Option<VoteSummary<T::Signal, T::BlockNumber, T::IpfsReference>> {
+        let state = <VoteStates<T>>::get(vote_id)?;
+        let now = system::Module::<T>::block_number();
+        // votes are accepted until the expiry block inclusive
+        let remaining_blocks = state.expires().map(|expires| {
+            if state.is_closed() || Self::check_vote_expired(&state) {
+                Zero::zero()
+            } else {
+                expires - now + One::one()
+            }
+        });
+        let vote = who.and_then(|who| <VoteLogger<T>>::get(vote_id, who));
+        Some(VoteSummary {
+            state,
+            remaining_blocks,
+            vote,
+        })
+    }
+    /// The justifications referenced by the voters of the vote with their directions
+    pub fn vote_justifications(
+        vote_id: T::VoteId,
+    ) -> Vec<(T::AccountId, VoterView, T::IpfsReference)> {
+        <VoteLogger<T>>::iter_prefix(vote_id)
+            .filter_map(|(who, vote)| {
+                vote.justification()
+                    .map(|justification| (who, vote.direction(), justification))
+            })
+            .collect()
+    }
+    /// Replaces the voter's signal and updates the tally for the voter's current direction
+    /// -> voters left without signal are removed from the vote
+    fn set_signal(
+        vote_id: T::VoteId,
+        who: &T::AccountId,
+        new_signal: T::Signal,
+    ) -> DispatchResult {
+        let vote_state = <VoteStates<T>>::get(vote_id)
+            .ok_or(Error::<T>::NoVoteStateForSignalUpdate)?;
+        let old_vote = <VoteLogger<T>>::get(vote_id, who);
+        let (old_signal, direction) = if let Some(vote) = &old_vote {
+            (vote.magnitude(), vote.direction())
+        } else {
+            (T::Signal::zero(), VoterView::NoVote)
+        };
+        if old_signal == new_signal {
+            return Ok(())
+        }
+        let new_vote_state =
+            vote_state.update_signal(old_signal, new_signal, direction);
+        if new_signal.is_zero() {
+            <VoteLogger<T>>::remove(vote_id, who);
+            <AccountVotes<T>>::remove(who, vote_id);
+        } else {
+            let new_vote = if let Some(vote) = old_vote {
+                vote.set_magnitude(new_signal)
+            } else {
+                Vote::new(new_signal, VoterView::NoVote, None)
+            };
+            <VoteLogger<T>>::insert(vote_id, who, new_vote);
+            <AccountVotes<T>>::insert(who, vote_id, true);
+        }
+        let old_issuance =
+            <TotalSignalIssuance<T>>::get(vote_id).unwrap_or_else(Zero::zero);
+        <TotalSignalIssuance<T>>::insert(
+            vote_id,
+            old_issuance - old_signal + new_signal,
+        );
+        <VoteStates<T>>::insert(vote_id, new_vote_state);
+        Ok(())
+    }
+    /// Votes against must reference a justification if the vote requires it
+    fn check_justification(
+        vote_id: T::VoteId,
+        direction: VoterView,
+        justification: &Option<T::IpfsReference>,
+    ) -> DispatchResult {
+        if direction == VoterView::Against && justification.is_none() {
+            ensure!(
+                !<JustificationRequired<T>>::get(vote_id),
+                Error::<T>::AgainstVoteRequiresJustification
+            );
+        }
+        Ok(())
+    }
+    /// Vote controls are permissioned by the supervisor of the voting organization
+    pub fn is_vote_supervisor(
+        vote_id: T::VoteId,
+        who: &T::AccountId,
+    ) -> Result<bool, DispatchError> {
+        let organization = <VoteOrganization<T>>::get(vote_id)
+            .ok_or(Error::<T>::NoOrganizationForVote)?;
+        Ok(<org::Module<T>>::is_organization_supervisor(
+            organization,
+            who,
+        ))
+    }
+    fn execute_vote_control(
+        caller: T::AccountId,
+        vote_id: T::VoteId,
+        control: VoteControl<T::BlockNumber>,
+    ) -> DispatchResult {
+        match control {
+            VoteControl::Extend(blocks) => {
+                let expires = <Self as ControlOpenVote<_, _>>::extend_vote(
+                    vote_id, blocks,
+                )?;
+                Self::deposit_event(RawEvent::VoteExtended(
+                    caller, vote_id, expires,
+                ));
+            }
+            VoteControl::CloseEarly => {
+                let outcome =
+                    <Self as ControlOpenVote<_, _>>::close_vote_early(vote_id)?;
+                Self::deposit_event(RawEvent::VoteClosedEarly(
+                    caller, vote_id, outcome,
+                ));
+            }
+            VoteControl::Cancel => {
+                <Self as ControlOpenVote<_, _>>::cancel_vote(vote_id)?;
+                Self::deposit_event(RawEvent::VoteCancelled(caller, vote_id));
+            }
+        }
+        Ok(())
     }
 }
 
@@ -263,10 +926,7 @@ impl<T: Trait> OpenVote<T::OrgId, T::Signal, T::BlockNumber, T::IpfsReference>
             ends,
         );
         // insert the VoteState
-        <VoteStates<T>>::insert(new_vote_id, new_vote_state);
-        // increment open vote count
-        let new_vote_count = <OpenVoteCounter>::get() + 1u32;
-        <OpenVoteCounter>::put(new_vote_count);
+        Self::insert_open_vote(new_vote_id, organization, new_vote_state);
         Ok(new_vote_id)
     }
     fn open_unanimous_consent(
@@ -286,6 +946,7 @@ impl<T: Trait> OpenVote<T::OrgId, T::Signal, T::BlockNumber, T::IpfsReference>
         // mints 1 signal per participant
         let total_possible_turnout =
             Self::batch_mint_equal_signal(new_vote_id, organization)?;
+        <EqualSignalVotes<T>>::insert(new_vote_id, true);
         // instantiate new VoteState for unanimous consent
         let new_vote_state = VoteState::new_unanimous_consent(
             topic,
@@ -294,10 +955,7 @@ impl<T: Trait> OpenVote<T::OrgId, T::Signal, T::BlockNumber, T::IpfsReference>
             ends,
         );
         // insert the VoteState
-        <VoteStates<T>>::insert(new_vote_id, new_vote_state);
-        // increment open vote count
-        let new_vote_count = <OpenVoteCounter>::get() + 1u32;
-        <OpenVoteCounter>::put(new_vote_count);
+        Self::insert_open_vote(new_vote_id, organization, new_vote_state);
         Ok(new_vote_id)
     }
 }
@@ -340,6 +998,7 @@ impl<T: Trait>
     ) {
         let new_vote = Vote::new(signal, VoterView::NoVote, None);
         <VoteLogger<T>>::insert(vote_id, who, new_vote);
+        <AccountVotes<T>>::insert(who, vote_id, true);
     }
 
     /// Mints equal signal for all members of the group (1u32.into())
@@ -355,7 +1014,8 @@ impl<T: Trait>
         new_vote_group.0.into_iter().for_each(|who| {
             let minted_signal: T::Signal = 1u32.into();
             let new_vote = Vote::new(minted_signal, VoterView::NoVote, None);
-            <VoteLogger<T>>::insert(vote_id, who, new_vote);
+            <VoteLogger<T>>::insert(vote_id, &who, new_vote);
+            <AccountVotes<T>>::insert(who, vote_id, true);
         });
         <TotalSignalIssuance<T>>::insert(vote_id, total_minted);
         Ok(total_minted)
@@ -365,19 +1025,19 @@ impl<T: Trait>
         vote_id: T::VoteId,
         organization: T::OrgId,
     ) -> Result<T::Signal, DispatchError> {
+        let class = T::SignalShareClass::get();
         let new_vote_group =
-            <org::Module<T>>::get_membership_with_shape(organization)
+            <org::Module<T>>::class_ownership(organization, class)
                 .ok_or(Error::<T>::CannotMintSignalBecauseMembershipShapeDNE)?;
         // total issuance
-        let total_minted: T::Signal = new_vote_group.total().into();
-        new_vote_group.account_ownership().into_iter().for_each(
-            |(who, shares)| {
-                let minted_signal: T::Signal = shares.into();
-                let new_vote =
-                    Vote::new(minted_signal, VoterView::NoVote, None);
-                <VoteLogger<T>>::insert(vote_id, who, new_vote);
-            },
-        );
+        let total_minted: T::Signal =
+            <org::Module<T>>::class_issuance(organization, class).into();
+        new_vote_group.into_iter().for_each(|(who, shares)| {
+            let minted_signal: T::Signal = shares.into();
+            let new_vote = Vote::new(minted_signal, VoterView::NoVote, None);
+            <VoteLogger<T>>::insert(vote_id, &who, new_vote);
+            <AccountVotes<T>>::insert(who, vote_id, true);
+        });
         <TotalSignalIssuance<T>>::insert(vote_id, total_minted);
         Ok(total_minted)
     }
@@ -428,12 +1088,22 @@ impl<T: Trait>
         // get the vote state
         let vote_state = <VoteStates<T>>::get(vote_id)
             .ok_or(Error::<T>::NoVoteStateForVoteRequest)?;
-        // TODO: add permissioned method for adding time to the vote state because of this restriction but this is a legitimate restriction
-        // -> every standard vote has a recognized end to establish when the decision must be made based on collected input
+        // every standard vote has a recognized end to establish when the decision must be made based on collected input
+        // -> supervisors may add time to the vote state with `extend_vote`
         ensure!(
             !Self::check_vote_expired(&vote_state),
             Error::<T>::VotePastExpirationTimeSoVotesNotAccepted
         );
+        ensure!(
+            !vote_state.is_closed(),
+            Error::<T>::VoteClosedSoVotesNotAccepted
+        );
+        // secret ballots are only accepted through commit and reveal
+        ensure!(
+            !vote_state.is_commit_reveal(),
+            Error::<T>::CommitRevealVoteRequiresCommitThenReveal
+        );
+        Self::check_justification(vote_id, direction, &justification)?;
         // get the organization associated with this vote_state
         let old_vote = <VoteLogger<T>>::get(vote_id, voter.clone())
             .ok_or(Error::<T>::SignalNotMintedForVoter)?;
@@ -454,3 +1124,356 @@ impl<T: Trait>
         Ok(())
     }
 }
+
+impl<T: Trait>
+    OpenCommitRevealVote<T::OrgId, T::Signal, T::BlockNumber, T::IpfsReference>
+    for Module<T>
+{
+    fn open_commit_reveal_vote(
+        topic: Option<T::IpfsReference>,
+        organization: T::OrgId,
+        passage_threshold: T::Signal,
+        rejection_threshold: Option<T::Signal>,
+        commit_duration: T::BlockNumber,
+        reveal_duration: T::BlockNumber,
+    ) -> Result<Self::VoteIdentifier, DispatchError> {
+        // the reveal phase starts when the commit phase ends
+        let now = system::Module::<T>::block_number();
+        let reveal_starts = now + commit_duration;
+        let ends = reveal_starts + reveal_duration;
+        // generate new vote_id
+        let new_vote_id = Self::generate_unique_id();
+        // by default, this call mints signal based on weighted ownership in group
+        let total_possible_turnout =
+            Self::batch_mint_signal(new_vote_id, organization)?;
+        // instantiate new VoteState with commit and reveal phases
+        let new_vote_state = VoteState::new_commit_reveal(
+            topic,
+            total_possible_turnout,
+            passage_threshold,
+            rejection_threshold,
+            now,
+            reveal_starts,
+            ends,
+        );
+        // insert the VoteState
+        Self::insert_open_vote(new_vote_id, organization, new_vote_state);
+        Ok(new_vote_id)
+    }
+}
+
+impl<T: Trait>
+    CommitRevealVoteOnProposal<
+        T::AccountId,
+        T::OrgId,
+        T::Signal,
+        T::BlockNumber,
+        T::VoteId,
+        T::IpfsReference,
+        T::Hash,
+    > for Module<T>
+{
+    fn commit_vote(
+        vote_id: T::VoteId,
+        voter: T::AccountId,
+        commitment: T::Hash,
+    ) -> DispatchResult {
+        let vote_state = <VoteStates<T>>::get(vote_id)
+            .ok_or(Error::<T>::NoVoteStateForVoteRequest)?;
+        let reveal_starts = vote_state
+            .reveal_starts()
+            .ok_or(Error::<T>::NotCommitRevealVoteSoCannotCommitOrReveal)?;
+        ensure!(
+            !vote_state.is_closed(),
+            Error::<T>::VoteClosedSoVotesNotAccepted
+        );
+        let now = system::Module::<T>::block_number();
+        ensure!(
+            now < reveal_starts,
+            Error::<T>::CommitPhaseEndedSoCommitmentsNotAccepted
+        );
+        let old_vote = <VoteLogger<T>>::get(vote_id, voter.clone())
+            .ok_or(Error::<T>::SignalNotMintedForVoter)?;
+        // the first commitment registers the voter as abstaining until the reveal
+        // -> commitments that are never revealed therefore count as abstentions
+        if old_vote.direction() == VoterView::NoVote {
+            let new_vote = old_vote
+                .set_new_view(VoterView::Abstain, None)
+                .ok_or(Error::<T>::VoteChangeNotSupported)?;
+            let new_state = Self::apply_vote(
+                vote_state,
+                old_vote.magnitude(),
+                VoterView::NoVote,
+                VoterView::Abstain,
+            )
+            .ok_or(Error::<T>::VoteChangeNotSupported)?;
+            <VoteLogger<T>>::insert(vote_id, voter.clone(), new_vote);
+            <VoteStates<T>>::insert(vote_id, new_state);
+        }
+        // later commitments in the commit phase overwrite earlier commitments
+        <VoteCommitments<T>>::insert(vote_id, voter, commitment);
+        Ok(())
+    }
+    fn reveal_vote(
+        vote_id: T::VoteId,
+        voter: T::AccountId,
+        direction: VoterView,
+        salt: T::Hash,
+        justification: Option<T::IpfsReference>,
+    ) -> DispatchResult {
+        let vote_state = <VoteStates<T>>::get(vote_id)
+            .ok_or(Error::<T>::NoVoteStateForVoteRequest)?;
+        let reveal_starts = vote_state
+            .reveal_starts()
+            .ok_or(Error::<T>::NotCommitRevealVoteSoCannotCommitOrReveal)?;
+        ensure!(
+            !vote_state.is_closed(),
+            Error::<T>::VoteClosedSoVotesNotAccepted
+        );
+        let now = system::Module::<T>::block_number();
+        ensure!(
+            now >= reveal_starts,
+            Error::<T>::RevealPhaseNotStartedSoRevealsNotAccepted
+        );
+        ensure!(
+            !Self::check_vote_expired(&vote_state),
+            Error::<T>::VotePastExpirationTimeSoVotesNotAccepted
+        );
+        let commitment = <VoteCommitments<T>>::get(vote_id, voter.clone())
+            .ok_or(Error::<T>::NoCommitmentForVoterToReveal)?;
+        ensure!(
+            Self::vote_commitment(&voter, vote_id, direction, salt)
+                == commitment,
+            Error::<T>::RevealDoesNotMatchCommitment
+        );
+        Self::check_justification(vote_id, direction, &justification)?;
+        let old_vote = <VoteLogger<T>>::get(vote_id, voter.clone())
+            .ok_or(Error::<T>::SignalNotMintedForVoter)?;
+        // revealing an abstention leaves the tally unchanged
+        if direction != old_vote.direction() {
+            let new_state = Self::apply_vote(
+                vote_state,
+                old_vote.magnitude(),
+                old_vote.direction(),
+                direction,
+            )
+            .ok_or(Error::<T>::VoteChangeNotSupported)?;
+            <VoteStates<T>>::insert(vote_id, new_state);
+        }
+        // the revealed vote keeps its justification, even for an abstention
+        let new_vote =
+            Vote::new(old_vote.magnitude(), direction, justification);
+        <VoteLogger<T>>::insert(vote_id, voter.clone(), new_vote);
+        <VoteCommitments<T>>::remove(vote_id, voter);
+        Ok(())
+    }
+}
+
+impl<T: Trait> ControlOpenVote<T::VoteId, T::BlockNumber> for Module<T> {
+    fn extend_vote(
+        vote_id: T::VoteId,
+        blocks: T::BlockNumber,
+    ) -> Result<T::BlockNumber, DispatchError> {
+        let vote_state = <VoteStates<T>>::get(vote_id)
+            .ok_or(Error::<T>::NoVoteStateForControlRequest)?;
+        ensure!(
+            !vote_state.is_closed(),
+            Error::<T>::CannotControlVoteThatIsClosed
+        );
+        ensure!(
+            !Self::check_vote_expired(&vote_state),
+            Error::<T>::CannotExtendExpiredVote
+        );
+        let new_vote_state = vote_state
+            .extend(blocks)
+            .ok_or(Error::<T>::CannotExtendVoteWithoutExpiry)?;
+        let new_expiry = new_vote_state
+            .expires()
+            .ok_or(Error::<T>::CannotExtendVoteWithoutExpiry)?;
+        if let Some(old_expiry) = vote_state.expires() {
+            Self::unindex_expiry(vote_id, old_expiry);
+        }
+        Self::index_expiry(vote_id, new_expiry);
+        <VoteStates<T>>::insert(vote_id, new_vote_state);
+        Ok(new_expiry)
+    }
+    fn close_vote_early(
+        vote_id: T::VoteId,
+    ) -> Result<VoteOutcome, DispatchError> {
+        let vote_state = <VoteStates<T>>::get(vote_id)
+            .ok_or(Error::<T>::NoVoteStateForControlRequest)?;
+        ensure!(
+            !vote_state.is_closed(),
+            Error::<T>::CannotControlVoteThatIsClosed
+        );
+        ensure!(
+            !Self::check_vote_expired(&vote_state),
+            Error::<T>::CannotControlVoteThatIsExpired
+        );
+        let now = system::Module::<T>::block_number();
+        let new_vote_state = vote_state
+            .close_early(now)
+            .ok_or(Error::<T>::CannotCloseVoteEarlyIfOutcomeUndecided)?;
+        let outcome = new_vote_state.outcome();
+        if let Some(expires) = vote_state.expires() {
+            Self::unindex_expiry(vote_id, expires);
+        }
+        // the tally is kept in storage for audit
+        <VoteStates<T>>::insert(vote_id, new_vote_state);
+        Self::archive_vote(vote_id);
+        Ok(outcome)
+    }
+    fn cancel_vote(vote_id: T::VoteId) -> DispatchResult {
+        let vote_state = <VoteStates<T>>::get(vote_id)
+            .ok_or(Error::<T>::NoVoteStateForControlRequest)?;
+        ensure!(
+            !vote_state.is_closed(),
+            Error::<T>::CannotControlVoteThatIsClosed
+        );
+        ensure!(
+            !Self::check_vote_expired(&vote_state),
+            Error::<T>::CannotControlVoteThatIsExpired
+        );
+        let now = system::Module::<T>::block_number();
+        if let Some(expires) = vote_state.expires() {
+            Self::unindex_expiry(vote_id, expires);
+        }
+        // the tally is kept in storage for audit
+        <VoteStates<T>>::insert(vote_id, vote_state.cancel(now));
+        Self::archive_vote(vote_id);
+        Ok(())
+    }
+}
+
+impl<T: Trait>
+    BurnableSignal<
+        T::AccountId,
+        T::OrgId,
+        T::Signal,
+        T::BlockNumber,
+        T::VoteId,
+        T::IpfsReference,
+    > for Module<T>
+{
+    fn burn_signal(
+        vote_id: T::VoteId,
+        who: &T::AccountId,
+        amount: Option<T::Signal>,
+    ) -> DispatchResult {
+        let old_vote = <VoteLogger<T>>::get(vote_id, who)
+            .ok_or(Error::<T>::SignalNotMintedForVoter)?;
+        let to_burn = amount.unwrap_or_else(|| old_vote.magnitude());
+        ensure!(
+            to_burn <= old_vote.magnitude(),
+            Error::<T>::CannotBurnMoreSignalThanMinted
+        );
+        Self::set_signal(vote_id, who, old_vote.magnitude() - to_burn)
+    }
+}
+
+impl<T: Trait>
+    JustifiedVote<
+        T::OrgId,
+        T::Signal,
+        T::BlockNumber,
+        T::AccountId,
+        VoterView,
+        T::IpfsReference,
+    > for Module<T>
+{
+    fn open_justified_vote(
+        topic: Option<T::IpfsReference>,
+        organization: T::OrgId,
+        passage_threshold: T::Signal,
+        rejection_threshold: Option<T::Signal>,
+        duration: Option<T::BlockNumber>,
+    ) -> Result<T::VoteId, DispatchError> {
+        let new_vote_id = Self::open_vote(
+            topic,
+            organization,
+            passage_threshold,
+            rejection_threshold,
+            duration,
+        )?;
+        <JustificationRequired<T>>::insert(new_vote_id, true);
+        Ok(new_vote_id)
+    }
+    fn justification_required_against(vote_id: T::VoteId) -> bool {
+        <JustificationRequired<T>>::get(vote_id)
+    }
+    fn justifications(
+        vote_id: T::VoteId,
+    ) -> Vec<(T::AccountId, VoterView, T::IpfsReference)> {
+        <VoteLogger<T>>::iter_prefix(vote_id)
+            .filter_map(|(voter, vote)| {
+                vote.justification()
+                    .map(|reason| (voter, vote.direction(), reason))
+            })
+            .collect()
+    }
+}
+
+impl<T: Trait> ShareChangeHandler<T::OrgId, T::AccountId, T::Shares>
+    for Module<T>
+{
+    fn can_change_shares(
+        organization: T::OrgId,
+        _who: &T::AccountId,
+    ) -> DispatchResult {
+        if <SignalPolicies<T>>::get(organization)
+            == SignalPolicy::ForbidShareChanges
+        {
+            ensure!(
+                Self::live_votes(organization).is_empty(),
+                Error::<T>::CannotChangeSharesWhileOrgHasOpenVotes
+            );
+        }
+        Ok(())
+    }
+    fn on_shares_changed(
+        organization: T::OrgId,
+        who: &T::AccountId,
+        _new_shares: T::Shares,
+    ) -> DispatchResult {
+        if <SignalPolicies<T>>::get(organization) != SignalPolicy::AutoSync {
+            return Ok(())
+        }
+        // the changed shares may be of any class, read the weight of the class signal is minted for
+        let weight = <org::Module<T>>::class_shares(
+            organization,
+            T::SignalShareClass::get(),
+            who,
+        );
+        for vote_id in Self::live_votes(organization) {
+            let new_signal: T::Signal = if <EqualSignalVotes<T>>::get(vote_id) {
+                // 1 person 1 vote for as long as the person is a member
+                if <org::Module<T>>::is_member_of_group(organization, who) {
+                    1u32.into()
+                } else {
+                    T::Signal::zero()
+                }
+            } else {
+                weight.into()
+            };
+            Self::set_signal(vote_id, who, new_signal)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: Trait> OrganizationRemovalGuard<T::OrgId> for Module<T> {
+    fn can_remove_organization(organization: T::OrgId) -> DispatchResult {
+        ensure!(
+            Self::live_votes(organization).is_empty(),
+            Error::<T>::CannotRemoveOrganizationWithOpenVotes
+        );
+        Ok(())
+    }
+    fn on_organization_removed(organization: T::OrgId) {
+        // vote states are kept for audit, only the organization's indexes are cleared
+        Self::archive_expired_votes(organization);
+        <OpenVotes<T>>::remove(organization);
+        <ClosedVotes<T>>::remove(organization);
+        <SignalPolicies<T>>::remove(organization);
+    }
+}
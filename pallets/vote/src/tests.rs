@@ -5,6 +5,7 @@ use frame_support::{
     impl_outer_event,
     impl_outer_origin,
     parameter_types,
+    traits::OnInitialize,
     weights::Weight,
 };
 use sp_core::H256;
@@ -13,6 +14,13 @@ use sp_runtime::{
     traits::IdentityLookup,
     Perbill,
 };
+use util::{
+    share::{
+        ShareClass,
+        VestingTerms,
+    },
+    traits::ShareIssuance,
+};
 
 pub type AccountId = u64;
 pub type BlockNumber = u64;
@@ -55,8 +63,26 @@ impl frame_system::Trait for Test {
     type OnKilledAccount = ();
     type BaseCallFilter = ();
 }
+parameter_types! {
+    pub const ExistentialDeposit: u64 = 1;
+}
+impl pallet_balances::Trait for Test {
+    type Balance = u64;
+    type Event = ();
+    type DustRemoval = ();
+    type ExistentialDeposit = ExistentialDeposit;
+    type AccountStore = System;
+}
 parameter_types! {
     pub const ReservationLimit: u32 = 10000;
+    pub const MaxMembers: u32 = 10000;
+    pub const MaxOrgDepth: u32 = 2;
+    pub const OrgVoteDuration: u64 = 10;
+    pub const SignalShareClass: ShareClass = ShareClass::Voting;
+    pub const SupervisorTermLength: u64 = 100;
+    pub const MaxConsecutiveSupervisorTerms: u32 = 2;
+    pub const MembershipDeposit: u64 = 10;
+    pub const MaxMembershipApplications: u32 = 2;
 }
 impl org::Trait for Test {
     type Event = TestEvent;
@@ -64,11 +90,30 @@ impl org::Trait for Test {
     type OrgId = u64;
     type Shares = u64;
     type ReservationLimit = ReservationLimit;
+    type MaxMembers = MaxMembers;
+    type MaxOrgDepth = MaxOrgDepth;
+    type RemovalGuard = VoteThreshold;
+    type OrgVote = VoteThreshold;
+    type OrgVoteId = u64;
+    type OrgVoteDuration = OrgVoteDuration;
+    type SignalShareClass = SignalShareClass;
+    type SupervisorTermLength = SupervisorTermLength;
+    type MaxConsecutiveSupervisorTerms = MaxConsecutiveSupervisorTerms;
+    type MembershipCurrency = pallet_balances::Module<Test>;
+    type MembershipDeposit = MembershipDeposit;
+    type MaxMembershipApplications = MaxMembershipApplications;
+    type ShareChangeHandler = VoteThreshold;
+}
+parameter_types! {
+    pub const MaxClosedVoteHistory: u32 = 2;
+    pub const MaxVoteControlDuration: u64 = 20;
 }
 impl Trait for Test {
     type Event = TestEvent;
     type VoteId = u64;
     type Signal = u64;
+    type MaxClosedVoteHistory = MaxClosedVoteHistory;
+    type MaxVoteControlDuration = MaxVoteControlDuration;
 }
 
 mod vote {
@@ -83,10 +128,10 @@ impl_outer_event! {
     }
 }
 pub type System = system::Module<Test>;
-// pub type Organization = org::Module<Test>;
+pub type Organization = org::Module<Test>;
 pub type VoteThreshold = Module<Test>;
 
-fn get_last_event() -> RawEvent<u64, u64, u64> {
+fn get_last_event() -> RawEvent<u64, u64, u64, u64> {
     System::events()
         .into_iter()
         .map(|r| r.event)
@@ -109,6 +154,7 @@ fn new_test_ext() -> sp_io::TestExternalities {
         first_organization_supervisor: 1,
         first_organization_value_constitution: 1738,
         first_organization_flat_membership: vec![1, 2, 3, 4, 5, 6],
+        organizations: vec![],
     }
     .assimilate_storage(&mut t)
     .unwrap();
@@ -243,3 +289,1230 @@ fn changing_votes_upholds_invariants() {
         assert_eq!(outcome_almost_passed, VoteOutcome::Approved);
     });
 }
+
+#[test]
+fn commit_reveal_voting_works() {
+    new_test_ext().execute_with(|| {
+        let one = Origin::signed(1);
+        // commit phase lasts 5 blocks, reveal phase lasts 5 blocks
+        assert_ok!(VoteThreshold::create_commit_reveal_vote(
+            one.clone(),
+            None,
+            1,
+            4,
+            None,
+            5,
+            5,
+        ));
+        assert_eq!(get_last_event(), RawEvent::NewVoteStarted(1, 1, 1));
+        // plain votes are not accepted for secret ballots
+        assert_noop!(
+            VoteThreshold::submit_vote(
+                one.clone(),
+                1,
+                VoterView::InFavor,
+                None
+            ),
+            Error::<Test>::CommitRevealVoteRequiresCommitThenReveal
+        );
+        for i in 1u64..5u64 {
+            let salt = H256::repeat_byte(i as u8);
+            let commitment = VoteThreshold::vote_commitment(
+                &i,
+                1,
+                VoterView::InFavor,
+                salt,
+            );
+            assert_ok!(VoteThreshold::commit_vote(
+                Origin::signed(i),
+                1,
+                commitment
+            ));
+            assert_eq!(get_last_event(), RawEvent::VoteCommitted(1, i));
+        }
+        // votes are pending until the commitment is revealed
+        assert_eq!(VoteThreshold::pending_votes_for_account(&1), vec![1]);
+        // a copied commitment cannot be revealed by another voter
+        assert_ok!(VoteThreshold::commit_vote(
+            Origin::signed(5),
+            1,
+            VoteThreshold::vote_commitment(
+                &1,
+                1,
+                VoterView::InFavor,
+                H256::repeat_byte(1)
+            )
+        ));
+        // commitments do not move the tally
+        let state = VoteThreshold::vote_states(1).unwrap();
+        assert_eq!(state.in_favor(), 0);
+        assert_eq!(state.turnout(), 5);
+        assert_eq!(
+            VoteThreshold::get_vote_outcome(1).unwrap(),
+            VoteOutcome::Voting
+        );
+        // reveals are not accepted during the commit phase
+        assert_noop!(
+            VoteThreshold::reveal_vote(
+                one.clone(),
+                1,
+                VoterView::InFavor,
+                H256::repeat_byte(1),
+                None
+            ),
+            Error::<Test>::RevealPhaseNotStartedSoRevealsNotAccepted
+        );
+        System::set_block_number(6);
+        // commitments are not accepted during the reveal phase
+        assert_noop!(
+            VoteThreshold::commit_vote(
+                Origin::signed(5),
+                1,
+                VoteThreshold::vote_commitment(
+                    &5,
+                    1,
+                    VoterView::InFavor,
+                    H256::repeat_byte(5)
+                )
+            ),
+            Error::<Test>::CommitPhaseEndedSoCommitmentsNotAccepted
+        );
+        // reveal must match the commitment
+        assert_noop!(
+            VoteThreshold::reveal_vote(
+                one.clone(),
+                1,
+                VoterView::Against,
+                H256::repeat_byte(1),
+                None
+            ),
+            Error::<Test>::RevealDoesNotMatchCommitment
+        );
+        assert_noop!(
+            VoteThreshold::reveal_vote(
+                Origin::signed(5),
+                1,
+                VoterView::InFavor,
+                H256::repeat_byte(1),
+                None
+            ),
+            Error::<Test>::RevealDoesNotMatchCommitment
+        );
+        for i in 1u64..5u64 {
+            assert_ok!(VoteThreshold::reveal_vote(
+                Origin::signed(i),
+                1,
+                VoterView::InFavor,
+                H256::repeat_byte(i as u8),
+                None
+            ));
+            assert_eq!(
+                get_last_event(),
+                RawEvent::VoteRevealed(1, i, VoterView::InFavor)
+            );
+        }
+        assert!(VoteThreshold::pending_votes_for_account(&1).is_empty());
+        assert_eq!(
+            VoteThreshold::get_vote_outcome(1).unwrap(),
+            VoteOutcome::Approved
+        );
+        // reveal phase ends when the vote expires
+        System::set_block_number(12);
+        assert_noop!(
+            VoteThreshold::reveal_vote(
+                one,
+                1,
+                VoterView::InFavor,
+                H256::repeat_byte(1),
+                None
+            ),
+            Error::<Test>::VotePastExpirationTimeSoVotesNotAccepted
+        );
+    });
+}
+
+#[test]
+fn revealed_votes_keep_their_justification() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(VoteThreshold::create_commit_reveal_vote(
+            Origin::signed(1),
+            None,
+            1,
+            4,
+            None,
+            5,
+            5,
+        ));
+        for (i, view) in
+            vec![(1u64, VoterView::Against), (2u64, VoterView::Abstain)]
+        {
+            let salt = H256::repeat_byte(i as u8);
+            let commitment = VoteThreshold::vote_commitment(&i, 1, view, salt);
+            assert_ok!(VoteThreshold::commit_vote(
+                Origin::signed(i),
+                1,
+                commitment
+            ));
+        }
+        System::set_block_number(6);
+        for (i, view, justification) in vec![
+            (1u64, VoterView::Against, 10u32),
+            (2u64, VoterView::Abstain, 20u32),
+        ] {
+            assert_ok!(VoteThreshold::reveal_vote(
+                Origin::signed(i),
+                1,
+                view,
+                H256::repeat_byte(i as u8),
+                Some(justification)
+            ));
+            let vote = VoteThreshold::vote_logger(1, i).unwrap();
+            assert_eq!(vote.direction(), view);
+            assert_eq!(vote.justification(), Some(justification));
+        }
+        let state = VoteThreshold::vote_states(1).unwrap();
+        assert_eq!(state.against(), 1);
+        assert_eq!(state.turnout(), 2);
+    });
+}
+
+#[test]
+fn unrevealed_commitments_count_as_abstentions() {
+    new_test_ext().execute_with(|| {
+        let one = Origin::signed(1);
+        assert_ok!(VoteThreshold::create_commit_reveal_vote(
+            one.clone(),
+            None,
+            1,
+            2,
+            Some(2),
+            5,
+            5,
+        ));
+        for i in 1u64..4u64 {
+            let salt = H256::repeat_byte(i as u8);
+            let commitment =
+                VoteThreshold::vote_commitment(&i, 1, VoterView::Against, salt);
+            assert_ok!(VoteThreshold::commit_vote(
+                Origin::signed(i),
+                1,
+                commitment
+            ));
+        }
+        System::set_block_number(6);
+        // only voter 1 reveals
+        assert_ok!(VoteThreshold::reveal_vote(
+            one,
+            1,
+            VoterView::Against,
+            H256::repeat_byte(1),
+            None
+        ));
+        let state = VoteThreshold::vote_states(1).unwrap();
+        assert_eq!(state.against(), 1);
+        assert_eq!(state.turnout(), 3);
+        assert_eq!(
+            VoteThreshold::vote_logger(1, 2).unwrap().direction(),
+            VoterView::Abstain
+        );
+        assert_eq!(
+            VoteThreshold::get_vote_outcome(1).unwrap(),
+            VoteOutcome::Voting
+        );
+    });
+}
+
+#[test]
+fn supervisor_vote_controls_work() {
+    new_test_ext().execute_with(|| {
+        let one = Origin::signed(1);
+        let two = Origin::signed(2);
+        assert_ok!(VoteThreshold::create_threshold_approval_vote(
+            one.clone(),
+            None,
+            1,
+            4,
+            None,
+            Some(10)
+        ));
+        // only the supervisor may control the vote
+        assert_noop!(
+            VoteThreshold::extend_vote(two.clone(), 1, 5),
+            Error::<Test>::NotAuthorizedToControlVote
+        );
+        assert_ok!(VoteThreshold::extend_vote(one.clone(), 1, 5));
+        assert_eq!(get_last_event(), RawEvent::VoteExtended(1, 1, 16));
+        assert_eq!(VoteThreshold::vote_states(1).unwrap().expires(), Some(16));
+        // outcome is not decided yet
+        assert_noop!(
+            VoteThreshold::close_vote_early(one.clone(), 1),
+            Error::<Test>::CannotCloseVoteEarlyIfOutcomeUndecided
+        );
+        // 3 against means the 4 signal support requirement can no longer be met
+        for i in 1u64..4u64 {
+            assert_ok!(VoteThreshold::submit_vote(
+                Origin::signed(i),
+                1,
+                VoterView::Against,
+                None
+            ));
+        }
+        assert_ok!(VoteThreshold::close_vote_early(one.clone(), 1));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::VoteClosedEarly(1, 1, VoteOutcome::Rejected)
+        );
+        // tally is preserved for audit
+        let state = VoteThreshold::vote_states(1).unwrap();
+        assert_eq!(state.against(), 3);
+        assert_eq!(state.closed(), Some(1));
+        assert_noop!(
+            VoteThreshold::submit_vote(
+                Origin::signed(4),
+                1,
+                VoterView::InFavor,
+                None
+            ),
+            Error::<Test>::VoteClosedSoVotesNotAccepted
+        );
+        assert_noop!(
+            VoteThreshold::cancel_vote(one.clone(), 1),
+            Error::<Test>::CannotControlVoteThatIsClosed
+        );
+        // cancel a second vote
+        assert_ok!(VoteThreshold::create_unanimous_consent_approval_vote(
+            one.clone(),
+            None,
+            1,
+            None,
+        ));
+        assert_noop!(
+            VoteThreshold::extend_vote(one.clone(), 2, 5),
+            Error::<Test>::CannotExtendVoteWithoutExpiry
+        );
+        assert_ok!(VoteThreshold::cancel_vote(one, 2));
+        assert_eq!(get_last_event(), RawEvent::VoteCancelled(1, 2));
+        assert_eq!(
+            VoteThreshold::get_vote_outcome(2).unwrap(),
+            VoteOutcome::Cancelled
+        );
+        assert_eq!(VoteThreshold::open_vote_counter(), 0);
+    });
+}
+
+#[test]
+fn governed_vote_controls_work() {
+    new_test_ext().execute_with(|| {
+        let one = Origin::signed(1);
+        assert_ok!(VoteThreshold::create_threshold_approval_vote(
+            one.clone(),
+            None,
+            1,
+            4,
+            None,
+            Some(10)
+        ));
+        // non members cannot propose controls
+        assert_noop!(
+            VoteThreshold::propose_vote_control(
+                Origin::signed(22),
+                1,
+                VoteControl::Cancel,
+                5
+            ),
+            Error::<Test>::NotAuthorizedToProposeVoteControl
+        );
+        // the governing vote expires within the configured bound
+        assert_noop!(
+            VoteThreshold::propose_vote_control(
+                Origin::signed(2),
+                1,
+                VoteControl::Cancel,
+                0
+            ),
+            Error::<Test>::VoteControlDurationOutOfBounds
+        );
+        assert_noop!(
+            VoteThreshold::propose_vote_control(
+                Origin::signed(2),
+                1,
+                VoteControl::Cancel,
+                21
+            ),
+            Error::<Test>::VoteControlDurationOutOfBounds
+        );
+        assert_ok!(VoteThreshold::propose_vote_control(
+            Origin::signed(2),
+            1,
+            VoteControl::Extend(10),
+            5
+        ));
+        assert_eq!(VoteThreshold::vote_states(2).unwrap().expires(), Some(6));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::VoteControlProposed(2, 1, 2, VoteControl::Extend(10))
+        );
+        // governing vote passes with a majority of the 6 shares
+        for i in 1u64..5u64 {
+            assert_ok!(VoteThreshold::submit_vote(
+                Origin::signed(i),
+                2,
+                VoterView::InFavor,
+                None
+            ));
+        }
+        assert_ok!(VoteThreshold::poll_vote_control(Origin::signed(3), 2));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::VoteControlPolled(3, 2, VoteOutcome::Approved)
+        );
+        assert_eq!(VoteThreshold::vote_states(1).unwrap().expires(), Some(21));
+        assert!(VoteThreshold::vote_control_proposals(2).is_none());
+    });
+}
+
+#[test]
+fn failed_governed_vote_control_is_removed() {
+    new_test_ext().execute_with(|| {
+        let one = Origin::signed(1);
+        assert_ok!(VoteThreshold::create_threshold_approval_vote(
+            one.clone(),
+            None,
+            1,
+            4,
+            None,
+            Some(10)
+        ));
+        assert_ok!(VoteThreshold::propose_vote_control(
+            Origin::signed(2),
+            1,
+            VoteControl::CloseEarly,
+            5
+        ));
+        for i in 1u64..5u64 {
+            assert_ok!(VoteThreshold::submit_vote(
+                Origin::signed(i),
+                2,
+                VoterView::InFavor,
+                None
+            ));
+        }
+        // the supervisor cancels the controlled vote first
+        assert_ok!(VoteThreshold::cancel_vote(one, 1));
+        assert_ok!(VoteThreshold::poll_vote_control(Origin::signed(3), 2));
+        let failed = TestEvent::vote(RawEvent::VoteControlFailed(2, 1));
+        assert!(System::events().iter().any(|r| r.event == failed));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::VoteControlPolled(3, 2, VoteOutcome::Approved)
+        );
+        assert!(VoteThreshold::vote_control_proposals(2).is_none());
+        assert_eq!(
+            VoteThreshold::get_vote_outcome(1).unwrap(),
+            VoteOutcome::Cancelled
+        );
+    });
+}
+
+#[test]
+fn snapshot_signal_policy_ignores_share_changes() {
+    new_test_ext().execute_with(|| {
+        let one = Origin::signed(1);
+        assert_eq!(VoteThreshold::signal_policies(1), SignalPolicy::Snapshot);
+        assert_ok!(VoteThreshold::create_threshold_approval_vote(
+            one, None, 1, 4, None, None
+        ));
+        assert_ok!(Organization::issue(1, 1, 10, false));
+        assert_ok!(Organization::burn(1, 2, None, false));
+        // signal minted when the vote opened is unchanged
+        assert_eq!(VoteThreshold::vote_logger(1, 1).unwrap().magnitude(), 1);
+        assert_eq!(
+            VoteThreshold::vote_states(1)
+                .unwrap()
+                .all_possible_turnout(),
+            6
+        );
+        assert_ok!(VoteThreshold::submit_vote(
+            Origin::signed(2),
+            1,
+            VoterView::InFavor,
+            None
+        ));
+    });
+}
+
+#[test]
+fn auto_sync_signal_policy_follows_share_changes() {
+    new_test_ext().execute_with(|| {
+        let one = Origin::signed(1);
+        assert_noop!(
+            VoteThreshold::set_signal_policy(
+                Origin::signed(2),
+                1,
+                SignalPolicy::AutoSync
+            ),
+            Error::<Test>::NotAuthorizedToSetSignalPolicy
+        );
+        assert_ok!(VoteThreshold::set_signal_policy(
+            one.clone(),
+            1,
+            SignalPolicy::AutoSync
+        ));
+        assert_eq!(
+            get_last_event(),
+            RawEvent::SignalPolicySet(1, 1, SignalPolicy::AutoSync)
+        );
+        assert_ok!(VoteThreshold::create_threshold_approval_vote(
+            one.clone(),
+            None,
+            1,
+            12,
+            None,
+            None
+        ));
+        assert_ok!(VoteThreshold::submit_vote(
+            one,
+            1,
+            VoterView::InFavor,
+            None
+        ));
+        // issued shares mint signal and move the tally of the existing vote
+        assert_ok!(Organization::issue(1, 1, 10, false));
+        assert_eq!(VoteThreshold::vote_logger(1, 1).unwrap().magnitude(), 11);
+        let state = VoteThreshold::vote_states(1).unwrap();
+        assert_eq!(state.in_favor(), 11);
+        assert_eq!(state.turnout(), 11);
+        assert_eq!(state.all_possible_turnout(), 16);
+        assert_eq!(VoteThreshold::total_signal_issuance(1), Some(16));
+        // burned out members can no longer vote
+        assert_ok!(Organization::burn(1, 2, None, false));
+        assert_noop!(
+            VoteThreshold::submit_vote(
+                Origin::signed(2),
+                1,
+                VoterView::InFavor,
+                None
+            ),
+            Error::<Test>::SignalNotMintedForVoter
+        );
+        // new members can vote
+        assert_ok!(Organization::issue(1, 7, 5, false));
+        assert_eq!(VoteThreshold::vote_logger(1, 7).unwrap().magnitude(), 5);
+        assert_ok!(VoteThreshold::submit_vote(
+            Origin::signed(7),
+            1,
+            VoterView::InFavor,
+            None
+        ));
+        assert_eq!(
+            VoteThreshold::get_vote_outcome(1).unwrap(),
+            VoteOutcome::Approved
+        );
+    });
+}
+
+#[test]
+fn forbid_signal_policy_blocks_share_changes_during_votes() {
+    new_test_ext().execute_with(|| {
+        let one = Origin::signed(1);
+        assert_ok!(VoteThreshold::set_signal_policy(
+            one.clone(),
+            1,
+            SignalPolicy::ForbidShareChanges
+        ));
+        // no open votes so share changes are permitted
+        assert_ok!(Organization::issue(1, 7, 5, false));
+        assert_ok!(VoteThreshold::create_threshold_approval_vote(
+            one.clone(),
+            None,
+            1,
+            4,
+            None,
+            None
+        ));
+        assert_noop!(
+            Organization::issue(1, 7, 5, false),
+            Error::<Test>::CannotChangeSharesWhileOrgHasOpenVotes
+        );
+        assert_noop!(
+            Organization::burn(1, 2, None, false),
+            Error::<Test>::CannotChangeSharesWhileOrgHasOpenVotes
+        );
+        assert_ok!(VoteThreshold::cancel_vote(one, 1));
+        assert_ok!(Organization::burn(1, 2, None, false));
+    });
+}
+
+#[test]
+fn burning_signal_updates_tally() {
+    new_test_ext().execute_with(|| {
+        let one = Origin::signed(1);
+        assert_ok!(VoteThreshold::create_threshold_approval_vote(
+            one.clone(),
+            None,
+            1,
+            4,
+            None,
+            None
+        ));
+        assert_ok!(VoteThreshold::submit_vote(
+            one,
+            1,
+            VoterView::InFavor,
+            None
+        ));
+        assert_noop!(
+            VoteThreshold::burn_signal(1, &1, Some(2)),
+            Error::<Test>::CannotBurnMoreSignalThanMinted
+        );
+        assert_ok!(VoteThreshold::burn_signal(1, &1, None));
+        assert!(VoteThreshold::vote_logger(1, 1).is_none());
+        let state = VoteThreshold::vote_states(1).unwrap();
+        assert_eq!(state.in_favor(), 0);
+        assert_eq!(state.turnout(), 0);
+        assert_eq!(state.all_possible_turnout(), 5);
+        assert_noop!(
+            VoteThreshold::burn_signal(1, &1, None),
+            Error::<Test>::SignalNotMintedForVoter
+        );
+    });
+}
+
+#[test]
+fn vote_indexes_work() {
+    new_test_ext().execute_with(|| {
+        let one = Origin::signed(1);
+        assert_ok!(VoteThreshold::create_threshold_approval_vote(
+            one.clone(),
+            None,
+            1,
+            4,
+            None,
+            Some(1)
+        ));
+        assert_ok!(VoteThreshold::create_unanimous_consent_approval_vote(
+            one.clone(),
+            None,
+            1,
+            None,
+        ));
+        assert_eq!(VoteThreshold::live_votes(1), vec![1, 2]);
+        assert_eq!(VoteThreshold::votes_for_account(&2).len(), 2);
+        assert_ok!(VoteThreshold::submit_vote(
+            Origin::signed(2),
+            2,
+            VoterView::InFavor,
+            None
+        ));
+        assert_eq!(VoteThreshold::pending_votes_for_account(&2), vec![1]);
+        // expired votes are no longer live and move to the closed history
+        // at the start of the first block after their last block
+        assert_eq!(VoteThreshold::votes_expiring_at(3), vec![1]);
+        System::set_block_number(2);
+        VoteThreshold::on_initialize(2);
+        assert_eq!(VoteThreshold::open_votes(1), vec![1, 2]);
+        System::set_block_number(3);
+        VoteThreshold::on_initialize(3);
+        assert!(VoteThreshold::votes_expiring_at(3).is_empty());
+        assert_eq!(VoteThreshold::live_votes(1), vec![2]);
+        assert_eq!(VoteThreshold::open_votes(1), vec![2]);
+        assert_eq!(VoteThreshold::closed_votes(1), vec![1]);
+        assert_eq!(VoteThreshold::open_vote_counter(), 1);
+        assert_eq!(VoteThreshold::votes_for_account(&2), vec![2]);
+        assert!(VoteThreshold::pending_votes_for_account(&2).is_empty());
+        assert_ok!(VoteThreshold::create_unanimous_consent_approval_vote(
+            one.clone(),
+            None,
+            1,
+            None,
+        ));
+        assert_eq!(VoteThreshold::open_votes(1), vec![2, 3]);
+        // closed vote history is bounded
+        assert_ok!(VoteThreshold::cancel_vote(one.clone(), 2));
+        assert_ok!(VoteThreshold::cancel_vote(one, 3));
+        assert_eq!(VoteThreshold::closed_votes(1), vec![2, 3]);
+        assert!(VoteThreshold::open_votes(1).is_empty());
+        assert_eq!(VoteThreshold::open_vote_counter(), 0);
+        assert!(VoteThreshold::votes_for_account(&2).is_empty());
+    });
+}
+
+#[test]
+fn vote_summary_works() {
+    new_test_ext().execute_with(|| {
+        let one = Origin::signed(1);
+        assert_ok!(VoteThreshold::create_unanimous_consent_approval_vote(
+            one,
+            None,
+            1,
+            Some(10),
+        ));
+        assert!(VoteThreshold::vote_summary(2, None).is_none());
+        assert_ok!(VoteThreshold::submit_vote(
+            Origin::signed(2),
+            1,
+            VoterView::InFavor,
+            None
+        ));
+        System::set_block_number(4);
+        let summary = VoteThreshold::vote_summary(1, Some(2)).unwrap();
+        assert_eq!(summary.state.in_favor(), 1);
+        assert_eq!(summary.remaining_blocks, Some(8));
+        assert_eq!(summary.vote.unwrap().direction(), VoterView::InFavor);
+        assert!(VoteThreshold::vote_summary(1, Some(3))
+            .unwrap()
+            .vote
+            .is_none());
+        // votes are still accepted at the expiry block
+        System::set_block_number(11);
+        assert_eq!(
+            VoteThreshold::vote_summary(1, None)
+                .unwrap()
+                .remaining_blocks,
+            Some(1)
+        );
+        assert_ok!(VoteThreshold::submit_vote(
+            Origin::signed(3),
+            1,
+            VoterView::InFavor,
+            None
+        ));
+        System::set_block_number(12);
+        assert_eq!(
+            VoteThreshold::vote_summary(1, None)
+                .unwrap()
+                .remaining_blocks,
+            Some(0)
+        );
+        assert_noop!(
+            VoteThreshold::submit_vote(
+                Origin::signed(4),
+                1,
+                VoterView::InFavor,
+                None
+            ),
+            Error::<Test>::VotePastExpirationTimeSoVotesNotAccepted
+        );
+    });
+}
+
+#[test]
+fn vote_justifications_works() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(VoteThreshold::create_threshold_approval_vote(
+            Origin::signed(1),
+            None,
+            1,
+            4,
+            None,
+            None
+        ));
+        assert!(VoteThreshold::vote_justifications(1).is_empty());
+        assert_ok!(VoteThreshold::submit_vote(
+            Origin::signed(2),
+            1,
+            VoterView::Against,
+            Some(20)
+        ));
+        // votes without justification are not listed
+        assert_ok!(VoteThreshold::submit_vote(
+            Origin::signed(3),
+            1,
+            VoterView::InFavor,
+            None
+        ));
+        assert_eq!(
+            VoteThreshold::vote_justifications(1),
+            vec![(2, VoterView::Against, 20)]
+        );
+    });
+}
+
+#[test]
+fn justification_required_against_works() {
+    new_test_ext().execute_with(|| {
+        let one = Origin::signed(1);
+        // only the supervisor may open votes
+        assert_noop!(
+            VoteThreshold::create_justified_threshold_approval_vote(
+                Origin::signed(2),
+                None,
+                1,
+                4,
+                Some(2),
+                None
+            ),
+            Error::<Test>::NotAuthorizedToCreateVoteForOrganization
+        );
+        assert_ok!(VoteThreshold::create_justified_threshold_approval_vote(
+            one.clone(),
+            None,
+            1,
+            4,
+            Some(2),
+            None
+        ));
+        assert_eq!(get_last_event(), RawEvent::NewVoteStarted(1, 1, 1));
+        assert!(VoteThreshold::justification_required_against(1));
+        assert_noop!(
+            VoteThreshold::submit_vote(
+                Origin::signed(2),
+                1,
+                VoterView::Against,
+                None
+            ),
+            Error::<Test>::AgainstVoteRequiresJustification
+        );
+        // votes in favor need no justification
+        assert_ok!(VoteThreshold::submit_vote(
+            Origin::signed(3),
+            1,
+            VoterView::InFavor,
+            None
+        ));
+        assert_ok!(VoteThreshold::submit_vote(
+            Origin::signed(2),
+            1,
+            VoterView::Against,
+            Some(2)
+        ));
+        assert_eq!(
+            VoteThreshold::justifications(1),
+            vec![(2, VoterView::Against, 2)]
+        );
+        // the requirement is fixed when the vote opens
+        assert_ok!(VoteThreshold::create_threshold_approval_vote(
+            one,
+            None,
+            1,
+            4,
+            Some(2),
+            None
+        ));
+        assert!(!VoteThreshold::justification_required_against(2));
+        assert_ok!(VoteThreshold::submit_vote(
+            Origin::signed(2),
+            2,
+            VoterView::Against,
+            None
+        ));
+    });
+}
+
+#[test]
+fn open_votes_block_organization_removal() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(VoteThreshold::create_unanimous_consent_approval_vote(
+            Origin::signed(1),
+            None,
+            1,
+            Some(2),
+        ));
+        assert_noop!(
+            VoteThreshold::can_remove_organization(1),
+            Error::<Test>::CannotRemoveOrganizationWithOpenVotes
+        );
+        // expired votes do not block removal
+        System::set_block_number(4);
+        assert_ok!(VoteThreshold::can_remove_organization(1));
+        VoteThreshold::on_organization_removed(1);
+        assert!(VoteThreshold::open_votes(1).is_empty());
+        assert_eq!(VoteThreshold::open_vote_counter(), 0);
+    });
+}
+
+#[test]
+fn expired_votes_cannot_be_controlled() {
+    new_test_ext().execute_with(|| {
+        let one = Origin::signed(1);
+        assert_ok!(VoteThreshold::create_threshold_approval_vote(
+            one.clone(),
+            None,
+            1,
+            4,
+            None,
+            Some(2)
+        ));
+        System::set_block_number(4);
+        assert_noop!(
+            VoteThreshold::close_vote_early(one.clone(), 1),
+            Error::<Test>::CannotControlVoteThatIsExpired
+        );
+        assert_noop!(
+            VoteThreshold::cancel_vote(one, 1),
+            Error::<Test>::CannotControlVoteThatIsExpired
+        );
+        // the outcome of the expired vote is not overwritten
+        assert_eq!(VoteThreshold::vote_states(1).unwrap().closed(), None);
+        // the expired vote is archived once without opening another vote
+        VoteThreshold::on_initialize(4);
+        VoteThreshold::on_initialize(5);
+        assert!(VoteThreshold::open_votes(1).is_empty());
+        assert_eq!(VoteThreshold::closed_votes(1), vec![1]);
+        assert_eq!(VoteThreshold::open_vote_counter(), 0);
+        assert!(VoteThreshold::votes_for_account(&2).is_empty());
+    });
+}
+
+#[test]
+fn vote_logger_migration_works() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(VoteThreshold::create_unanimous_consent_approval_vote(
+            Origin::signed(1),
+            None,
+            1,
+            None,
+        ));
+        assert_ok!(VoteThreshold::submit_vote(
+            Origin::signed(2),
+            1,
+            VoterView::InFavor,
+            None
+        ));
+        // chains with votes logged under opaque hashes and without the vote indexes
+        let votes: Vec<(u64, Vote<u64, u32>)> =
+            <VoteLogger<Test>>::iter_prefix(1).collect();
+        assert_eq!(votes.len(), 6);
+        <VoteLogger<Test>>::remove_prefix(1);
+        <VoteOrganization<Test>>::remove(1);
+        for (who, vote) in votes.iter() {
+            <AccountVotes<Test>>::remove(who, 1);
+            let key = [
+                sp_io::hashing::blake2_256(&1u64.encode()),
+                sp_io::hashing::blake2_256(&who.encode()),
+            ]
+            .concat();
+            frame_support::storage::migration::put_storage_value(
+                b"Vote",
+                b"VoteLogger",
+                &key,
+                *vote,
+            );
+        }
+        <StorageVersion>::put(Releases::V1);
+        assert!(VoteThreshold::vote_logger(1, 2).is_none());
+        VoteThreshold::migrate_vote_logger();
+        for (who, vote) in votes {
+            assert_eq!(VoteThreshold::vote_logger(1, who), Some(vote));
+        }
+        assert_eq!(
+            VoteThreshold::vote_logger(1, 2).unwrap().direction(),
+            VoterView::InFavor
+        );
+        assert_eq!(<StorageVersion>::get(), Releases::V2);
+    });
+}
+
+#[test]
+fn vote_state_migration_works() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(VoteThreshold::create_threshold_approval_vote(
+            Origin::signed(1),
+            Some(7),
+            1,
+            4,
+            Some(2),
+            Some(10)
+        ));
+        let state = VoteThreshold::vote_states(1).unwrap();
+        // chains with vote states stored without the commit-reveal phase and close block
+        let old_state = (
+            Some(7u32),
+            0u64,
+            0u64,
+            0u64,
+            6u64,
+            4u64,
+            Some(2u64),
+            1u64,
+            Some(11u64),
+            VoteOutcome::Voting,
+        );
+        unhashed::put(&<VoteStates<Test>>::hashed_key_for(1), &old_state);
+        <StorageVersion>::put(Releases::V2);
+        VoteThreshold::migrate_vote_states();
+        assert_eq!(VoteThreshold::vote_states(1), Some(state));
+        assert_eq!(<StorageVersion>::get(), Releases::V3);
+    });
+}
+
+#[test]
+fn votes_expiring_at_migration_works() {
+    new_test_ext().execute_with(|| {
+        let one = Origin::signed(1);
+        assert_ok!(VoteThreshold::create_threshold_approval_vote(
+            one.clone(),
+            None,
+            1,
+            4,
+            None,
+            Some(10)
+        ));
+        assert_ok!(VoteThreshold::create_threshold_approval_vote(
+            one.clone(),
+            None,
+            1,
+            4,
+            None,
+            Some(1)
+        ));
+        assert_ok!(VoteThreshold::create_unanimous_consent_approval_vote(
+            one, None, 1, None,
+        ));
+        // chains with open votes that are not indexed by expiry
+        <VotesExpiringAt<Test>>::remove(12);
+        <VotesExpiringAt<Test>>::remove(3);
+        <StorageVersion>::put(Releases::V3);
+        System::set_block_number(5);
+        VoteThreshold::migrate_votes_expiring_at();
+        assert_eq!(VoteThreshold::votes_expiring_at(12), vec![1]);
+        // votes which already expired are archived in the current block
+        assert_eq!(VoteThreshold::votes_expiring_at(5), vec![2]);
+        VoteThreshold::on_initialize(5);
+        assert_eq!(VoteThreshold::open_votes(1), vec![1, 3]);
+        assert_eq!(<StorageVersion>::get(), Releases::V4);
+    });
+}
+
+#[test]
+fn only_votes_with_expiry_are_indexed_by_expiry() {
+    new_test_ext().execute_with(|| {
+        let one = Origin::signed(1);
+        assert_ok!(VoteThreshold::create_threshold_approval_vote(
+            one.clone(),
+            None,
+            1,
+            4,
+            None,
+            Some(2)
+        ));
+        assert_ok!(VoteThreshold::create_unanimous_consent_approval_vote(
+            one.clone(),
+            None,
+            1,
+            None,
+        ));
+        // the vote without an expiry is never drained
+        assert_eq!(VoteThreshold::votes_expiring_at(4), vec![1]);
+        assert_eq!(<VotesExpiringAt<Test>>::iter().count(), 1);
+        // an extension moves the vote to the bucket of its new expiry
+        assert_ok!(VoteThreshold::extend_vote(one.clone(), 1, 3));
+        assert!(VoteThreshold::votes_expiring_at(4).is_empty());
+        assert_eq!(VoteThreshold::votes_expiring_at(7), vec![1]);
+        System::set_block_number(4);
+        VoteThreshold::on_initialize(4);
+        assert_eq!(VoteThreshold::open_votes(1), vec![1, 2]);
+        // a cancelled vote leaves the index
+        assert_ok!(VoteThreshold::cancel_vote(one, 1));
+        assert!(VoteThreshold::votes_expiring_at(7).is_empty());
+        assert_eq!(VoteThreshold::open_votes(1), vec![2]);
+    });
+}
+
+#[test]
+fn signal_is_weighted_by_signal_share_class() {
+    new_test_ext().execute_with(|| {
+        let one = Origin::signed(1);
+        assert_ok!(Organization::issue_class_shares(
+            one.clone(),
+            1,
+            ShareClass::Voting,
+            2,
+            4
+        ));
+        assert_ok!(Organization::issue_class_shares(
+            one.clone(),
+            1,
+            ShareClass::Revenue,
+            7,
+            6
+        ));
+        assert_ok!(VoteThreshold::create_threshold_approval_vote(
+            one, None, 1, 6, None, None
+        ));
+        // voting shares add signal, revenue shares do not mint any
+        assert_eq!(VoteThreshold::vote_logger(1, 2).unwrap().magnitude(), 5);
+        assert_eq!(VoteThreshold::vote_logger(1, 1).unwrap().magnitude(), 1);
+        assert!(VoteThreshold::vote_logger(1, 7).is_none());
+        assert_eq!(VoteThreshold::total_signal_issuance(1), Some(10));
+    });
+}
+
+#[test]
+fn org_supervisor_change_is_decided_by_member_vote() {
+    new_test_ext().execute_with(|| {
+        let two = Origin::signed(2);
+        assert_ok!(Organization::propose_supervisor_change(two.clone(), 1, 2));
+        // the member vote is decided by a majority of the signal share class
+        let state = VoteThreshold::vote_states(1).unwrap();
+        assert_eq!(state.all_possible_turnout(), 6);
+        assert_eq!(state.passage_threshold(), 4);
+        assert_eq!(state.rejection_threshold(), Some(4));
+        for i in 1u64..4u64 {
+            assert_ok!(VoteThreshold::submit_vote(
+                Origin::signed(i),
+                1,
+                VoterView::InFavor,
+                None
+            ));
+        }
+        assert_noop!(
+            Organization::poll_supervisor_change(two.clone(), 1),
+            org::Error::<Test>::SupervisorChangeVoteUndecided
+        );
+        assert_ok!(VoteThreshold::submit_vote(
+            Origin::signed(4),
+            1,
+            VoterView::InFavor,
+            None
+        ));
+        assert_ok!(Organization::poll_supervisor_change(two, 1));
+        assert_eq!(
+            Organization::organization_states(1).unwrap().sudo(),
+            Some(2)
+        );
+        assert_eq!(Organization::supervisor_terms(1).unwrap().supervisor(), 2);
+        assert!(Organization::supervisor_changes(1).is_none());
+    });
+}
+
+#[test]
+fn org_constitution_amendment_is_decided_by_member_vote() {
+    new_test_ext().execute_with(|| {
+        let three = Origin::signed(3);
+        assert_ok!(Organization::propose_constitution_amendment(
+            three.clone(),
+            1,
+            1739
+        ));
+        // a majority against rejects the amendment
+        for i in 1u64..5u64 {
+            assert_ok!(VoteThreshold::submit_vote(
+                Origin::signed(i),
+                1,
+                VoterView::Against,
+                None
+            ));
+        }
+        assert_eq!(
+            VoteThreshold::get_vote_outcome(1).unwrap(),
+            VoteOutcome::Rejected
+        );
+        assert_ok!(Organization::poll_constitution_amendment(three.clone(), 1));
+        assert_eq!(
+            Organization::organization_states(1).unwrap().constitution(),
+            1738
+        );
+        assert!(Organization::constitution_amendments(1).is_none());
+        // a majority in favor amends it
+        assert_ok!(Organization::propose_constitution_amendment(
+            three.clone(),
+            1,
+            1739
+        ));
+        for i in 1u64..5u64 {
+            assert_ok!(VoteThreshold::submit_vote(
+                Origin::signed(i),
+                2,
+                VoterView::InFavor,
+                None
+            ));
+        }
+        System::set_block_number(3);
+        assert_ok!(Organization::poll_constitution_amendment(three, 1));
+        assert_eq!(
+            Organization::organization_states(1).unwrap().constitution(),
+            1739
+        );
+        assert_eq!(Organization::constitution_history(1), vec![(1738, 3)]);
+    });
+}
+
+#[test]
+fn org_dissolution_is_decided_by_member_vote() {
+    new_test_ext().execute_with(|| {
+        let one = Origin::signed(1);
+        assert_ok!(VoteThreshold::create_threshold_approval_vote(
+            one.clone(),
+            None,
+            1,
+            4,
+            None,
+            None
+        ));
+        assert_ok!(Organization::dissolve_organization(one.clone(), 1, false));
+        for i in 1u64..5u64 {
+            assert_ok!(VoteThreshold::submit_vote(
+                Origin::signed(i),
+                2,
+                VoterView::InFavor,
+                None
+            ));
+        }
+        // the approved dissolution vote is closed but the other open vote
+        // blocks the removal
+        assert_eq!(
+            Organization::poll_dissolution(one.clone(), 1),
+            Err(Error::<Test>::CannotRemoveOrganizationWithOpenVotes.into())
+        );
+        assert_eq!(VoteThreshold::open_votes(1), vec![1]);
+        assert!(VoteThreshold::vote_states(2).unwrap().is_closed());
+        assert_ok!(VoteThreshold::cancel_vote(one.clone(), 1));
+        assert_ok!(Organization::poll_dissolution(one, 1));
+        assert!(Organization::organization_states(1).is_none());
+        assert!(VoteThreshold::open_votes(1).is_empty());
+        assert_eq!(
+            VoteThreshold::get_vote_outcome(2).unwrap(),
+            VoteOutcome::Approved
+        );
+    });
+}
+
+#[test]
+fn org_vesting_revocation_is_decided_by_member_vote() {
+    new_test_ext().execute_with(|| {
+        let one = Origin::signed(1);
+        assert_ok!(Organization::issue_shares(
+            one.clone(),
+            1,
+            7,
+            10,
+            Some(VestingTerms::new(10, 20))
+        ));
+        System::set_block_number(11);
+        assert_ok!(Organization::propose_vesting_revocation(one.clone(), 1, 7));
+        // only the 5 vested shares of 7 weight its signal
+        let state = VoteThreshold::vote_states(1).unwrap();
+        assert_eq!(state.all_possible_turnout(), 11);
+        assert_eq!(state.passage_threshold(), 6);
+        assert_eq!(VoteThreshold::vote_logger(1, 7).unwrap().magnitude(), 5);
+        assert_ok!(VoteThreshold::submit_vote(
+            Origin::signed(7),
+            1,
+            VoterView::Against,
+            None
+        ));
+        for i in 1u64..6u64 {
+            assert_ok!(VoteThreshold::submit_vote(
+                Origin::signed(i),
+                1,
+                VoterView::InFavor,
+                None
+            ));
+        }
+        assert_noop!(
+            Organization::poll_vesting_revocation(one.clone(), 1, 7),
+            org::Error::<Test>::VestingRevocationVoteUndecided
+        );
+        assert_ok!(VoteThreshold::submit_vote(
+            Origin::signed(6),
+            1,
+            VoterView::InFavor,
+            None
+        ));
+        assert_ok!(Organization::poll_vesting_revocation(one, 1, 7));
+        assert_eq!(Organization::members(1, 7).unwrap().total(), 5);
+        assert!(Organization::vesting_schedules(1, 7).is_none());
+        assert_eq!(Organization::total_issuance(1), 11);
+    });
+}